@@ -1,14 +1,16 @@
 use crate::msrvs::Msrv;
-use crate::types::{DisallowedPath, MacroMatcher, MatchLintBehaviour, Rename};
+use crate::types::{DisallowedPath, DocItemKind, DocVisibility, MacroMatcher, MatchLintBehaviour, Rename};
 use crate::ClippyConfiguration;
 use rustc_data_structures::fx::FxHashSet;
+use rustc_session::lint::Level;
 use rustc_session::Session;
-use rustc_span::{BytePos, Pos, SourceFile, Span, SyntaxContext};
-use serde::de::{IgnoredAny, IntoDeserializer, MapAccess, Visitor};
+use rustc_span::{BytePos, FileName, Pos, SourceFile, Span, SyntaxContext};
+use serde::de::{DeserializeSeed, IgnoredAny, IntoDeserializer, MapAccess, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::Range;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 use std::str::FromStr;
 use std::sync::OnceLock;
 use std::{cmp, env, fmt, fs, io};
@@ -38,49 +40,167 @@ const DEFAULT_DOC_VALID_IDENTS: &[&str] = &[
 const DEFAULT_DISALLOWED_NAMES: &[&str] = &["foo", "baz", "quux"];
 const DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS: &[&str] = &["i", "j", "x", "y", "z", "w", "n"];
 
-/// Conf with parse errors
-#[derive(Default)]
+/// Conf with parse errors, the spans are the byte ranges in the configuration file
+#[derive(Clone, Default)]
 struct TryConf {
     conf: Conf,
+    /// Names of the fields that were explicitly set in the file
+    set_fields: FxHashSet<&'static str>,
+    /// Whether `inherit = true` was set, i.e. whether unset values are taken from the next
+    /// configuration file found in a parent directory
+    inherit: bool,
+    /// The entries of the `[lints]` table
+    lints: Vec<LintEntry>,
+    /// The path globs of an `[[overrides]]` table, empty for the top level table of the file
+    paths: Vec<String>,
+    /// The `[[overrides]]` tables, in the order they appear in the file
+    overrides: Vec<TryConf>,
+    /// The entries of the [`DisallowedPath`] lists, to set their spans
+    disallowed_path_spans: Option<DisallowedPathSpans>,
     errors: Vec<ConfError>,
     warnings: Vec<ConfError>,
 }
 
 impl TryConf {
-    fn from_toml_error(file: &SourceFile, error: &toml::de::Error) -> Self {
+    fn from_toml_error(src: &str, error: &toml::de::Error) -> Self {
         Self {
-            conf: Conf::default(),
-            errors: vec![ConfError::from_toml(file, error)],
-            warnings: vec![],
+            errors: vec![ConfError::from_toml(src, error)],
+            ..Self::default()
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct ConfError {
     message: String,
-    span: Span,
+    span: Range<usize>,
 }
 
 impl ConfError {
-    fn from_toml(file: &SourceFile, error: &toml::de::Error) -> Self {
-        let span = error.span().unwrap_or(0..file.source_len.0 as usize);
-        Self::spanned(file, error.message(), span)
+    fn from_toml(src: &str, error: &toml::de::Error) -> Self {
+        let span = error.span().unwrap_or(0..src.len());
+        Self::spanned(error.message(), span)
     }
 
-    fn spanned(file: &SourceFile, message: impl Into<String>, span: Range<usize>) -> Self {
+    fn spanned(message: impl Into<String>, span: Range<usize>) -> Self {
         Self {
             message: message.into(),
-            span: Span::new(
-                file.start_pos + BytePos::from_usize(span.start),
-                file.start_pos + BytePos::from_usize(span.end),
-                SyntaxContext::root(),
-                None,
-            ),
+            span,
         }
     }
 }
 
+/// Converts a byte range of `file` to a `Span`
+fn file_span(file: &SourceFile, span: Range<usize>) -> Span {
+    Span::new(
+        file.start_pos + BytePos::from_usize(span.start),
+        file.start_pos + BytePos::from_usize(span.end),
+        SyntaxContext::root(),
+        None,
+    )
+}
+
+/// A lint level set in the `[lints]` table of a configuration file
+#[derive(Clone, Debug)]
+pub struct LintConfig {
+    /// The full name of the lint or lint group, e.g. `clippy::needless_borrow`
+    pub name: String,
+    pub level: Level,
+    /// The span of the key in the configuration file
+    pub span: Span,
+}
+
+/// An entry of the `[lints]` table, the span is the byte range of the key
+#[derive(Clone)]
+struct LintEntry {
+    name: String,
+    level: Level,
+    span: Range<usize>,
+}
+
+/// Converts a key of the `[lints]` table to the full lint name, `needless-borrow` and
+/// `clippy::needless_borrow` both become `clippy::needless_borrow`
+fn lint_name(key: &str) -> String {
+    format!(
+        "clippy::{}",
+        key.strip_prefix("clippy::")
+            .unwrap_or(key)
+            .replace('-', "_")
+            .to_ascii_lowercase()
+    )
+}
+
+fn lint_level(value: &toml::Value) -> Option<Level> {
+    match value.as_str()? {
+        "allow" => Some(Level::Allow),
+        "warn" => Some(Level::Warn),
+        "deny" => Some(Level::Deny),
+        "forbid" => Some(Level::Forbid),
+        _ => None,
+    }
+}
+
+/// The index of the `[[overrides]]` table that applies to each source file of the crate, by the
+/// index of the file in the source map. It's filled by the first [`Conf::for_span`] call, so that
+/// the path of each file is only canonicalized and matched once.
+#[derive(Clone, Default)]
+pub struct OverrideIndices(OnceLock<Vec<Option<usize>>>);
+
+/// The values of an `[[overrides]]` table, the values it doesn't set are the ones of the
+/// configuration that contains it
+#[derive(Clone)]
+pub struct PathOverride {
+    /// The directory of the configuration file, the globs in `paths` are relative to it
+    dir: PathBuf,
+    paths: Vec<String>,
+    conf: Conf,
+}
+
+impl PathOverride {
+    fn matches(&self, path: &Path) -> bool {
+        let Ok(relative) = path.strip_prefix(&self.dir) else {
+            return false;
+        };
+        let components: Option<Vec<&str>> = relative
+            .components()
+            .map(|component| match component {
+                Component::Normal(name) => name.to_str(),
+                _ => None,
+            })
+            .collect();
+
+        components.is_some_and(|components| {
+            self.paths.iter().any(|glob| {
+                let glob: Vec<&str> = glob.split('/').collect();
+                glob_matches(&glob, &components)
+            })
+        })
+    }
+}
+
+/// Matches the components of a relative path against the components of a glob, `**` matches any
+/// number of components, `*` any number of characters in a component, and `?` a single character
+fn glob_matches(glob: &[&str], path: &[&str]) -> bool {
+    match glob.split_first() {
+        None => path.is_empty(),
+        Some((&"**", glob_rest)) => (0..=path.len()).any(|skip| glob_matches(glob_rest, &path[skip..])),
+        Some((pattern, glob_rest)) => path.split_first().is_some_and(|(name, path_rest)| {
+            let name: Vec<char> = name.chars().collect();
+            let pattern: Vec<char> = pattern.chars().collect();
+            component_matches(&pattern, &name) && glob_matches(glob_rest, path_rest)
+        }),
+    }
+}
+
+fn component_matches(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skip| component_matches(rest, &name[skip..])),
+        Some(('?', rest)) => !name.is_empty() && component_matches(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && component_matches(rest, &name[1..]),
+    }
+}
+
 macro_rules! wrap_option {
     () => {
         None
@@ -106,11 +226,22 @@ macro_rules! define_Conf {
         $(#[doc = $doc:literal])+
         $(#[conf_deprecated($dep:literal, $new_conf:ident)])?
         $(#[default_text = $default_text:expr])?
+        // `$overridable` never matches anything, it's only there so that the marker can be
+        // repeated on
+        $(#[overridable $($overridable:tt)?])?
         ($name:ident: $ty:ty = $default:expr),
     )*) => {
         /// Clippy lint configuration
+        #[derive(Clone)]
         pub struct Conf {
             $($(#[doc = $doc])+ pub $name: $ty,)*
+            /// The lint levels set in the `[lints]` table
+            pub lints: Vec<LintConfig>,
+            /// The `[[overrides]]` tables, use [`Conf::for_span`] to get the values that apply to a
+            /// file
+            pub overrides: Vec<PathOverride>,
+            /// The cache of [`Conf::for_span`], it isn't read from the configuration file
+            pub override_indices: OverrideIndices,
         }
 
         mod defaults {
@@ -120,49 +251,84 @@ macro_rules! define_Conf {
 
         impl Default for Conf {
             fn default() -> Self {
-                Self { $($name: defaults::$name(),)* }
+                Self {
+                    $($name: defaults::$name(),)*
+                    lints: Vec::new(),
+                    overrides: Vec::new(),
+                    override_indices: OverrideIndices::default(),
+                }
             }
         }
 
         #[derive(Deserialize)]
         #[serde(field_identifier, rename_all = "kebab-case")]
         #[allow(non_camel_case_types)]
-        enum Field { $($name,)* third_party, }
+        enum Field { $($name,)* third_party, inherit, lints, overrides, paths, }
 
-        struct ConfVisitor<'a>(&'a SourceFile);
+        /// The keys that can be set in an `[[overrides]]` table, the ones marked `#[overridable]`.
+        /// The lints using these values have to get them from [`Conf::for_span`].
+        const OVERRIDABLE_KEYS: &[&str] = &["paths", $($(stringify!($name), $($overridable)?)?)*];
 
-        impl<'de> Visitor<'de> for ConfVisitor<'_> {
+        impl Field {
+            /// Whether the field can be set in an `[[overrides]]` table, see [`OVERRIDABLE_KEYS`]
+            fn is_overridable(&self) -> bool {
+                matches!(self, Self::paths $($(| Self::$name $($overridable)?)?)*)
+            }
+        }
+
+        /// Deserializes the top level table of a configuration file, or one of its `[[overrides]]`
+        /// tables if the field is `true`
+        struct ConfVisitor(bool);
+
+        impl<'de> Visitor<'de> for ConfVisitor {
             type Value = TryConf;
 
             fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("Conf")
             }
 
+            #[allow(clippy::too_many_lines)]
             fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error> where V: MapAccess<'de> {
                 let mut errors = Vec::new();
                 let mut warnings = Vec::new();
+                let mut inherit = None;
+                let mut lints = Vec::new();
+                let mut paths = None;
+                let mut overrides = Vec::new();
+                let mut overrides_span = None;
                 $(let mut $name = None;)*
                 // could get `Field` here directly, but get `String` first for diagnostics
                 while let Some(name) = map.next_key::<toml::Spanned<String>>()? {
                     match Field::deserialize(name.get_ref().as_str().into_deserializer()) {
                         Err(e) => {
                             let e: FieldError = e;
-                            errors.push(ConfError::spanned(self.0, e.0, name.span()));
+                            errors.push(ConfError::spanned(e.0, name.span()));
+                        }
+                        Ok(field) if self.0 && !field.is_overridable() => {
+                            errors.push(ConfError::spanned(
+                                format!(
+                                    "`{}` can't be set in `[[overrides]]`, the keys that can be set are: {}",
+                                    name.get_ref(),
+                                    OVERRIDABLE_KEYS.iter().map(|key| format!("`{}`", key.replace('_', "-"))).collect::<Vec<_>>().join(", "),
+                                ),
+                                name.span(),
+                            ));
+                            drop(map.next_value::<IgnoredAny>());
                         }
                         $(Ok(Field::$name) => {
-                            $(warnings.push(ConfError::spanned(self.0, format!("deprecated field `{}`. {}", name.get_ref(), $dep), name.span()));)?
+                            $(warnings.push(ConfError::spanned(format!("deprecated field `{}`. {}", name.get_ref(), $dep), name.span()));)?
                             let raw_value = map.next_value::<toml::Spanned<toml::Value>>()?;
                             let value_span = raw_value.span();
                             match <$ty>::deserialize(raw_value.into_inner()) {
-                                Err(e) => errors.push(ConfError::spanned(self.0, e.to_string().replace('\n', " ").trim(), value_span)),
+                                Err(e) => errors.push(ConfError::spanned(e.to_string().replace('\n', " ").trim(), value_span)),
                                 Ok(value) => match $name {
-                                    Some(_) => errors.push(ConfError::spanned(self.0, format!("duplicate field `{}`", name.get_ref()), name.span())),
+                                    Some(_) => errors.push(ConfError::spanned(format!("duplicate field `{}`", name.get_ref()), name.span())),
                                     None => {
                                         $name = Some(value);
                                         // $new_conf is the same as one of the defined `$name`s, so
                                         // this variable is defined in line 2 of this function.
                                         $(match $new_conf {
-                                            Some(_) => errors.push(ConfError::spanned(self.0, concat!(
+                                            Some(_) => errors.push(ConfError::spanned(concat!(
                                                 "duplicate field `", stringify!($new_conf),
                                                 "` (provided as `", stringify!($name), "`)"
                                             ), name.span())),
@@ -173,11 +339,97 @@ macro_rules! define_Conf {
                             }
                         })*
                         // ignore contents of the third_party key
-                        Ok(Field::third_party) => drop(map.next_value::<IgnoredAny>())
+                        Ok(Field::third_party) => drop(map.next_value::<IgnoredAny>()),
+                        Ok(Field::inherit) => {
+                            let raw_value = map.next_value::<toml::Spanned<toml::Value>>()?;
+                            let value_span = raw_value.span();
+                            match bool::deserialize(raw_value.into_inner()) {
+                                Err(e) => errors.push(ConfError::spanned(e.to_string().replace('\n', " ").trim(), value_span)),
+                                Ok(value) => if inherit.replace(value).is_some() {
+                                    errors.push(ConfError::spanned("duplicate field `inherit`", name.span()));
+                                },
+                            }
+                        },
+                        Ok(Field::lints) => {
+                            let table: BTreeMap<toml::Spanned<String>, toml::Spanned<toml::Value>> = map.next_value()?;
+                            for (key, value) in table {
+                                let lint_name = lint_name(key.get_ref());
+                                if lints.iter().any(|lint: &LintEntry| lint.name == lint_name) {
+                                    errors.push(ConfError::spanned(format!("duplicate lint `{lint_name}`"), key.span()));
+                                } else if let Some(level) = lint_level(value.get_ref()) {
+                                    lints.push(LintEntry { name: lint_name, level, span: key.span() });
+                                } else {
+                                    errors.push(ConfError::spanned(
+                                        "invalid lint level, expected one of `allow`, `warn`, `deny` or `forbid`",
+                                        value.span(),
+                                    ));
+                                }
+                            }
+                        },
+                        Ok(Field::overrides) => {
+                            overrides_span = Some(name.span());
+                            overrides = map.next_value_seed(OverridesSeed)?;
+                        },
+                        Ok(Field::paths) if !self.0 => {
+                            errors.push(ConfError::spanned("`paths` can only be set in `[[overrides]]`", name.span()));
+                            drop(map.next_value::<IgnoredAny>());
+                        },
+                        Ok(Field::paths) => {
+                            let raw_value = map.next_value::<toml::Spanned<toml::Value>>()?;
+                            let value_span = raw_value.span();
+                            match <Vec<String>>::deserialize(raw_value.into_inner()) {
+                                Err(e) => errors.push(ConfError::spanned(e.to_string().replace('\n', " ").trim(), value_span)),
+                                Ok(value) => if paths.replace(value).is_some() {
+                                    errors.push(ConfError::spanned("duplicate field `paths`", name.span()));
+                                },
+                            }
+                        },
                     }
                 }
-                let conf = Conf { $($name: $name.unwrap_or_else(defaults::$name),)* };
-                Ok(TryConf { conf, errors, warnings })
+                for table in &mut overrides {
+                    if table.paths.is_empty()
+                        && let Some(span) = overrides_span.clone()
+                    {
+                        errors.push(ConfError::spanned("missing field `paths` in `[[overrides]]`", span));
+                    }
+                    errors.append(&mut table.errors);
+                    warnings.append(&mut table.warnings);
+                }
+                let mut set_fields = FxHashSet::default();
+                $(if $name.is_some() {
+                    set_fields.insert(stringify!($name));
+                })*
+                let conf = Conf {
+                    $($name: $name.unwrap_or_else(defaults::$name),)*
+                    lints: Vec::new(),
+                    overrides: Vec::new(),
+                    override_indices: OverrideIndices::default(),
+                };
+                Ok(TryConf {
+                    conf,
+                    set_fields,
+                    inherit: inherit.unwrap_or_default(),
+                    lints,
+                    paths: paths.unwrap_or_default(),
+                    overrides,
+                    disallowed_path_spans: None,
+                    errors,
+                    warnings,
+                })
+            }
+        }
+
+        impl Conf {
+            /// Replaces every value that is not in `set_fields` with the one from `parent`, and adds
+            /// the lint levels of `parent` that are not set in `self`
+            fn inherit_from(&mut self, set_fields: &FxHashSet<&'static str>, parent: Conf) {
+                $(if !set_fields.contains(stringify!($name)) {
+                    self.$name = parent.$name;
+                })*
+                let lints: Vec<_> = parent.lints.into_iter().filter(|parent_lint| {
+                    !self.lints.iter().any(|lint| lint.name == parent_lint.name)
+                }).collect();
+                self.lints.extend(lints);
             }
         }
 
@@ -249,7 +501,7 @@ define_Conf! {
     ///
     /// Suppress lints whenever the suggested change would cause breakage for other crates.
     (avoid_breaking_exported_api: bool = true),
-    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD, MANUAL_HASH_ONE, ITER_KV_MAP.
+    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD, MANUAL_HASH_ONE, ITER_KV_MAP, CAST_LOSSLESS, CAST_SLICE_DIFFERENT_SIZES, CAST_SLICE_FROM_RAW_PARTS, PTR_CAST_CONSTNESS, EXPLICIT_ITER_LOOP, IS_DIGIT_ASCII_RADIX, MANUAL_MAIN_SEPARATOR_STR, NEEDLESS_BORROWS_FOR_GENERIC_ARGS, PATH_ENDS_WITH_EXT, STRING_LIT_CHARS_ANY, UNNECESSARY_TO_OWNED, USELESS_VEC, INCOMPATIBLE_MSRV, REGEX_CREATION_IN_LOOPS.
     ///
     /// The minimum rust version that the project supports. Defaults to the `rust-version` field in `Cargo.toml`
     #[default_text = ""]
@@ -262,10 +514,12 @@ define_Conf! {
     /// Lint: COGNITIVE_COMPLEXITY.
     ///
     /// The maximum cognitive complexity a function can have
+    #[overridable]
     (cognitive_complexity_threshold: u64 = 25),
     /// Lint: EXCESSIVE_NESTING.
     ///
     /// The maximum amount of nesting a block can reside in
+    #[overridable]
     (excessive_nesting_threshold: u64 = 0),
     /// DEPRECATED LINT: CYCLOMATIC_COMPLEXITY.
     ///
@@ -297,6 +551,7 @@ define_Conf! {
     /// Lint: TOO_MANY_ARGUMENTS.
     ///
     /// The maximum number of argument a function or method can have
+    #[overridable]
     (too_many_arguments_threshold: u64 = 7),
     /// Lint: TYPE_COMPLEXITY.
     ///
@@ -343,6 +598,7 @@ define_Conf! {
     /// Lint: TOO_MANY_LINES.
     ///
     /// The maximum number of lines a function or method can have
+    #[overridable]
     (too_many_lines_threshold: u64 = 100),
     /// Lint: LARGE_STACK_ARRAYS, LARGE_CONST_ARRAYS.
     ///
@@ -384,6 +640,12 @@ define_Conf! {
     ///
     /// The list of disallowed types, written as fully qualified paths.
     (disallowed_types: Vec<DisallowedPath> = Vec::new()),
+    /// Lint: DISALLOWED_MACROS, DISALLOWED_METHODS, DISALLOWED_TYPES, AWAIT_HOLDING_INVALID_TYPE, ASYNC_BLOCKING_CALL.
+    ///
+    /// Whether the paths in `disallowed-macros`, `disallowed-methods`, `disallowed-types`,
+    /// `await-holding-invalid-types`, `blocking-paths` and `blocking-offload-paths` that don't match
+    /// any item are reported as errors instead of warnings.
+    (error_on_unresolved_disallowed_paths: bool = false),
     /// Lint: UNREADABLE_LITERAL.
     ///
     /// Should the fraction of a decimal be linted to include separators.
@@ -436,10 +698,12 @@ define_Conf! {
     /// Lint: EXPECT_USED.
     ///
     /// Whether `expect` should be allowed in test functions or `#[cfg(test)]`
+    #[overridable]
     (allow_expect_in_tests: bool = false),
     /// Lint: UNWRAP_USED.
     ///
     /// Whether `unwrap` should be allowed in test functions or `#[cfg(test)]`
+    #[overridable]
     (allow_unwrap_in_tests: bool = false),
     /// Lint: DBG_MACRO.
     ///
@@ -547,6 +811,44 @@ define_Conf! {
     ///
     /// Whether to also run the listed lints on private items.
     (check_private_items: bool = false),
+    /// Lint: LARGE_REGEX.
+    ///
+    /// The maximum estimated compiled size of a regex, roughly the number of states of its automaton
+    (large_regex_threshold: u64 = 10_000),
+    /// Lint: MISSING_EXAMPLES_DOC.
+    ///
+    /// The kinds of items that should have an `# Examples` section in their docs: `fn`, `method`,
+    /// `struct`, `enum`, `union`, `trait`, `type_alias`, `const`, `static`, `macro` and `mod`.
+    (missing_examples_doc_item_kinds: Vec<DocItemKind> = vec![
+        DocItemKind::Fn,
+        DocItemKind::Method,
+        DocItemKind::Struct,
+        DocItemKind::Enum,
+        DocItemKind::Union,
+        DocItemKind::Trait,
+        DocItemKind::Macro,
+    ]),
+    /// Lint: MISSING_EXAMPLES_DOC.
+    ///
+    /// The minimum visibility of the items that should have an `# Examples` section in their docs:
+    /// `"pub"` for the items exported from the crate, `"pub(crate)"` or `"private"`.
+    (missing_examples_doc_min_visibility: DocVisibility = DocVisibility::Public),
+    /// Lint: MISSING_PANICS_DOC.
+    ///
+    /// How many levels of calls to the functions of the current crate are followed to find a
+    /// possible panic. With `0`, only the body of the function itself is checked.
+    (missing_panics_doc_call_depth: u64 = 0),
+    /// Lint: ASYNC_BLOCKING_CALL.
+    ///
+    /// The paths of functions that block the thread, in addition to the built-in ones. A path to a
+    /// module or a type matches all the functions in it.
+    (blocking_paths: Vec<DisallowedPath> = Vec::new()),
+    /// Lint: ASYNC_BLOCKING_CALL.
+    ///
+    /// The paths of functions that run the closure passed to them on a thread where blocking is
+    /// allowed, in addition to the built-in ones like `tokio::task::spawn_blocking`. The blocking
+    /// calls in these closures are not linted.
+    (blocking_offload_paths: Vec<DisallowedPath> = Vec::new()),
 }
 
 /// Search for the configuration file.
@@ -555,16 +857,21 @@ define_Conf! {
 ///
 /// Returns any unexpected filesystem error encountered when searching for the config file
 pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
-    /// Possible filename to search for.
-    const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
-
     // Start looking for a config file in CLIPPY_CONF_DIR, or failing that, CARGO_MANIFEST_DIR.
     // If neither of those exist, use ".". (Update documentation if this priority changes)
-    let mut current = env::var_os("CLIPPY_CONF_DIR")
+    let current = env::var_os("CLIPPY_CONF_DIR")
         .or_else(|| env::var_os("CARGO_MANIFEST_DIR"))
         .map_or_else(|| PathBuf::from("."), PathBuf::from)
         .canonicalize()?;
 
+    lookup_conf_file_from(current)
+}
+
+/// Search for the configuration file in `current` and its ancestors.
+fn lookup_conf_file_from(mut current: PathBuf) -> io::Result<(Option<PathBuf>, Vec<String>)> {
+    /// Possible filename to search for.
+    const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
+
     let mut found_config: Option<PathBuf> = None;
     let mut warnings = vec![];
 
@@ -602,8 +909,52 @@ pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
     }
 }
 
-fn deserialize(file: &SourceFile) -> TryConf {
-    match toml::de::Deserializer::new(file.src.as_ref().unwrap()).deserialize_map(ConfVisitor(file)) {
+impl<'de> DeserializeSeed<'de> for ConfVisitor {
+    type Value = TryConf;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+/// Deserializes the array of `[[overrides]]` tables
+struct OverridesSeed;
+
+impl<'de> DeserializeSeed<'de> for OverridesSeed {
+    type Value = Vec<TryConf>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for OverridesSeed {
+    type Value = Vec<TryConf>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an array of tables")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut tables = Vec::new();
+        while let Some(table) = seq.next_element_seed(ConfVisitor(true))? {
+            tables.push(table);
+        }
+        Ok(tables)
+    }
+}
+
+fn deserialize(src: &str) -> TryConf {
+    match toml::de::Deserializer::new(src).deserialize_map(ConfVisitor(false)) {
         Ok(mut conf) => {
             extend_vec_if_indicator_present(&mut conf.conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
             extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
@@ -614,9 +965,46 @@ fn deserialize(file: &SourceFile) -> TryConf {
                     .extend(DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS.iter().map(ToString::to_string));
             }
 
+            conf.disallowed_path_spans = toml::from_str(src).ok();
+
             conf
         },
-        Err(e) => TryConf::from_toml_error(file, &e),
+        Err(e) => TryConf::from_toml_error(src, &e),
+    }
+}
+
+/// The entries of the [`DisallowedPath`] lists of a configuration file
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct DisallowedPathSpans {
+    #[serde(default)]
+    disallowed_macros: Vec<toml::Spanned<IgnoredAny>>,
+    #[serde(default)]
+    disallowed_methods: Vec<toml::Spanned<IgnoredAny>>,
+    #[serde(default)]
+    disallowed_types: Vec<toml::Spanned<IgnoredAny>>,
+    #[serde(default)]
+    await_holding_invalid_types: Vec<toml::Spanned<IgnoredAny>>,
+    #[serde(default)]
+    blocking_paths: Vec<toml::Spanned<IgnoredAny>>,
+    #[serde(default)]
+    blocking_offload_paths: Vec<toml::Spanned<IgnoredAny>>,
+}
+
+/// Sets the spans of the [`DisallowedPath`]s set in `file`, so that the paths that don't match any
+/// item can be reported against their entry
+fn set_disallowed_path_spans(file: &SourceFile, conf: &mut Conf, spans: DisallowedPathSpans) {
+    for (paths, spans) in [
+        (&mut conf.disallowed_macros, spans.disallowed_macros),
+        (&mut conf.disallowed_methods, spans.disallowed_methods),
+        (&mut conf.disallowed_types, spans.disallowed_types),
+        (&mut conf.await_holding_invalid_types, spans.await_holding_invalid_types),
+        (&mut conf.blocking_paths, spans.blocking_paths),
+        (&mut conf.blocking_offload_paths, spans.blocking_offload_paths),
+    ] {
+        for (path, span) in paths.iter_mut().zip(spans) {
+            path.set_span(file_span(file, span.span()));
+        }
     }
 }
 
@@ -627,14 +1015,15 @@ fn extend_vec_if_indicator_present(vec: &mut Vec<String>, default: &[&str]) {
 }
 
 impl Conf {
-    pub fn read(sess: &Session, path: &io::Result<(Option<PathBuf>, Vec<String>)>) -> &'static Conf {
+    /// Reads the configuration from the parsed `files`, reporting their errors
+    pub fn read(sess: &Session, files: &ConfFiles) -> &'static Conf {
         static CONF: OnceLock<Conf> = OnceLock::new();
-        CONF.get_or_init(|| Conf::read_inner(sess, path))
+        CONF.get_or_init(|| Conf::read_inner(sess, files))
     }
 
-    fn read_inner(sess: &Session, path: &io::Result<(Option<PathBuf>, Vec<String>)>) -> Conf {
-        match path {
-            Ok((_, warnings)) => {
+    fn read_inner(sess: &Session, files: &ConfFiles) -> Conf {
+        match &files.lookup {
+            Ok(warnings) => {
                 for warning in warnings {
                     sess.warn(warning.clone());
                 }
@@ -644,42 +1033,239 @@ impl Conf {
             },
         }
 
-        let TryConf {
-            mut conf,
-            errors,
-            warnings,
-        } = match path {
-            Ok((Some(path), _)) => match sess.source_map().load_file(path) {
-                Ok(file) => deserialize(&file),
-                Err(error) => {
-                    sess.err(format!("failed to read `{}`: {error}", path.display()));
-                    TryConf::default()
-                },
-            },
-            _ => TryConf::default(),
-        };
+        let mut conf = read_file_chain(sess, &files.files);
 
         conf.msrv.read_cargo(sess);
 
-        // all conf errors are non-fatal, we just use the default conf in case of error
-        for error in errors {
-            sess.span_err(
-                error.span,
-                format!("error reading Clippy's configuration file: {}", error.message),
-            );
+        conf
+    }
+
+    /// Returns the configuration that applies to the file containing `span`, that is the values of
+    /// the first `[[overrides]]` table with a glob matching the path of the file, or `self` if
+    /// there is none.
+    pub fn for_span(&self, sess: &Session, span: Span) -> &Conf {
+        if self.overrides.is_empty() {
+            return self;
         }
 
-        for warning in warnings {
-            sess.span_warn(
-                warning.span,
-                format!("error reading Clippy's configuration file: {}", warning.message),
-            );
+        let source_map = sess.source_map();
+        let file = source_map.lookup_source_file_idx(span.source_callsite().lo());
+        let indices = self.override_indices.0.get_or_init(|| {
+            source_map
+                .files()
+                .iter()
+                .map(|file| self.override_index(file))
+                .collect()
+        });
+        // the files loaded after the first call aren't cached
+        let index = match indices.get(file) {
+            Some(&index) => index,
+            None => self.override_index(&source_map.files()[file]),
+        };
+        index.map_or(self, |index| &self.overrides[index].conf)
+    }
+
+    /// The index of the first `[[overrides]]` table with a glob matching the path of `file`
+    fn override_index(&self, file: &SourceFile) -> Option<usize> {
+        if file.is_imported() {
+            return None;
         }
+        let FileName::Real(name) = &file.name else {
+            return None;
+        };
+        let path = canonicalize(name.local_path()?);
+        self.overrides.iter().position(|table| table.matches(&path))
+    }
+}
 
-        conf
+/// Canonicalizes `path`, or makes it absolute if it doesn't exist. The directories of the
+/// `[[overrides]]` tables and the paths of the files are both canonicalized so that they match
+/// through symlinks.
+fn canonicalize(path: &Path) -> PathBuf {
+    fs::canonicalize(path)
+        .or_else(|_| env::current_dir().map(|dir| dir.join(path)))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// The configuration file found by [`lookup_conf_file`], and if it sets `inherit = true` the ones
+/// of its parent directories, read and parsed once before the compiler session is created.
+///
+/// The levels of their `[lints]` tables are passed to rustc with [`ConfFiles::lint_levels`], the
+/// rest of the configuration is read by [`Conf::read`], which reports the errors against the files.
+#[allow(clippy::module_name_repetitions)]
+pub struct ConfFiles {
+    /// The warnings of looking up the first configuration file, or the error
+    lookup: Result<Vec<String>, String>,
+    /// The files of the chain, starting with the closest one
+    files: Vec<ConfFile>,
+}
+
+struct ConfFile {
+    path: PathBuf,
+    /// The normalized contents of the file, or the error reading it
+    src: Result<String, String>,
+    try_conf: TryConf,
+    /// The warnings of looking up the configuration file of the parent directories, or the error
+    parent_lookup: Result<Vec<String>, String>,
+}
+
+impl ConfFiles {
+    pub fn read(path: io::Result<(Option<PathBuf>, Vec<String>)>) -> Self {
+        let (lookup, mut next) = match path {
+            Ok((path, warnings)) => (Ok(warnings), path),
+            Err(error) => (Err(error.to_string()), None),
+        };
+
+        let mut files = Vec::new();
+        while let Some(path) = next.take() {
+            let src = fs::read_to_string(&path)
+                .map(normalize_src)
+                .map_err(|error| error.to_string());
+            let try_conf = src.as_deref().map_or_else(|_| TryConf::default(), deserialize);
+
+            // Continue the search in the parent of the directory containing the current file
+            let mut parent_lookup = Ok(Vec::new());
+            if try_conf.inherit
+                && let Some(parent_dir) = path.parent().and_then(Path::parent)
+            {
+                parent_lookup = match lookup_conf_file_from(parent_dir.to_path_buf()) {
+                    Ok((parent, warnings)) => {
+                        next = parent;
+                        Ok(warnings)
+                    },
+                    Err(error) => Err(error.to_string()),
+                };
+            }
+
+            files.push(ConfFile {
+                path,
+                src,
+                try_conf,
+                parent_lookup,
+            });
+        }
+
+        Self { lookup, files }
+    }
+
+    /// The lint levels set in the `[lints]` tables, the levels set in a file take precedence over
+    /// the ones of its parent directories.
+    ///
+    /// They're passed to rustc the same way as `-A`/`-W`/`-D`/`-F` flags.
+    pub fn lint_levels(&self) -> Vec<(String, Level)> {
+        let mut levels: Vec<(String, Level)> = Vec::new();
+        for lint in self.files.iter().flat_map(|file| &file.try_conf.lints) {
+            if !levels.iter().any(|(name, _)| *name == lint.name) {
+                levels.push((lint.name.clone(), lint.level));
+            }
+        }
+        levels
     }
 }
 
+/// Removes the BOM and replaces `\r\n` with `\n` like rustc does for the files of the source map,
+/// so that the byte ranges of the parsed file are the same in the [`SourceFile`]
+fn normalize_src(mut src: String) -> String {
+    if src.starts_with('\u{feff}') {
+        src.drain(..3);
+    }
+    if src.contains('\r') {
+        src = src.replace("\r\n", "\n");
+    }
+    src
+}
+
+/// Reads the configuration of the chain of `files`, adding them to the source map to report their
+/// errors.
+///
+/// Values set in a file take precedence over the ones set in the files of its parent directories.
+fn read_file_chain(sess: &Session, files: &[ConfFile]) -> Conf {
+    let mut chain = Vec::new();
+    let mut overrides = Vec::new();
+    for file in files {
+        let mut try_conf = match &file.src {
+            Ok(src) => {
+                let source_file = sess.source_map().new_source_file(file.path.clone().into(), src.clone());
+                let mut try_conf = file.try_conf.clone();
+
+                // all conf errors are non-fatal, we just use the default conf in case of error
+                for error in &try_conf.errors {
+                    sess.span_err(
+                        file_span(&source_file, error.span.clone()),
+                        format!("error reading Clippy's configuration file: {}", error.message),
+                    );
+                }
+
+                for warning in &try_conf.warnings {
+                    sess.span_warn(
+                        file_span(&source_file, warning.span.clone()),
+                        format!("error reading Clippy's configuration file: {}", warning.message),
+                    );
+                }
+
+                if let Some(spans) = try_conf.disallowed_path_spans.take() {
+                    set_disallowed_path_spans(&source_file, &mut try_conf.conf, spans);
+                }
+                try_conf.conf.lints = try_conf
+                    .lints
+                    .drain(..)
+                    .map(|LintEntry { name, level, span }| LintConfig {
+                        name,
+                        level,
+                        span: file_span(&source_file, span),
+                    })
+                    .collect();
+                try_conf
+            },
+            Err(error) => {
+                sess.err(format!("failed to read `{}`: {error}", file.path.display()));
+                TryConf::default()
+            },
+        };
+
+        // The overrides of a file take precedence over the ones of its parent directories
+        let dir = canonicalize(file.path.parent().unwrap_or(Path::new("")));
+        overrides.extend(try_conf.overrides.drain(..).map(|table| (dir.clone(), table)));
+
+        chain.push(try_conf);
+
+        match &file.parent_lookup {
+            Ok(warnings) => {
+                for warning in warnings {
+                    sess.warn(warning.clone());
+                }
+            },
+            Err(error) => {
+                sess.err(format!("error finding Clippy's configuration file: {error}"));
+            },
+        }
+    }
+
+    chain
+        .into_iter()
+        .rev()
+        .map(|TryConf { conf, set_fields, .. }| (conf, set_fields))
+        .reduce(|(parent, _), (mut conf, set_fields)| {
+            conf.inherit_from(&set_fields, parent);
+            (conf, set_fields)
+        })
+        .map_or_else(Conf::default, |(mut conf, _)| {
+            conf.overrides = overrides
+                .into_iter()
+                .map(|(dir, table)| {
+                    let mut override_conf = table.conf;
+                    override_conf.inherit_from(&table.set_fields, conf.clone());
+                    PathOverride {
+                        dir,
+                        paths: table.paths,
+                        conf: override_conf,
+                    }
+                })
+                .collect();
+            conf
+        })
+}
+
 const SEPARATOR_WIDTH: usize = 4;
 
 #[derive(Debug)]
@@ -792,4 +1378,24 @@ mod tests {
             "Configuration variable lacks test: {names:?}\nAdd a test to `tests/ui-toml`"
         );
     }
+
+    #[test]
+    fn override_globs() {
+        let matches = |glob: &str, path: &str| {
+            let glob: Vec<&str> = glob.split('/').collect();
+            let path: Vec<&str> = path.split('/').collect();
+            super::glob_matches(&glob, &path)
+        };
+
+        assert!(matches("tests/**", "tests/ui.rs"));
+        assert!(matches("tests/**", "tests/ui/main.rs"));
+        assert!(!matches("tests/**", "src/tests.rs"));
+        assert!(matches("**/generated/*.rs", "generated/a.rs"));
+        assert!(matches("**/generated/*.rs", "src/generated/a.rs"));
+        assert!(!matches("**/generated/*.rs", "src/generated/a/b.rs"));
+        assert!(matches("src/*_test.rs", "src/parser_test.rs"));
+        assert!(!matches("src/*_test.rs", "src/parser.rs"));
+        assert!(matches("benches/b?.rs", "benches/b1.rs"));
+        assert!(!matches("benches/b?.rs", "benches/b10.rs"));
+    }
 }
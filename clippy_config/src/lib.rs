@@ -19,5 +19,5 @@ mod metadata;
 pub mod msrvs;
 pub mod types;
 
-pub use conf::{get_configuration_metadata, lookup_conf_file, Conf};
+pub use conf::{get_configuration_metadata, lookup_conf_file, Conf, ConfFiles, LintConfig, PathOverride};
 pub use metadata::ClippyConfiguration;
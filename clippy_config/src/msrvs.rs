@@ -1,8 +1,13 @@
 use rustc_ast::Attribute;
+use rustc_data_structures::fx::FxHashMap;
 use rustc_semver::RustcVersion;
+use rustc_session::lint::Lint;
 use rustc_session::Session;
-use rustc_span::{sym, Symbol};
+use rustc_span::{sym, Span, Symbol};
 use serde::Deserialize;
+use std::cell::RefCell;
+use std::env;
+use std::sync::OnceLock;
 
 macro_rules! msrv_aliases {
     ($($major:literal,$minor:literal,$patch:literal {
@@ -17,7 +22,7 @@ macro_rules! msrv_aliases {
 // names may refer to stabilized feature flags or library items
 msrv_aliases! {
     1,71,0 { TUPLE_ARRAY_CONVERSIONS, BUILD_HASHER_HASH_ONE }
-    1,70,0 { OPTION_IS_SOME_AND, BINARY_HEAP_RETAIN }
+    1,70,0 { OPTION_IS_SOME_AND, BINARY_HEAP_RETAIN, ONCE_LOCK }
     1,68,0 { PATH_MAIN_SEPARATOR_STR }
     1,65,0 { LET_ELSE, POINTER_CAST_CONSTNESS }
     1,62,0 { BOOL_THEN_SOME, DEFAULT_ENUM_ATTRIBUTE }
@@ -51,6 +56,57 @@ msrv_aliases! {
     1,15,0 { MAYBE_BOUND_IN_WHERE }
 }
 
+/// The start of the note that `cargo clippy --msrv-report` adds to the diagnostics that would be
+/// suppressed by the MSRV, followed by the MSRV and the version the lint requires
+pub const MSRV_REPORT_NOTE: &str = "this lint is suppressed by the MSRV";
+
+/// The MSRV and the version required by a failed [`Msrv::meets_or_report`] check
+type UnmetCheck = (RustcVersion, RustcVersion);
+
+thread_local! {
+    /// With `--msrv-report`, the MSRV and the required version of the failed
+    /// [`Msrv::meets_or_report`] checks by the lint name and span, until a diagnostic of the lint is
+    /// emitted for the span or the body or item containing it has been linted
+    static UNMET: RefCell<FxHashMap<(&'static str, Span), UnmetCheck>> = RefCell::new(FxHashMap::default());
+}
+
+/// Whether `cargo clippy --msrv-report` is running. Then the lints that are gated on the MSRV are
+/// not suppressed, instead their diagnostics are noted with the version they require.
+pub fn is_report_mode() -> bool {
+    static REPORT_MODE: OnceLock<bool> = OnceLock::new();
+    *REPORT_MODE.get_or_init(|| env::var_os("CLIPPY_MSRV_REPORT").is_some())
+}
+
+/// With `--msrv-report`, returns the MSRV and the required version of the failed
+/// [`Msrv::meets_or_report`] check of `lint` for the span of its diagnostic, or else of a check
+/// whose span overlaps it
+pub fn take_unmet(lint: &'static Lint, spans: &[Span]) -> Option<UnmetCheck> {
+    UNMET.with_borrow_mut(|unmet| {
+        if unmet.is_empty() {
+            return None;
+        }
+        let key = spans
+            .iter()
+            .map(|&span| (lint.name, span))
+            .find(|key| unmet.contains_key(key))
+            .or_else(|| {
+                unmet.keys().copied().find(|&(name, check)| {
+                    name == lint.name
+                        && spans
+                            .iter()
+                            .any(|&span| check.contains(span) || span.contains(check) || check.overlaps(span))
+                })
+            })?;
+        unmet.remove(&key)
+    })
+}
+
+/// Forgets the failed [`Msrv::meets_or_report`] checks that no diagnostic was emitted for, called
+/// once a body or item has been linted
+pub fn clear_unmet() {
+    UNMET.with_borrow_mut(FxHashMap::clear);
+}
+
 /// Tracks the current MSRV from `clippy.toml`, `Cargo.toml` or set via `#[clippy::msrv]`
 #[derive(Debug, Clone)]
 pub struct Msrv {
@@ -75,7 +131,7 @@ impl Msrv {
     }
 
     pub fn read_cargo(&mut self, sess: &Session) {
-        let cargo_msrv = std::env::var("CARGO_PKG_RUST_VERSION")
+        let cargo_msrv = env::var("CARGO_PKG_RUST_VERSION")
             .ok()
             .and_then(|v| RustcVersion::parse(&v).ok());
 
@@ -100,6 +156,25 @@ impl Msrv {
         self.current().map_or(true, |version| version.meets(required))
     }
 
+    /// Checks the MSRV before `lint` checks `span`, for the lints that are skipped if the MSRV is
+    /// lower than `required`.
+    ///
+    /// With `cargo clippy --msrv-report`, the lint isn't skipped: this returns `true` and the
+    /// diagnostic the lint emits for `span` is noted with the MSRV and `required`. Use
+    /// [`Msrv::meets`] for the checks that only pick a suggestion.
+    pub fn meets_or_report(&self, lint: &'static Lint, span: Span, required: RustcVersion) -> bool {
+        let Some(msrv) = self.current().filter(|version| !version.meets(required)) else {
+            return true;
+        };
+        if is_report_mode() {
+            // a lint can check several spans before it emits a diagnostic for one of them, only a
+            // later check of the same span replaces a check
+            UNMET.with_borrow_mut(|unmet| unmet.insert((lint.name, span), (msrv, required)));
+            return true;
+        }
+        false
+    }
+
     fn parse_attr(sess: &Session, attrs: &[Attribute]) -> Option<RustcVersion> {
         let sym_msrv = Symbol::intern("msrv");
         let mut msrv_attrs = attrs.iter().filter(|attr| attr.path_matches(&[sym::clippy, sym_msrv]));
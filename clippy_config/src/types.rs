@@ -1,3 +1,4 @@
+use rustc_span::{Span, DUMMY_SP};
 use serde::de::{self, Deserializer, Visitor};
 use serde::{ser, Deserialize, Serialize};
 use std::fmt;
@@ -9,26 +10,53 @@ pub struct Rename {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[serde(from = "DisallowedPathEnum")]
+pub struct DisallowedPath {
+    path: String,
+    reason: Option<String>,
+    /// The span of the entry in the configuration file, [`DUMMY_SP`] if it isn't known
+    #[serde(skip)]
+    span: Span,
+}
+
+#[derive(Deserialize)]
 #[serde(untagged)]
-pub enum DisallowedPath {
+enum DisallowedPathEnum {
     Simple(String),
     WithReason { path: String, reason: Option<String> },
 }
 
+impl From<DisallowedPathEnum> for DisallowedPath {
+    fn from(value: DisallowedPathEnum) -> Self {
+        let (path, reason) = match value {
+            DisallowedPathEnum::Simple(path) => (path, None),
+            DisallowedPathEnum::WithReason { path, reason } => (path, reason),
+        };
+        Self {
+            path,
+            reason,
+            span: DUMMY_SP,
+        }
+    }
+}
+
 impl DisallowedPath {
     pub fn path(&self) -> &str {
-        let (Self::Simple(path) | Self::WithReason { path, .. }) = self;
-
-        path
+        &self.path
     }
 
     pub fn reason(&self) -> Option<String> {
-        match self {
-            Self::WithReason {
-                reason: Some(reason), ..
-            } => Some(format!("{reason} (from clippy.toml)")),
-            _ => None,
-        }
+        self.reason
+            .as_ref()
+            .map(|reason| format!("{reason} (from clippy.toml)"))
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub(crate) fn set_span(&mut self, span: Span) {
+        self.span = span;
     }
 }
 
@@ -39,7 +67,38 @@ pub enum MatchLintBehaviour {
     Never,
 }
 
-#[derive(Debug)]
+/// The kinds of items whose docs are checked by `missing_examples_doc`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocItemKind {
+    Fn,
+    Method,
+    Struct,
+    Enum,
+    Union,
+    Trait,
+    TypeAlias,
+    Const,
+    Static,
+    Macro,
+    Mod,
+}
+
+/// The minimum visibility of the items whose docs are checked by `missing_examples_doc`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DocVisibility {
+    /// Items that are exported from the crate
+    #[serde(rename = "pub")]
+    Public,
+    /// Items that are at least `pub(crate)`
+    #[serde(rename = "pub(crate)")]
+    Crate,
+    /// All items
+    #[serde(rename = "private")]
+    Private,
+}
+
+#[derive(Clone, Debug)]
 pub struct MacroMatcher {
     pub name: String,
     pub braces: (char, char),
@@ -40,6 +40,12 @@ fn main() {
                 matches.get_one::<String>("category").map(String::as_str),
                 matches.get_one::<String>("type").map(String::as_str),
                 matches.get_flag("msrv"),
+                matches.get_one::<new_lint::MsrvAlias>("msrv-alias"),
+                &matches
+                    .get_many::<new_lint::ConfOption>("config")
+                    .unwrap_or_default()
+                    .cloned()
+                    .collect::<Vec<_>>(),
             ) {
                 Ok(()) => update_lints::update(update_lints::UpdateMode::Change),
                 Err(e) => eprintln!("Unable to create lint: {e}"),
@@ -205,6 +211,24 @@ fn get_clap_config() -> ArgMatches {
                         .long("msrv")
                         .action(ArgAction::SetTrue)
                         .help("Add MSRV config code to the lint"),
+                    Arg::new("msrv-alias")
+                        .long("msrv-alias")
+                        .value_name("NAME[=VERSION]")
+                        .value_parser(new_lint::MsrvAlias::parse)
+                        .help(
+                            "Gate the lint on an MSRV alias of `clippy_config::msrvs`, e.g. `LET_ELSE`. \
+                            With a version, e.g. `LET_ELSE=1.65.0`, the alias is added if it doesn't exist. \
+                            Implies `--msrv`",
+                        ),
+                    Arg::new("config")
+                        .long("config")
+                        .value_name("NAME[: TYPE = DEFAULT]")
+                        .action(ArgAction::Append)
+                        .value_parser(new_lint::ConfOption::parse)
+                        .help(
+                            "Add a `clippy.toml` option to the lint, e.g. `--config 'foo_threshold: u64 = 10'`. \
+                            Without a type and default value, an existing option is used. Can be repeated",
+                        ),
                 ]),
             Command::new("setup")
                 .about("Support for setting up your personal development environment")
@@ -7,12 +7,109 @@ use std::io::prelude::*;
 use std::io::{self, ErrorKind};
 use std::path::{Path, PathBuf};
 
+const CONF_PATH: &str = "clippy_config/src/conf.rs";
+const MSRVS_PATH: &str = "clippy_config/src/msrvs.rs";
+
 struct LintData<'a> {
     pass: &'a str,
     name: &'a str,
     category: &'a str,
     ty: Option<&'a str>,
     project_root: PathBuf,
+    /// The `clippy.toml` options of the lint
+    conf: Vec<LintConf>,
+    /// The MSRV alias the lint is gated on
+    msrv_alias: Option<&'a str>,
+}
+
+/// A `clippy.toml` option of the lint, as it is declared in `define_Conf!`
+struct LintConf {
+    name: String,
+    ty: String,
+    default: String,
+}
+
+/// A `clippy.toml` option of the new lint, from `--config`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfOption {
+    name: String,
+    /// The type and the default value of a new option, `None` to use an existing one
+    new: Option<(String, String)>,
+}
+
+impl ConfOption {
+    /// Parses `name: type = default` for a new option, or `name` for an existing one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is not snake case, or if the default value is missing.
+    pub fn parse(arg: &str) -> Result<Self, String> {
+        let (name, new) = match arg.split_once(':') {
+            Some((name, rest)) => {
+                let (ty, default) = rest
+                    .split_once('=')
+                    .ok_or_else(|| format!("expected `name: type = default`, found `{arg}`"))?;
+                (name, Some((ty.trim().to_owned(), default.trim().to_owned())))
+            },
+            None => (arg, None),
+        };
+
+        let name = name.trim().replace('-', "_");
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(format!("invalid configuration option name `{name}`"));
+        }
+        if name == "msrv" {
+            return Err("use `--msrv` for the `msrv` option".into());
+        }
+        Ok(Self { name, new })
+    }
+}
+
+/// An alias of `clippy_config::msrvs`, from `--msrv-alias`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsrvAlias {
+    name: String,
+    /// The version of a new alias, `None` to use an existing one
+    version: Option<(u32, u32, u32)>,
+}
+
+impl MsrvAlias {
+    /// Parses `NAME=1.xx.0` for a new alias, or `NAME` for an existing one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is not upper case, or if the version is invalid.
+    pub fn parse(arg: &str) -> Result<Self, String> {
+        let (name, version) = match arg.split_once('=') {
+            Some((name, version)) => (name.trim(), Some(version.trim())),
+            None => (arg.trim(), None),
+        };
+
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(format!("invalid MSRV alias `{name}`, expected e.g. `LET_ELSE`"));
+        }
+        let version = version
+            .map(|version| {
+                let mut parts = version.split('.').map(str::parse::<u32>);
+                match (parts.next(), parts.next(), parts.next().unwrap_or(Ok(0)), parts.next()) {
+                    (Some(Ok(major)), Some(Ok(minor)), Ok(patch), None) => Ok((major, minor, patch)),
+                    _ => Err(format!("invalid version `{version}`, expected e.g. `1.65.0`")),
+                }
+            })
+            .transpose()?;
+        Ok(Self {
+            name: name.to_owned(),
+            version,
+        })
+    }
 }
 
 trait Context {
@@ -33,9 +130,13 @@ impl<T> Context for io::Result<T> {
 
 /// Creates the files required to implement and test a new lint and runs `update_lints`.
 ///
+/// With `--msrv-alias` the alias is added to `clippy_config/src/msrvs.rs`, and with `--config` the
+/// options are added to `clippy_config/src/conf.rs` with a test in `tests/ui-toml`.
+///
 /// # Errors
 ///
-/// This function errors out if the files couldn't be created or written to.
+/// This function errors out if the files couldn't be created or written to, or if the MSRV alias or
+/// the configuration options are invalid.
 #[allow(clippy::missing_panics_doc)]
 pub fn create(
     pass: &String,
@@ -43,25 +144,56 @@ pub fn create(
     category: Option<&str>,
     mut ty: Option<&str>,
     msrv: bool,
+    msrv_alias: Option<&MsrvAlias>,
+    conf: &[ConfOption],
 ) -> io::Result<()> {
     if category == Some("cargo") && ty.is_none() {
         // `cargo` is a special category, these lints should always be in `clippy_lints/src/cargo`
         ty = Some("cargo");
     }
 
+    let name = lint_name.expect("`name` argument is validated by clap");
+    let project_root = clippy_project_root();
+    let msrv = msrv || msrv_alias.is_some();
+
+    // the configuration files are checked before any file is created, and written after
+    let msrvs_rs = match msrv_alias {
+        Some(alias) => {
+            let msrvs_rs = fs::read_to_string(project_root.join(MSRVS_PATH)).context("reading")?;
+            Some(add_msrv_alias(&msrvs_rs, alias).map_err(error)?)
+        },
+        None => None,
+    };
+    let conf_rs = fs::read_to_string(project_root.join(CONF_PATH)).context("reading")?;
+    let (conf_rs, lint_conf) = add_conf(&conf_rs, &name.to_uppercase(), msrv, conf).map_err(error)?;
+
     let lint = LintData {
         pass,
-        name: lint_name.expect("`name` argument is validated by clap"),
+        name,
         category: category.expect("`category` argument is validated by clap"),
         ty,
-        project_root: clippy_project_root(),
+        project_root,
+        conf: lint_conf,
+        msrv_alias: msrv_alias.map(|alias| alias.name.as_str()),
     };
 
     create_lint(&lint, msrv).context("Unable to create lint implementation")?;
     create_test(&lint, msrv).context("Unable to create a test for the new lint")?;
 
+    if let Some(msrvs_rs) = msrvs_rs {
+        fs::write(lint.project_root.join(MSRVS_PATH), msrvs_rs).context(format!("writing to `{MSRVS_PATH}`"))?;
+    }
+    if msrv || !conf.is_empty() {
+        fs::write(lint.project_root.join(CONF_PATH), conf_rs).context(format!("writing to `{CONF_PATH}`"))?;
+    }
+    if !lint.conf.is_empty() {
+        create_conf_test(&lint).context("Unable to create a test for the configuration")?;
+    }
+
     if lint.ty.is_none() {
         add_lint(&lint, msrv).context("Unable to add lint to clippy_lints/src/lib.rs")?;
+    } else if !lint.conf.is_empty() {
+        bind_conf(&lint).context("Unable to add the configuration to clippy_lints/src/lib.rs")?;
     }
 
     if pass == "early" {
@@ -71,10 +203,21 @@ pub fn create(
             an early pass, as they lack many features and utilities"
         );
     }
+    if conf.iter().any(|option| option.new.is_some()) {
+        println!(
+            "\n\
+            NOTE: Describe the new configuration options in `{CONF_PATH}`, then run\n\
+            `cargo collect-metadata` and `TESTNAME=toml_unknown_key cargo uibless`"
+        );
+    }
 
     Ok(())
 }
 
+fn error(message: String) -> io::Error {
+    io::Error::new(ErrorKind::Other, message)
+}
+
 fn create_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
     if let Some(ty) = lint.ty {
         create_lint_for_ty(lint, enable_msrv, ty)
@@ -139,15 +282,55 @@ fn create_test(lint: &LintData<'_>, msrv: bool) -> io::Result<()> {
     Ok(())
 }
 
+fn create_conf_test(lint: &LintData<'_>) -> io::Result<()> {
+    let relative_test_dir = format!("tests/ui-toml/{}", lint.name);
+    let test_dir = lint.project_root.join(&relative_test_dir);
+    fs::create_dir(&test_dir)?;
+
+    let mut clippy_toml = String::from("# TODO: set values that are not the defaults\n");
+    for LintConf { name, default, .. } in &lint.conf {
+        let key = name.replace('_', "-");
+        if is_toml_literal(default) {
+            let _: fmt::Result = writeln!(clippy_toml, "{key} = {default}");
+        } else {
+            let _: fmt::Result = writeln!(clippy_toml, "# {key} = ");
+        }
+    }
+    write_file(test_dir.join("clippy.toml"), clippy_toml)?;
+    write_file(
+        test_dir.join(format!("{}.rs", lint.name)),
+        get_test_file_contents(lint.name, false),
+    )?;
+
+    println!("Generated configuration test: `{relative_test_dir}`");
+    Ok(())
+}
+
 fn add_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
     let path = "clippy_lints/src/lib.rs";
     let mut lib_rs = fs::read_to_string(path).context("reading")?;
 
+    for conf in &lint.conf {
+        bind_conf_option(&mut lib_rs, conf);
+    }
+
     let comment_start = lib_rs.find("// add lints here,").expect("Couldn't find comment");
 
-    let new_lint = if enable_msrv {
+    let mut args = Vec::new();
+    if enable_msrv {
+        args.push(String::from("msrv()"));
+    }
+    for LintConf { name, ty, .. } in &lint.conf {
+        args.push(if is_copy(ty) {
+            name.clone()
+        } else {
+            format!("{name}.clone()")
+        });
+    }
+
+    let new_lint = if args.is_empty() {
         format!(
-            "store.register_{lint_pass}_pass(move |{ctor_arg}| Box::new({module_name}::{camel_name}::new(msrv())));\n    ",
+            "store.register_{lint_pass}_pass(|{ctor_arg}| Box::new({module_name}::{camel_name}));\n    ",
             lint_pass = lint.pass,
             ctor_arg = if lint.pass == "late" { "_" } else { "" },
             module_name = lint.name,
@@ -155,11 +338,12 @@ fn add_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
         )
     } else {
         format!(
-            "store.register_{lint_pass}_pass(|{ctor_arg}| Box::new({module_name}::{camel_name}));\n    ",
+            "store.register_{lint_pass}_pass(move |{ctor_arg}| Box::new({module_name}::{camel_name}::new({args})));\n    ",
             lint_pass = lint.pass,
             ctor_arg = if lint.pass == "late" { "_" } else { "" },
             module_name = lint.name,
             camel_name = to_camel_case(lint.name),
+            args = args.join(", "),
         )
     };
 
@@ -168,6 +352,260 @@ fn add_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
     fs::write(path, lib_rs).context("writing")
 }
 
+/// Binds the configuration options of a lint that belongs to a type in `register_lints`, they
+/// have to be passed to the lint pass of the type by hand
+fn bind_conf(lint: &LintData<'_>) -> io::Result<()> {
+    let path = "clippy_lints/src/lib.rs";
+    let mut lib_rs = fs::read_to_string(path).context("reading")?;
+    for conf in &lint.conf {
+        bind_conf_option(&mut lib_rs, conf);
+        println!(
+            "Be sure to pass `{}` to the lint pass of `{}` in `clippy_lints/src/lib.rs`!",
+            conf.name,
+            lint.ty.unwrap_or_default()
+        );
+    }
+    fs::write(path, lib_rs).context("writing")
+}
+
+/// Adds the option to the destructuring of `Conf` in `register_lints`, the options that are used
+/// there come first and are sorted by name
+fn bind_conf_option(lib_rs: &mut String, LintConf { name, ty, .. }: &LintConf) {
+    if lib_rs.contains(&format!("        {name},\n")) || lib_rs.contains(&format!("        ref {name},\n")) {
+        return;
+    }
+    // the options that are read by the passes with `Conf::for_span` are ignored
+    *lib_rs = lib_rs.replacen(&format!("        {name}: _,\n"), "", 1);
+
+    let start = lib_rs
+        .find("    let Conf {\n")
+        .expect("Couldn't find the destructuring of `Conf`")
+        + 15;
+    let end = start
+        + lib_rs[start..]
+            .find("\n\n")
+            .expect("Couldn't find the end of the used options")
+        + 1;
+    let mut offset = start;
+    for line in lib_rs[start..end].split_inclusive('\n') {
+        let bound = line.trim().trim_start_matches("ref ").trim_end_matches(',');
+        if bound > name.as_str() {
+            break;
+        }
+        offset += line.len();
+    }
+
+    let binding = if is_copy(ty) {
+        format!("        {name},\n")
+    } else {
+        format!("        ref {name},\n")
+    };
+    lib_rs.insert_str(offset, &binding);
+}
+
+/// Whether `value` can be written as it is in `clippy.toml`
+fn is_toml_literal(value: &str) -> bool {
+    value == "true"
+        || value == "false"
+        || value.replace('_', "").parse::<f64>().is_ok()
+        || (value.len() > 1 && value.starts_with('"') && value.ends_with('"'))
+}
+
+/// Whether the configuration values of type `ty` are copied instead of cloned
+fn is_copy(ty: &str) -> bool {
+    matches!(
+        ty,
+        "bool"
+            | "char"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+            | "i8"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "f32"
+            | "f64"
+    )
+}
+
+/// Adds `alias` to the `msrv_aliases!` in `clippy_config/src/msrvs.rs`, next to the aliases of the
+/// same version. Existing aliases are left as they are.
+fn add_msrv_alias(msrvs_rs: &str, alias: &MsrvAlias) -> Result<String, String> {
+    const START: &str = "\nmsrv_aliases! {\n";
+    let start = msrvs_rs.find(START).ok_or("couldn't find `msrv_aliases!`")? + START.len();
+    let end = start
+        + msrvs_rs[start..]
+            .find("\n}")
+            .ok_or("couldn't find the end of `msrv_aliases!`")?
+        + 1;
+
+    // where to add the alias to the aliases of its version, or where to add a line for its version
+    let mut same_version = None;
+    let mut new_version = end;
+    let mut offset = start;
+    for line in msrvs_rs[start..end].split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+
+        let Some((version, names)) = line.trim().split_once(" {") else {
+            continue;
+        };
+        let mut parts = version.split(',').map(str::parse::<u32>);
+        let (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch))) = (parts.next(), parts.next(), parts.next()) else {
+            continue;
+        };
+        let version = (major, minor, patch);
+
+        if names
+            .trim_end_matches('}')
+            .split(',')
+            .any(|name| name.trim() == alias.name)
+        {
+            return match alias.version {
+                Some(new) if new != version => Err(format!(
+                    "the MSRV alias `{}` already exists for {major}.{minor}.{patch}",
+                    alias.name
+                )),
+                _ => Ok(msrvs_rs.to_owned()),
+            };
+        }
+        if alias.version == Some(version) {
+            same_version = Some(line_start + line.rfind(" }").ok_or("malformed `msrv_aliases!`")?);
+        } else if alias.version > Some(version) && new_version == end {
+            new_version = line_start;
+        }
+    }
+
+    let Some((major, minor, patch)) = alias.version else {
+        return Err(format!(
+            "unknown MSRV alias `{0}`, add its version with `--msrv-alias {0}=1.xx.0`",
+            alias.name
+        ));
+    };
+    let mut msrvs_rs = msrvs_rs.to_owned();
+    if let Some(offset) = same_version {
+        msrvs_rs.insert_str(offset, &format!(", {}", alias.name));
+    } else {
+        msrvs_rs.insert_str(
+            new_version,
+            &format!("    {major},{minor},{patch} {{ {} }}\n", alias.name),
+        );
+    }
+    Ok(msrvs_rs)
+}
+
+/// Adds the lint to the documentation of the `msrv` option and the existing options in
+/// `clippy_config/src/conf.rs`, and adds the new options. Returns the names and types of the
+/// options.
+fn add_conf(
+    conf_rs: &str,
+    lint_name_upper: &str,
+    msrv: bool,
+    options: &[ConfOption],
+) -> Result<(String, Vec<LintConf>), String> {
+    let mut conf_rs = conf_rs.to_owned();
+    let mut lint_conf = Vec::new();
+
+    if msrv {
+        add_lint_to_conf_doc(&mut conf_rs, "msrv", lint_name_upper)?;
+    }
+
+    for option in options {
+        let name = &option.name;
+        match (&option.new, find_conf(&conf_rs, name)?) {
+            (Some(_), Some(_)) => {
+                return Err(format!(
+                    "the configuration option `{name}` already exists, use it with `--config {name}`"
+                ));
+            },
+            (None, None) => {
+                return Err(format!(
+                    "unknown configuration option `{name}`, add it with `--config '{name}: TYPE = DEFAULT'`"
+                ));
+            },
+            (None, Some((_, conf))) => {
+                add_lint_to_conf_doc(&mut conf_rs, name, lint_name_upper)?;
+                lint_conf.push(conf);
+            },
+            (Some((ty, default)), None) => {
+                let end = define_conf_bounds(&conf_rs)?.1;
+                conf_rs.insert_str(
+                    end,
+                    &format!(
+                        "    /// Lint: {lint_name_upper}.\n    ///\n    \
+                        /// TODO: describe what the option configures\n    ({name}: {ty} = {default}),\n"
+                    ),
+                );
+                lint_conf.push(LintConf {
+                    name: name.clone(),
+                    ty: ty.clone(),
+                    default: default.clone(),
+                });
+            },
+        }
+    }
+
+    Ok((conf_rs, lint_conf))
+}
+
+/// The start of the entries of `define_Conf!` and the start of its last line
+fn define_conf_bounds(conf_rs: &str) -> Result<(usize, usize), String> {
+    const START: &str = "\ndefine_Conf! {\n";
+    let start = conf_rs.find(START).ok_or("couldn't find `define_Conf!`")? + START.len();
+    let end = start
+        + conf_rs[start..]
+            .find("\n}\n")
+            .ok_or("couldn't find the end of `define_Conf!`")?
+        + 1;
+    Ok((start, end))
+}
+
+/// The offset and the declaration of the option `name` in `define_Conf!`
+fn find_conf(conf_rs: &str, name: &str) -> Result<Option<(usize, LintConf)>, String> {
+    let (start, end) = define_conf_bounds(conf_rs)?;
+    let Some(offset) = conf_rs[start..end].find(&format!("\n    ({name}: ")) else {
+        return Ok(None);
+    };
+    let offset = start + offset + 1;
+    let (ty, default) = conf_rs[offset..]
+        .split_once(": ")
+        .and_then(|(_, rest)| rest.split_once(" ="))
+        .and_then(|(ty, rest)| Some((ty, rest.split_once("),\n")?.0)))
+        .ok_or_else(|| format!("couldn't parse the declaration of `{name}`"))?;
+    let conf = LintConf {
+        name: name.to_owned(),
+        ty: ty.trim().to_owned(),
+        default: default.trim().to_owned(),
+    };
+    Ok(Some((offset, conf)))
+}
+
+/// Adds the lint to the `/// Lint: ` line of the documentation of the option `name`
+fn add_lint_to_conf_doc(conf_rs: &mut String, name: &str, lint_name_upper: &str) -> Result<(), String> {
+    let (offset, _) = find_conf(conf_rs, name)?.ok_or_else(|| format!("couldn't find the option `{name}`"))?;
+    let doc_start = conf_rs[..offset].rfind("\n    (").map_or(0, |prev| prev + 1);
+    let line_start = doc_start
+        + conf_rs[doc_start..offset]
+            .find("    /// Lint: ")
+            .ok_or_else(|| format!("couldn't find the lints of the option `{name}`"))?;
+    let line_end = line_start + conf_rs[line_start..].find('\n').unwrap_or_default();
+
+    let line = &conf_rs[line_start..line_end];
+    let new_line = if let Some(line) = line.strip_suffix('.') {
+        format!("{line}, {lint_name_upper}.")
+    } else {
+        format!("{line}, {lint_name_upper}")
+    };
+    conf_rs.replace_range(line_start..line_end, &new_line);
+    Ok(())
+}
+
 fn write_file<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
     fn inner(path: &Path, contents: &[u8]) -> io::Result<()> {
         OpenOptions::new()
@@ -277,10 +715,12 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
     let name_camel = to_camel_case(lint.name);
     let name_upper = lint_name.to_uppercase();
 
+    let has_fields = enable_msrv || !lint.conf.is_empty();
+
     result.push_str(&if enable_msrv {
         formatdoc!(
             r#"
-            use clippy_utils::msrvs::{{self, Msrv}};
+            use clippy_config::msrvs::{{self, Msrv}};
             {pass_import}
             use rustc_lint::{{{context_import}, {pass_type}, LintContext}};
             use rustc_session::impl_lint_pass;
@@ -292,47 +732,83 @@ fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
             r#"
             {pass_import}
             use rustc_lint::{{{context_import}, {pass_type}}};
-            use rustc_session::declare_lint_pass;
+            use rustc_session::{};
 
-        "#
+        "#,
+            if has_fields {
+                "impl_lint_pass"
+            } else {
+                "declare_lint_pass"
+            }
         )
     });
 
     let _: fmt::Result = writeln!(result, "{}", get_lint_declaration(&name_upper, category));
 
-    result.push_str(&if enable_msrv {
-        formatdoc!(
+    if !has_fields {
+        result.push_str(&formatdoc!(
             r#"
-            pub struct {name_camel} {{
-                msrv: Msrv,
-            }}
+            declare_lint_pass!({name_camel} => [{name_upper}]);
 
-            impl {name_camel} {{
-                #[must_use]
-                pub fn new(msrv: Msrv) -> Self {{
-                    Self {{ msrv }}
-                }}
-            }}
+            impl {pass_type}{pass_lifetimes} for {name_camel} {{}}
+        "#
+        ));
+        return result;
+    }
+
+    let mut fields: Vec<(&str, &str)> = Vec::new();
+    if enable_msrv {
+        fields.push(("msrv", "Msrv"));
+    }
+    fields.extend(lint.conf.iter().map(|conf| (conf.name.as_str(), conf.ty.as_str())));
+    let mut declarations = String::new();
+    for (name, ty) in &fields {
+        let _: fmt::Result = write!(declarations, "\n    {name}: {ty},");
+    }
+    let params: Vec<String> = fields.iter().map(|(name, ty)| format!("{name}: {ty}")).collect();
+    let names: Vec<&str> = fields.iter().map(|(name, _)| *name).collect();
+    let (params, names) = (params.join(", "), names.join(", "));
+
+    let lint_pass_body = match (enable_msrv, lint.msrv_alias) {
+        (true, Some(alias)) => {
+            let expr_lifetime = if lint.pass == "late" { "<'_>" } else { "" };
+            format!(
+                "
+    fn check_expr(&mut self, cx: &{context_import}<'_>, expr: &Expr{expr_lifetime}) {{
+        // TODO: check the MSRV once the expression matches, right before linting it
+        if self.msrv.meets_or_report({name_upper}, expr.span, msrvs::{alias}) {{
+            todo!();
+        }}
+    }}
 
-            impl_lint_pass!({name_camel} => [{name_upper}]);
+    extract_msrv_attr!({context_import});
+"
+            )
+        },
+        (true, None) => format!("\n    extract_msrv_attr!({context_import});\n"),
+        (false, _) => String::new(),
+    };
 
-            impl {pass_type}{pass_lifetimes} for {name_camel} {{
-                extract_msrv_attr!({context_import});
+    result.push_str(&formatdoc!(
+        r#"
+        pub struct {name_camel} {{{declarations}
+        }}
+
+        impl {name_camel} {{
+            #[must_use]
+            pub fn new({params}) -> Self {{
+                Self {{ {names} }}
             }}
+        }}
 
-            // TODO: Add MSRV level to `clippy_config/src/msrvs.rs` if needed.
-            // TODO: Update msrv config comment in `clippy_config/src/conf.rs`
-        "#
-        )
-    } else {
-        formatdoc!(
-            r#"
-            declare_lint_pass!({name_camel} => [{name_upper}]);
+        impl_lint_pass!({name_camel} => [{name_upper}]);
 
-            impl {pass_type}{pass_lifetimes} for {name_camel} {{}}
-        "#
-        )
-    });
+        impl {pass_type}{pass_lifetimes} for {name_camel} {{{lint_pass_body}}}
+    "#
+    ));
+    if enable_msrv && lint.msrv_alias.is_none() {
+        result.push_str("\n// TODO: Add MSRV level to `clippy_config/src/msrvs.rs` if needed.\n");
+    }
 
     result
 }
@@ -397,18 +873,36 @@ fn create_lint_for_ty(lint: &LintData<'_>, enable_msrv: bool, ty: &str) -> io::R
     let name_upper = lint.name.to_uppercase();
     let mut lint_file_contents = String::new();
 
+    let conf_params: String = lint
+        .conf
+        .iter()
+        .map(|LintConf { name, ty, .. }| {
+            if is_copy(ty) {
+                format!(", {name}: {ty}")
+            } else {
+                format!(", {name}: &{ty}")
+            }
+        })
+        .collect();
+
     if enable_msrv {
+        let alias = lint.msrv_alias.map_or_else(
+            || String::from("todo!(\"Add a new entry in `clippy_config/src/msrvs`\")"),
+            |alias| format!("msrvs::{alias}"),
+        );
         let _: fmt::Result = writedoc!(
             lint_file_contents,
             r#"
-                use clippy_utils::msrvs::{{self, Msrv}};
+                use clippy_config::msrvs::{{self, Msrv}};
                 use rustc_lint::{{{context_import}, LintContext}};
+                use rustc_span::Span;
 
                 use super::{name_upper};
 
                 // TODO: Adjust the parameters as necessary
-                pub(super) fn check(cx: &{context_import}{pass_lifetimes}, msrv: &Msrv) {{
-                    if !msrv.meets(todo!("Add a new entry in `clippy_utils/src/msrvs`")) {{
+                pub(super) fn check(cx: &{context_import}{pass_lifetimes}, span: Span, msrv: &Msrv{conf_params}) {{
+                    // TODO: check the MSRV once the code matches, right before linting it
+                    if !msrv.meets_or_report({name_upper}, span, {alias}) {{
                         return;
                     }}
                     todo!();
@@ -424,7 +918,7 @@ fn create_lint_for_ty(lint: &LintData<'_>, enable_msrv: bool, ty: &str) -> io::R
                 use super::{name_upper};
 
                 // TODO: Adjust the parameters as necessary
-                pub(super) fn check(cx: &{context_import}{pass_lifetimes}) {{
+                pub(super) fn check(cx: &{context_import}{pass_lifetimes}{conf_params}) {{
                     todo!();
                 }}
            "#
@@ -596,3 +1090,52 @@ fn test_camel_case() {
     let name4 = to_camel_case(name3);
     assert_eq!(name4, "LintName");
 }
+
+#[test]
+fn test_parse_conf_option() {
+    let option = ConfOption::parse("max-foo-len: u64 = 3").unwrap();
+    assert_eq!(option.name, "max_foo_len");
+    assert_eq!(option.new, Some(("u64".into(), "3".into())));
+
+    let option = ConfOption::parse("avoid_breaking_exported_api").unwrap();
+    assert_eq!(option.new, None);
+
+    assert!(ConfOption::parse("max_foo_len: u64").is_err());
+    assert!(ConfOption::parse("MaxFooLen").is_err());
+    assert!(ConfOption::parse("msrv").is_err());
+}
+
+#[test]
+fn test_parse_msrv_alias() {
+    let alias = MsrvAlias::parse("FOO_BAR=1.71").unwrap();
+    assert_eq!(alias.name, "FOO_BAR");
+    assert_eq!(alias.version, Some((1, 71, 0)));
+
+    assert_eq!(MsrvAlias::parse("LET_ELSE").unwrap().version, None);
+    assert!(MsrvAlias::parse("let_else").is_err());
+    assert!(MsrvAlias::parse("FOO_BAR=1").is_err());
+    assert!(MsrvAlias::parse("FOO_BAR=1.71.0.1").is_err());
+}
+
+#[test]
+fn test_add_msrv_alias() {
+    let msrvs_rs = "\nmsrv_aliases! {\n    1,71,0 { A }\n    1,65,0 { B, C }\n}\n";
+    let add = |alias| add_msrv_alias(msrvs_rs, &MsrvAlias::parse(alias).unwrap());
+
+    assert_eq!(
+        add("D=1.65.0").unwrap(),
+        "\nmsrv_aliases! {\n    1,71,0 { A }\n    1,65,0 { B, C, D }\n}\n"
+    );
+    assert_eq!(
+        add("D=1.70.0").unwrap(),
+        "\nmsrv_aliases! {\n    1,71,0 { A }\n    1,70,0 { D }\n    1,65,0 { B, C }\n}\n"
+    );
+    assert_eq!(
+        add("D=1.40.0").unwrap(),
+        "\nmsrv_aliases! {\n    1,71,0 { A }\n    1,65,0 { B, C }\n    1,40,0 { D }\n}\n"
+    );
+    assert_eq!(add("C").unwrap(), msrvs_rs);
+    assert_eq!(add("C=1.65.0").unwrap(), msrvs_rs);
+    assert!(add("C=1.71.0").is_err());
+    assert!(add("D").is_err());
+}
@@ -87,7 +87,9 @@ impl ApproxConstant {
         let s = s.as_str();
         if s.parse::<f64>().is_ok() {
             for &(constant, name, min_digits, msrv) in &KNOWN_CONSTS {
-                if is_approx_const(constant, s, min_digits) && msrv.map_or(true, |msrv| self.msrv.meets(msrv)) {
+                if is_approx_const(constant, s, min_digits)
+                    && msrv.map_or(true, |msrv| self.msrv.meets_or_report(APPROX_CONSTANT, e.span, msrv))
+                {
                     span_lint_and_help(
                         cx,
                         APPROX_CONSTANT,
@@ -0,0 +1,297 @@
+use std::ops::ControlFlow;
+
+use clippy_config::types::DisallowedPath;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{def_path_res, disallowed_path_res, fn_def_id, get_parent_expr, get_parent_node};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, DefIdMap};
+use rustc_hir::{Body, CoroutineKind, Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls that block the thread in the bodies of `async fn`s and `async` blocks:
+    /// `std::thread::sleep`, the file system functions of `std::fs`, connecting to and reading
+    /// from or writing to a `std::net::TcpStream`, reading from or writing to a `std::fs::File`,
+    /// locking a `std::sync::Mutex` whose guard is kept in a variable, and the functions of
+    /// `reqwest::blocking`.
+    ///
+    /// More paths can be added with the `blocking-paths` configuration option. A path to a module
+    /// or a type matches all the functions in it.
+    ///
+    /// The closures passed to functions that run them on a thread where blocking is allowed, like
+    /// `tokio::task::spawn_blocking`, `tokio::task::block_in_place` or `std::thread::spawn`, are
+    /// not checked. More of these functions can be added with the `blocking-offload-paths`
+    /// configuration option.
+    ///
+    /// ### Why is this bad?
+    /// Async tasks are run on a few threads of the executor. A blocking call stops the thread,
+    /// so no other task can make progress on it until the call returns.
+    ///
+    /// ### Known problems
+    /// A closure that is stored in a variable before it's passed to `tokio::task::spawn_blocking`
+    /// is still checked. Locking a `Mutex` in a temporary is allowed, as the lock is only held for
+    /// the statement.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// async fn load_config() -> std::io::Result<String> {
+    ///     std::fs::read_to_string("config.toml")
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// async fn load_config() -> std::io::Result<String> {
+    ///     tokio::fs::read_to_string("config.toml").await
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub ASYNC_BLOCKING_CALL,
+    pedantic,
+    "blocking calls in async code"
+}
+
+/// The functions that block the thread. A path to a module or a type matches all the functions in
+/// it.
+const BLOCKING_PATHS: &[&[&str]] = &[
+    &["std", "thread", "sleep"],
+    &["std", "fs", "canonicalize"],
+    &["std", "fs", "copy"],
+    &["std", "fs", "create_dir"],
+    &["std", "fs", "create_dir_all"],
+    &["std", "fs", "hard_link"],
+    &["std", "fs", "metadata"],
+    &["std", "fs", "read"],
+    &["std", "fs", "read_dir"],
+    &["std", "fs", "read_link"],
+    &["std", "fs", "read_to_string"],
+    &["std", "fs", "remove_dir"],
+    &["std", "fs", "remove_dir_all"],
+    &["std", "fs", "remove_file"],
+    &["std", "fs", "rename"],
+    &["std", "fs", "set_permissions"],
+    &["std", "fs", "symlink_metadata"],
+    &["std", "fs", "write"],
+    &["std", "fs", "DirBuilder", "create"],
+    &["std", "fs", "File", "create"],
+    &["std", "fs", "File", "metadata"],
+    &["std", "fs", "File", "open"],
+    &["std", "fs", "File", "set_len"],
+    &["std", "fs", "File", "sync_all"],
+    &["std", "fs", "File", "sync_data"],
+    &["std", "fs", "OpenOptions", "open"],
+    &["std", "net", "TcpListener", "accept"],
+    &["std", "net", "TcpStream", "connect"],
+    &["std", "net", "TcpStream", "connect_timeout"],
+    &["std", "net", "TcpStream", "peek"],
+    &["reqwest", "blocking"],
+];
+
+/// The functions that run the closure passed to them on a thread where blocking is allowed
+const OFFLOAD_PATHS: &[&[&str]] = &[
+    &["std", "thread", "spawn"],
+    &["std", "thread", "Builder", "spawn"],
+    &["tokio", "task", "spawn_blocking"],
+    &["tokio", "task", "block_in_place"],
+    &["tokio", "runtime", "Handle", "spawn_blocking"],
+    &["tokio", "runtime", "Runtime", "spawn_blocking"],
+    &["async_std", "task", "spawn_blocking"],
+    &["blocking", "unblock"],
+];
+
+/// The types whose `std::io::Read` and `std::io::Write` impls block the thread
+const BLOCKING_IO_TYPES: &[&[&str]] = &[&["std", "fs", "File"], &["std", "net", "TcpStream"]];
+
+pub struct AsyncBlockingCall {
+    conf_blocking_paths: Vec<DisallowedPath>,
+    conf_offload_paths: Vec<DisallowedPath>,
+    error_on_unresolved: bool,
+    /// The blocking functions, modules and types, with the index of their configuration entry if
+    /// they are not built in
+    blocking: DefIdMap<Option<usize>>,
+    /// The functions that run the closure passed to them on a thread where blocking is allowed
+    offload: FxHashSet<DefId>,
+    io_types: FxHashSet<DefId>,
+}
+
+impl AsyncBlockingCall {
+    #[must_use]
+    pub fn new(
+        conf_blocking_paths: Vec<DisallowedPath>,
+        conf_offload_paths: Vec<DisallowedPath>,
+        error_on_unresolved: bool,
+    ) -> Self {
+        Self {
+            conf_blocking_paths,
+            conf_offload_paths,
+            error_on_unresolved,
+            blocking: DefIdMap::default(),
+            offload: FxHashSet::default(),
+            io_types: FxHashSet::default(),
+        }
+    }
+
+    /// Returns the blocking function called by `expr`, with the index of its configuration entry
+    fn blocking_callee(&self, cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<(DefId, Option<usize>)> {
+        let def_id = fn_def_id(cx, expr)?;
+        if !matches!(cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn) {
+            return None;
+        }
+
+        // the function itself, its impl or type, and its modules
+        let mut current = Some(def_id);
+        while let Some(id) = current {
+            if let Some(&index) = self.blocking.get(&id) {
+                return Some((def_id, index));
+            }
+            if let DefKind::Impl { of_trait: false } = cx.tcx.def_kind(id)
+                && let ty::Adt(adt, _) = cx.tcx.type_of(id).instantiate_identity().kind()
+                && let Some(&index) = self.blocking.get(&adt.did())
+            {
+                return Some((def_id, index));
+            }
+            current = cx.tcx.opt_parent(id);
+        }
+
+        // `Read` and `Write` methods on files and sockets
+        if let ExprKind::MethodCall(_, receiver, ..) = expr.kind
+            && let Some(trait_id) = cx.tcx.trait_of_item(def_id)
+            && matches!(cx.tcx.get_diagnostic_name(trait_id), Some(sym::IoRead | sym::IoWrite))
+            && let ty::Adt(adt, _) = cx.typeck_results().expr_ty(receiver).peel_refs().kind()
+            && self.io_types.contains(&adt.did())
+        {
+            return Some((def_id, None));
+        }
+        None
+    }
+
+    /// Whether the closure `expr` is passed to a function that runs it on a thread where blocking
+    /// is allowed
+    fn is_offloaded(&self, cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+        get_parent_expr(cx, expr).is_some_and(|parent| {
+            let (ExprKind::Call(_, args) | ExprKind::MethodCall(_, _, args, _)) = parent.kind else {
+                return false;
+            };
+            args.iter().any(|arg| arg.hir_id == expr.hir_id)
+                && fn_def_id(cx, parent).is_some_and(|def_id| self.offload.contains(&def_id))
+        })
+    }
+
+    /// Lints the blocking calls in `expr`, and in the closures it contains unless they are passed
+    /// to a function that runs them on a thread where blocking is allowed. Nested `async` blocks
+    /// are checked on their own.
+    fn check_blocking_calls(&self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        let _: Option<!> = for_each_expr(expr, |expr| {
+            if in_external_macro(cx.tcx.sess, expr.span) {
+                return ControlFlow::Continue(());
+            }
+            if let ExprKind::Closure(closure) = expr.kind {
+                let body = cx.tcx.hir().body(closure.body);
+                if body.coroutine_kind.is_none() && !self.is_offloaded(cx, expr) {
+                    self.check_blocking_calls(cx, body.value);
+                }
+            } else if let Some((def_id, index)) = self.blocking_callee(cx, expr) {
+                let conf = index.map(|index| &self.conf_blocking_paths[index]);
+                span_lint_and_then(
+                    cx,
+                    ASYNC_BLOCKING_CALL,
+                    expr.span,
+                    &format!("blocking call to `{}` in async code", cx.tcx.def_path_str(def_id)),
+                    |diag| {
+                        if let Some(reason) = conf.and_then(DisallowedPath::reason) {
+                            diag.note(reason);
+                        }
+                        diag.help("use an async alternative, or run the call on a thread where blocking is allowed");
+                    },
+                );
+            } else if let ExprKind::MethodCall(path, receiver, [], _) = expr.kind
+                && path.ident.name.as_str() == "lock"
+                && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(receiver).peel_refs(), sym::Mutex)
+                && is_guard_bound(cx, expr)
+            {
+                span_lint_and_then(
+                    cx,
+                    ASYNC_BLOCKING_CALL,
+                    expr.span,
+                    "blocking call to `std::sync::Mutex::lock` in async code",
+                    |diag| {
+                        diag.note("the thread is blocked while another task holds the lock");
+                        diag.help(
+                            "use the mutex of the async runtime, or only lock it in a temporary for a short statement",
+                        );
+                    },
+                );
+            }
+            ControlFlow::Continue(())
+        });
+    }
+}
+
+impl_lint_pass!(AsyncBlockingCall => [ASYNC_BLOCKING_CALL]);
+
+impl LateLintPass<'_> for AsyncBlockingCall {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for path in BLOCKING_PATHS {
+            for res in def_path_res(cx, path) {
+                if let Some(id) = res.opt_def_id() {
+                    self.blocking.insert(id, None);
+                }
+            }
+        }
+        for path in OFFLOAD_PATHS {
+            self.offload
+                .extend(def_path_res(cx, path).into_iter().filter_map(|res| res.opt_def_id()));
+        }
+        for path in BLOCKING_IO_TYPES {
+            self.io_types
+                .extend(def_path_res(cx, path).into_iter().filter_map(|res| res.opt_def_id()));
+        }
+        for (index, conf) in self.conf_blocking_paths.iter().enumerate() {
+            for res in disallowed_path_res(cx, conf, self.error_on_unresolved) {
+                if let Some(id) = res.opt_def_id() {
+                    self.blocking.insert(id, Some(index));
+                }
+            }
+        }
+        for conf in &self.conf_offload_paths {
+            self.offload.extend(
+                disallowed_path_res(cx, conf, self.error_on_unresolved)
+                    .into_iter()
+                    .filter_map(|res| res.opt_def_id()),
+            );
+        }
+    }
+
+    fn check_body(&mut self, cx: &LateContext<'_>, body: &Body<'_>) {
+        if !matches!(body.coroutine_kind, Some(CoroutineKind::Async(_))) {
+            return;
+        }
+        self.check_blocking_calls(cx, body.value);
+    }
+}
+
+/// Whether the guard returned by the `lock` call `expr`, unwrapped or not, is kept in a variable
+fn is_guard_bound(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let mut expr_id = expr.hir_id;
+    loop {
+        match get_parent_node(cx.tcx, expr_id) {
+            Some(Node::Expr(parent)) => match parent.kind {
+                ExprKind::MethodCall(path, receiver, ..)
+                    if receiver.hir_id == expr_id && matches!(path.ident.name.as_str(), "unwrap" | "expect") =>
+                {
+                    expr_id = parent.hir_id;
+                },
+                _ => return false,
+            },
+            Some(Node::Local(local)) => return local.init.is_some_and(|init| init.hir_id == expr_id),
+            _ => return false,
+        }
+    }
+}
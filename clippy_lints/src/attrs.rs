@@ -858,7 +858,7 @@ fn check_empty_line_after_outer_attr(cx: &EarlyContext<'_>, item: &rustc_ast::It
 }
 
 fn check_deprecated_cfg_attr(cx: &EarlyContext<'_>, attr: &Attribute, msrv: &Msrv) {
-    if msrv.meets(msrvs::TOOL_ATTRIBUTES)
+    if msrv.meets_or_report(DEPRECATED_CFG_ATTR, attr.span, msrvs::TOOL_ATTRIBUTES)
         // check cfg_attr
         && attr.has_name(sym::cfg_attr)
         && let Some(items) = attr.meta_item_list()
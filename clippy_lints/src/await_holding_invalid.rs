@@ -1,6 +1,6 @@
 use clippy_config::types::DisallowedPath;
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::{match_def_path, paths};
+use clippy_utils::{disallowed_path_res, match_def_path, paths};
 use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::def_id::DefId;
 use rustc_hir::{Body, CoroutineKind, CoroutineSource};
@@ -171,13 +171,15 @@ impl_lint_pass!(AwaitHolding => [AWAIT_HOLDING_LOCK, AWAIT_HOLDING_REFCELL_REF,
 #[derive(Debug)]
 pub struct AwaitHolding {
     conf_invalid_types: Vec<DisallowedPath>,
+    error_on_unresolved: bool,
     def_ids: FxHashMap<DefId, DisallowedPath>,
 }
 
 impl AwaitHolding {
-    pub(crate) fn new(conf_invalid_types: Vec<DisallowedPath>) -> Self {
+    pub(crate) fn new(conf_invalid_types: Vec<DisallowedPath>, error_on_unresolved: bool) -> Self {
         Self {
             conf_invalid_types,
+            error_on_unresolved,
             def_ids: FxHashMap::default(),
         }
     }
@@ -186,9 +188,10 @@ impl AwaitHolding {
 impl LateLintPass<'_> for AwaitHolding {
     fn check_crate(&mut self, cx: &LateContext<'_>) {
         for conf in &self.conf_invalid_types {
-            let segs: Vec<_> = conf.path().split("::").collect();
-            for id in clippy_utils::def_path_def_ids(cx, &segs) {
-                self.def_ids.insert(id, conf.clone());
+            for res in disallowed_path_res(cx, conf, self.error_on_unresolved) {
+                if let Some(id) = res.opt_def_id() {
+                    self.def_ids.insert(id, conf.clone());
+                }
             }
         }
     }
@@ -11,7 +11,7 @@ use super::CAST_SLICE_DIFFERENT_SIZES;
 
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'tcx>, msrv: &Msrv) {
     // suggestion is invalid if `ptr::slice_from_raw_parts` does not exist
-    if !msrv.meets(msrvs::PTR_SLICE_RAW_PARTS) {
+    if !msrv.meets_or_report(CAST_SLICE_DIFFERENT_SIZES, expr.span, msrvs::PTR_SLICE_RAW_PARTS) {
         return;
     }
 
@@ -24,7 +24,7 @@ fn raw_parts_kind(cx: &LateContext<'_>, did: DefId) -> Option<RawPartsKind> {
 }
 
 pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, cast_expr: &Expr<'_>, cast_to: Ty<'_>, msrv: &Msrv) {
-    if msrv.meets(msrvs::PTR_SLICE_RAW_PARTS)
+    if msrv.meets_or_report(CAST_SLICE_FROM_RAW_PARTS, expr.span, msrvs::PTR_SLICE_RAW_PARTS)
         && let ty::RawPtr(ptrty) = cast_to.kind()
         && let ty::Slice(_) = ptrty.ty.kind()
         && let ExprKind::Call(fun, [ptr_arg, len_arg]) = cast_expr.peel_blocks().kind
@@ -771,7 +771,10 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
 
             as_underscore::check(cx, expr, cast_to_hir);
 
-            if self.msrv.meets(msrvs::BORROW_AS_PTR) {
+            if self
+                .msrv
+                .meets_or_report(BORROW_AS_PTR, expr.span, msrvs::BORROW_AS_PTR)
+            {
                 borrow_as_ptr::check(cx, expr, cast_expr, cast_to_hir);
             }
         }
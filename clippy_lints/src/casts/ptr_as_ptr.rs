@@ -10,7 +10,7 @@ use rustc_middle::ty::{self, TypeAndMut};
 use super::PTR_AS_PTR;
 
 pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, msrv: &Msrv) {
-    if !msrv.meets(msrvs::POINTER_CAST) {
+    if !msrv.meets_or_report(PTR_AS_PTR, expr.span, msrvs::POINTER_CAST) {
         return;
     }
 
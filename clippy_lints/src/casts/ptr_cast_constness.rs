@@ -16,7 +16,7 @@ pub(super) fn check<'tcx>(
     cast_to: Ty<'tcx>,
     msrv: &Msrv,
 ) {
-    if msrv.meets(msrvs::POINTER_CAST_CONSTNESS)
+    if msrv.meets_or_report(PTR_CAST_CONSTNESS, expr.span, msrvs::POINTER_CAST_CONSTNESS)
         && let ty::RawPtr(TypeAndMut {
             mutbl: from_mutbl,
             ty: from_ty,
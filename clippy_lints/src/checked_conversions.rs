@@ -50,10 +50,6 @@ impl_lint_pass!(CheckedConversions => [CHECKED_CONVERSIONS]);
 
 impl<'tcx> LateLintPass<'tcx> for CheckedConversions {
     fn check_expr(&mut self, cx: &LateContext<'_>, item: &Expr<'_>) {
-        if !self.msrv.meets(msrvs::TRY_FROM) {
-            return;
-        }
-
         let result = if !in_constant(cx, item.hir_id)
             && !in_external_macro(cx.sess(), item.span)
             && let ExprKind::Binary(op, left, right) = &item.kind
@@ -68,7 +64,11 @@ impl<'tcx> LateLintPass<'tcx> for CheckedConversions {
         };
 
         if let Some(cv) = result {
-            if let Some(to_type) = cv.to_type {
+            if let Some(to_type) = cv.to_type
+                && self
+                    .msrv
+                    .meets_or_report(CHECKED_CONVERSIONS, item.span, msrvs::TRY_FROM)
+            {
                 let mut applicability = Applicability::MachineApplicable;
                 let snippet = snippet_with_applicability(cx, cv.expr_to_cast.span, "_", &mut applicability);
                 span_lint_and_sugg(
@@ -1,5 +1,6 @@
 //! calculate cognitive complexity and warn about overly complex functions
 
+use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint_and_help;
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::is_type_diagnostic_item;
@@ -35,14 +36,16 @@ declare_clippy_lint! {
 }
 
 pub struct CognitiveComplexity {
+    conf: &'static Conf,
     limit: LimitStack,
 }
 
 impl CognitiveComplexity {
     #[must_use]
-    pub fn new(limit: u64) -> Self {
+    pub fn new(conf: &'static Conf) -> Self {
         Self {
-            limit: LimitStack::new(limit),
+            conf,
+            limit: LimitStack::new(conf.cognitive_complexity_threshold),
         }
     }
 }
@@ -95,7 +98,13 @@ impl CognitiveComplexity {
             cc -= ret_adjust;
         }
 
-        if cc > self.limit.limit() {
+        // the `#[clippy::cognitive_complexity]` attribute takes precedence over the configuration
+        let limit = self
+            .limit
+            .attr_limit()
+            .unwrap_or_else(|| self.conf.for_span(cx.sess(), body_span).cognitive_complexity_threshold);
+
+        if cc > limit {
             let fn_span = match kind {
                 FnKind::ItemFn(ident, _, _) | FnKind::Method(ident, _) => ident.span,
                 FnKind::Closure => {
@@ -121,10 +130,7 @@ impl CognitiveComplexity {
                 cx,
                 COGNITIVE_COMPLEXITY,
                 fn_span,
-                &format!(
-                    "the function has a cognitive complexity of ({cc}/{})",
-                    self.limit.limit()
-                ),
+                &format!("the function has a cognitive complexity of ({cc}/{limit})"),
                 None,
                 "you could split it up into multiple smaller functions",
             );
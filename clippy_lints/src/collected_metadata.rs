@@ -0,0 +1,705 @@
+// This file was generated by `cargo collect-metadata`.
+// Use that command to update this file and do not edit by hand.
+// Manual edits will be overwritten.
+
+/// The name, the applicability of the suggestions and whether the lint is gated on the MSRV of
+/// each lint, sorted by name
+pub(crate) static COLLECTED_METADATA: &[(&str, &str, bool)] = &[
+    ("absolute_paths", "Unresolved", false),
+    ("absurd_extreme_comparisons", "Unresolved", false),
+    ("alloc_instead_of_core", "MachineApplicable", false),
+    ("allow_attributes", "MachineApplicable", false),
+    ("allow_attributes_without_reason", "Unresolved", false),
+    ("almost_complete_range", "MaybeIncorrect", false),
+    ("almost_swapped", "MaybeIncorrect", false),
+    ("approx_constant", "Unresolved", true),
+    ("arc_with_non_send_sync", "Unresolved", false),
+    ("arithmetic_side_effects", "Unresolved", false),
+    ("as_conversions", "Unresolved", false),
+    ("as_ptr_cast_mut", "MaybeIncorrect", false),
+    ("as_underscore", "MachineApplicable", false),
+    ("assertions_on_constants", "Unresolved", false),
+    ("assertions_on_result_states", "MachineApplicable", false),
+    ("assign_op_pattern", "MachineApplicable", false),
+    ("async_blocking_call", "Unresolved", false),
+    ("async_yields_async", "MaybeIncorrect", false),
+    ("await_holding_invalid_type", "Unresolved", false),
+    ("await_holding_lock", "Unresolved", false),
+    ("await_holding_refcell_ref", "Unresolved", false),
+    ("bad_bit_mask", "Unresolved", false),
+    ("big_endian_bytes", "Unresolved", false),
+    ("bind_instead_of_map", "MachineApplicable", false),
+    ("blanket_clippy_restriction_lints", "Unresolved", false),
+    ("blocks_in_conditions", "Unresolved", false),
+    ("bool_assert_comparison", "MachineApplicable", false),
+    ("bool_comparison", "Unresolved", false),
+    ("bool_to_int_with_if", "MachineApplicable", false),
+    ("borrow_as_ptr", "MachineApplicable", true),
+    ("borrow_deref_ref", "MachineApplicable", false),
+    ("borrow_interior_mutable_const", "Unresolved", false),
+    ("borrowed_box", "Unspecified", false),
+    ("box_collection", "Unresolved", false),
+    ("box_default", "MachineApplicable", false),
+    ("boxed_local", "Unresolved", false),
+    ("branches_sharing_code", "Unspecified", false),
+    ("builtin_type_shadow", "Unresolved", false),
+    ("bytes_count_to_len", "MachineApplicable", false),
+    ("bytes_nth", "MachineApplicable", false),
+    ("cargo_common_metadata", "Unresolved", false),
+    ("case_sensitive_file_extension_comparisons", "MaybeIncorrect", false),
+    ("cast_abs_to_unsigned", "MachineApplicable", true),
+    ("cast_enum_constructor", "Unresolved", false),
+    ("cast_enum_truncation", "Unresolved", false),
+    ("cast_lossless", "MachineApplicable", true),
+    ("cast_nan_to_int", "Unresolved", false),
+    ("cast_possible_truncation", "Unresolved", false),
+    ("cast_possible_wrap", "Unresolved", false),
+    ("cast_precision_loss", "Unresolved", false),
+    ("cast_ptr_alignment", "Unresolved", false),
+    ("cast_sign_loss", "Unresolved", false),
+    ("cast_slice_different_sizes", "Unresolved", true),
+    ("cast_slice_from_raw_parts", "MachineApplicable", true),
+    ("char_lit_as_u8", "MachineApplicable", false),
+    ("chars_last_cmp", "Unresolved", false),
+    ("chars_next_cmp", "Unresolved", false),
+    ("checked_conversions", "MachineApplicable", true),
+    ("clear_with_drain", "MachineApplicable", false),
+    ("clone_on_copy", "MachineApplicable", false),
+    ("clone_on_ref_ptr", "Unspecified", false),
+    ("cloned_instead_of_copied", "MachineApplicable", true),
+    ("cmp_null", "Unresolved", false),
+    ("cmp_owned", "MachineApplicable", false),
+    ("cognitive_complexity", "Unresolved", false),
+    ("collapsible_else_if", "MachineApplicable", false),
+    ("collapsible_if", "MachineApplicable", false),
+    ("collapsible_match", "Unresolved", false),
+    ("collapsible_str_replace", "MachineApplicable", true),
+    ("collection_is_never_read", "Unresolved", false),
+    ("comparison_chain", "Unresolved", false),
+    ("comparison_to_empty", "MachineApplicable", false),
+    ("copy_iterator", "Unresolved", false),
+    ("crate_in_macro_def", "MachineApplicable", false),
+    ("create_dir", "MaybeIncorrect", false),
+    ("crosspointer_transmute", "Unresolved", false),
+    ("dbg_macro", "MachineApplicable", false),
+    ("debug_assert_with_mut_call", "Unresolved", false),
+    ("decimal_literal_representation", "MachineApplicable", false),
+    ("declare_interior_mutable_const", "Unresolved", false),
+    ("default_constructed_unit_structs", "MachineApplicable", false),
+    ("default_instead_of_iter_empty", "MachineApplicable", false),
+    ("default_numeric_fallback", "MaybeIncorrect", false),
+    ("default_trait_access", "Unspecified", false),
+    ("default_union_representation", "Unresolved", false),
+    ("deprecated_cfg_attr", "MachineApplicable", true),
+    ("deprecated_semver", "Unresolved", false),
+    ("deref_addrof", "MachineApplicable", false),
+    ("deref_by_slicing", "Unresolved", false),
+    ("derivable_impls", "MachineApplicable", true),
+    ("derive_ord_xor_partial_ord", "Unresolved", false),
+    ("derive_partial_eq_without_eq", "MachineApplicable", false),
+    ("derived_hash_with_manual_eq", "Unresolved", false),
+    ("disallowed_macros", "Unresolved", false),
+    ("disallowed_methods", "Unresolved", false),
+    ("disallowed_names", "Unresolved", false),
+    ("disallowed_script_idents", "Unresolved", false),
+    ("disallowed_types", "Unresolved", false),
+    ("diverging_sub_expression", "Unresolved", false),
+    ("doc_link_to_private_item", "Unresolved", false),
+    ("doc_link_with_quotes", "Unresolved", false),
+    ("doc_markdown", "Unresolved", false),
+    ("double_comparisons", "MachineApplicable", false),
+    ("double_must_use", "Unresolved", false),
+    ("double_neg", "Unresolved", false),
+    ("double_parens", "Unresolved", false),
+    ("drain_collect", "MachineApplicable", false),
+    ("drop_non_drop", "Unresolved", false),
+    ("duplicate_mod", "Unresolved", false),
+    ("duplicate_underscore_argument", "Unresolved", false),
+    ("duration_subsec", "MachineApplicable", false),
+    ("else_if_without_else", "Unresolved", false),
+    ("empty_drop", "MaybeIncorrect", false),
+    ("empty_enum", "Unresolved", false),
+    ("empty_line_after_doc_comments", "Unresolved", false),
+    ("empty_line_after_outer_attr", "Unresolved", false),
+    ("empty_loop", "Unresolved", false),
+    ("empty_structs_with_brackets", "Unspecified", false),
+    ("enum_clike_unportable_variant", "Unresolved", false),
+    ("enum_glob_use", "MachineApplicable", false),
+    ("enum_variant_names", "Unresolved", false),
+    ("eq_op", "Unresolved", false),
+    ("equatable_if_let", "MachineApplicable", false),
+    ("erasing_op", "Unresolved", false),
+    ("err_expect", "MachineApplicable", true),
+    ("error_impl_error", "Unresolved", false),
+    ("excessive_nesting", "Unresolved", false),
+    ("excessive_precision", "MachineApplicable", false),
+    ("exhaustive_enums", "MaybeIncorrect", false),
+    ("exhaustive_structs", "MaybeIncorrect", false),
+    ("exit", "Unresolved", false),
+    ("expect_fun_call", "MachineApplicable", false),
+    ("expect_used", "Unresolved", false),
+    ("expl_impl_clone_on_copy", "Unresolved", false),
+    ("explicit_auto_deref", "MachineApplicable", false),
+    ("explicit_counter_loop", "MaybeIncorrect", false),
+    ("explicit_deref_methods", "MachineApplicable", false),
+    ("explicit_into_iter_loop", "MachineApplicable", false),
+    ("explicit_iter_loop", "MachineApplicable", true),
+    ("explicit_write", "MachineApplicable", false),
+    ("extend_with_drain", "MachineApplicable", false),
+    ("extra_unused_lifetimes", "Unresolved", false),
+    ("extra_unused_type_parameters", "MachineApplicable", false),
+    ("fallible_impl_from", "Unresolved", false),
+    ("field_reassign_with_default", "Unresolved", false),
+    ("filetype_is_file", "Unresolved", false),
+    ("filter_map_bool_then", "MachineApplicable", false),
+    ("filter_map_identity", "MachineApplicable", false),
+    ("filter_map_next", "Unresolved", true),
+    ("filter_next", "Unresolved", false),
+    ("flat_map_identity", "MachineApplicable", false),
+    ("flat_map_option", "MachineApplicable", false),
+    ("float_arithmetic", "Unresolved", false),
+    ("float_cmp", "Unresolved", false),
+    ("float_cmp_const", "Unresolved", false),
+    ("float_equality_without_abs", "MaybeIncorrect", false),
+    ("fn_address_comparisons", "Unresolved", false),
+    ("fn_params_excessive_bools", "Unresolved", false),
+    ("fn_to_numeric_cast", "MaybeIncorrect", false),
+    ("fn_to_numeric_cast_any", "MaybeIncorrect", false),
+    ("fn_to_numeric_cast_with_truncation", "MaybeIncorrect", false),
+    ("for_kv_map", "Unresolved", false),
+    ("forget_non_drop", "Unresolved", false),
+    ("format_collect", "Unresolved", false),
+    ("format_in_format_args", "Unresolved", false),
+    ("format_push_string", "Unresolved", false),
+    ("four_forward_slashes", "MachineApplicable", false),
+    ("from_iter_instead_of_collect", "MaybeIncorrect", false),
+    ("from_over_into", "MachineApplicable", true),
+    ("from_raw_with_void_ptr", "Unresolved", false),
+    ("from_str_radix_10", "MaybeIncorrect", false),
+    ("future_not_send", "Unresolved", false),
+    ("get_first", "MachineApplicable", false),
+    ("get_last_with_len", "MachineApplicable", false),
+    ("get_unwrap", "MachineApplicable", false),
+    ("host_endian_bytes", "Unresolved", false),
+    ("identity_op", "MachineApplicable", false),
+    ("if_let_mutex", "Unresolved", false),
+    ("if_not_else", "Unresolved", false),
+    ("if_same_then_else", "Unresolved", false),
+    ("if_then_some_else_none", "Unresolved", true),
+    ("ifs_same_cond", "Unresolved", false),
+    ("ignored_unit_patterns", "MachineApplicable", false),
+    ("impl_hash_borrow_with_str_and_bytes", "Unresolved", false),
+    ("impl_trait_in_params", "Unresolved", false),
+    ("implicit_clone", "MachineApplicable", false),
+    ("implicit_hasher", "Unresolved", false),
+    ("implicit_return", "MachineApplicable", false),
+    ("implicit_saturating_add", "MachineApplicable", false),
+    ("implicit_saturating_sub", "MachineApplicable", false),
+    ("implied_bounds_in_impls", "MachineApplicable", false),
+    ("impossible_comparisons", "Unresolved", false),
+    ("imprecise_flops", "MachineApplicable", false),
+    ("incompatible_msrv", "Unresolved", false),
+    ("inconsistent_digit_grouping", "MachineApplicable", false),
+    ("inconsistent_struct_constructor", "MachineApplicable", false),
+    ("index_refutable_slice", "MaybeIncorrect", true),
+    ("indexing_slicing", "Unresolved", false),
+    ("ineffective_bit_mask", "Unresolved", false),
+    ("inefficient_to_string", "MachineApplicable", false),
+    ("infallible_destructuring_match", "MachineApplicable", false),
+    ("infinite_iter", "Unresolved", false),
+    ("inherent_to_string", "Unresolved", false),
+    ("inherent_to_string_shadow_display", "Unresolved", false),
+    ("init_numbered_fields", "MachineApplicable", false),
+    ("inline_always", "Unresolved", false),
+    ("inline_asm_x86_att_syntax", "Unresolved", false),
+    ("inline_asm_x86_intel_syntax", "Unresolved", false),
+    ("inline_fn_without_body", "MachineApplicable", false),
+    ("inspect_for_each", "Unresolved", false),
+    ("int_plus_one", "MachineApplicable", false),
+    ("integer_division", "Unresolved", false),
+    ("into_iter_on_ref", "MachineApplicable", false),
+    ("into_iter_without_iter", "Unspecified", false),
+    ("invalid_null_ptr_usage", "MachineApplicable", false),
+    ("invalid_regex", "Unresolved", false),
+    ("invalid_upcast_comparisons", "Unresolved", false),
+    ("invisible_characters", "MachineApplicable", false),
+    ("is_digit_ascii_radix", "MachineApplicable", true),
+    ("items_after_statements", "Unresolved", false),
+    ("items_after_test_module", "MachineApplicable", false),
+    ("iter_cloned_collect", "MachineApplicable", false),
+    ("iter_count", "MachineApplicable", false),
+    ("iter_kv_map", "Unresolved", true),
+    ("iter_next_loop", "Unresolved", false),
+    ("iter_next_slice", "MachineApplicable", false),
+    ("iter_not_returning_iterator", "Unresolved", false),
+    ("iter_nth", "Unresolved", false),
+    ("iter_nth_zero", "MachineApplicable", false),
+    ("iter_on_empty_collections", "MaybeIncorrect", false),
+    ("iter_on_single_items", "MaybeIncorrect", false),
+    ("iter_out_of_bounds", "Unresolved", false),
+    ("iter_over_hash_type", "Unresolved", false),
+    ("iter_overeager_cloned", "MachineApplicable", false),
+    ("iter_skip_next", "MachineApplicable", false),
+    ("iter_skip_zero", "MaybeIncorrect", false),
+    ("iter_with_drain", "MaybeIncorrect", false),
+    ("iter_without_into_iter", "Unspecified", false),
+    ("iterator_step_by_zero", "Unresolved", false),
+    ("join_absolute_paths", "Unspecified", false),
+    ("just_underscores_and_digits", "Unresolved", false),
+    ("large_const_arrays", "MachineApplicable", false),
+    ("large_digit_groups", "MachineApplicable", false),
+    ("large_enum_variant", "MaybeIncorrect", false),
+    ("large_futures", "Unspecified", false),
+    ("large_include_file", "Unresolved", false),
+    ("large_regex", "Unresolved", false),
+    ("large_stack_arrays", "Unresolved", false),
+    ("large_stack_frames", "Unresolved", false),
+    ("large_types_passed_by_value", "MaybeIncorrect", false),
+    ("len_without_is_empty", "Unresolved", false),
+    ("len_zero", "MachineApplicable", false),
+    ("let_and_return", "MachineApplicable", false),
+    ("let_underscore_future", "Unresolved", false),
+    ("let_underscore_lock", "Unresolved", false),
+    ("let_underscore_must_use", "Unresolved", false),
+    ("let_underscore_untyped", "Unresolved", false),
+    ("let_unit_value", "MachineApplicable", false),
+    ("let_with_type_underscore", "Unresolved", false),
+    ("lines_filter_map_ok", "MaybeIncorrect", false),
+    ("linkedlist", "Unresolved", false),
+    ("little_endian_bytes", "Unresolved", false),
+    ("lossy_float_literal", "MachineApplicable", false),
+    ("macro_use_imports", "MaybeIncorrect", false),
+    ("main_recursion", "Unresolved", false),
+    ("manual_assert", "MachineApplicable", false),
+    ("manual_async_fn", "MachineApplicable", false),
+    ("manual_bits", "MachineApplicable", true),
+    ("manual_clamp", "Unresolved", true),
+    ("manual_filter", "Unresolved", false),
+    ("manual_filter_map", "MachineApplicable", false),
+    ("manual_find", "MachineApplicable", false),
+    ("manual_find_map", "MachineApplicable", false),
+    ("manual_flatten", "MaybeIncorrect", false),
+    ("manual_hash_one", "MachineApplicable", true),
+    ("manual_instant_elapsed", "MachineApplicable", false),
+    ("manual_is_ascii_check", "MachineApplicable", true),
+    ("manual_is_finite", "Unresolved", false),
+    ("manual_is_infinite", "Unresolved", false),
+    ("manual_let_else", "HasPlaceholders", true),
+    ("manual_main_separator_str", "MachineApplicable", true),
+    ("manual_map", "Unresolved", false),
+    ("manual_memcpy", "Unspecified", false),
+    ("manual_next_back", "MachineApplicable", false),
+    ("manual_non_exhaustive", "Unspecified", true),
+    ("manual_ok_or", "MachineApplicable", false),
+    ("manual_range_contains", "MachineApplicable", true),
+    ("manual_range_patterns", "MachineApplicable", false),
+    ("manual_rem_euclid", "MachineApplicable", true),
+    ("manual_retain", "MachineApplicable", true),
+    ("manual_saturating_arithmetic", "MachineApplicable", false),
+    ("manual_slice_size_calculation", "MachineApplicable", false),
+    ("manual_split_once", "MachineApplicable", true),
+    ("manual_str_repeat", "MachineApplicable", true),
+    ("manual_string_new", "MachineApplicable", false),
+    ("manual_strip", "Unresolved", true),
+    ("manual_swap", "MachineApplicable", false),
+    ("manual_try_fold", "HasPlaceholders", true),
+    ("manual_unwrap_or", "MachineApplicable", false),
+    ("manual_while_let_some", "MachineApplicable", false),
+    ("many_single_char_names", "Unresolved", false),
+    ("map_clone", "MachineApplicable", false),
+    ("map_collect_result_unit", "MachineApplicable", false),
+    ("map_entry", "MachineApplicable", false),
+    ("map_err_ignore", "Unresolved", false),
+    ("map_flatten", "MachineApplicable", false),
+    ("map_identity", "MachineApplicable", false),
+    ("map_unwrap_or", "MachineApplicable", true),
+    ("match_as_ref", "MachineApplicable", false),
+    ("match_bool", "HasPlaceholders", false),
+    ("match_like_matches_macro", "MaybeIncorrect", true),
+    ("match_on_vec_items", "MaybeIncorrect", false),
+    ("match_overlapping_arm", "Unresolved", false),
+    ("match_ref_pats", "Unresolved", false),
+    ("match_result_ok", "MachineApplicable", false),
+    ("match_same_arms", "MaybeIncorrect", false),
+    ("match_single_binding", "MachineApplicable", false),
+    ("match_str_case_mismatch", "MachineApplicable", false),
+    ("match_wild_err_arm", "Unresolved", false),
+    ("match_wildcard_for_single_variants", "MaybeIncorrect", false),
+    ("maybe_infinite_iter", "Unresolved", false),
+    ("maybe_misused_cfg", "MaybeIncorrect", false),
+    ("mem_forget", "Unresolved", false),
+    ("mem_replace_option_with_none", "MachineApplicable", false),
+    ("mem_replace_with_default", "MachineApplicable", true),
+    ("mem_replace_with_uninit", "Unresolved", false),
+    ("min_ident_chars", "Unresolved", false),
+    ("min_max", "Unresolved", false),
+    ("mismatched_target_os", "MaybeIncorrect", false),
+    ("mismatching_type_param_order", "Unresolved", false),
+    ("misnamed_getters", "MaybeIncorrect", false),
+    ("misrefactored_assign_op", "MaybeIncorrect", false),
+    ("missing_assert_message", "Unresolved", false),
+    ("missing_asserts_for_indexing", "Unresolved", false),
+    ("missing_const_for_fn", "Unresolved", true),
+    ("missing_docs_in_private_items", "Unresolved", false),
+    ("missing_enforced_import_renames", "MachineApplicable", false),
+    ("missing_errors_doc", "Unresolved", false),
+    ("missing_examples_doc", "Unresolved", false),
+    ("missing_fields_in_debug", "Unresolved", false),
+    ("missing_inline_in_public_items", "Unresolved", false),
+    ("missing_panics_doc", "Unresolved", false),
+    ("missing_safety_doc", "Unresolved", false),
+    ("missing_spin_loop", "MachineApplicable", false),
+    ("missing_trait_methods", "Unresolved", false),
+    ("mistyped_literal_suffixes", "MaybeIncorrect", false),
+    ("mixed_case_hex_literals", "Unresolved", false),
+    ("mixed_read_write_in_expression", "Unresolved", false),
+    ("mod_module_files", "Unresolved", false),
+    ("module_inception", "Unresolved", false),
+    ("module_name_repetitions", "Unresolved", false),
+    ("modulo_arithmetic", "Unresolved", false),
+    ("modulo_one", "Unresolved", false),
+    ("multi_assignments", "Unresolved", false),
+    ("multiple_crate_versions", "Unresolved", false),
+    ("multiple_inherent_impl", "Unresolved", false),
+    ("multiple_unsafe_ops_per_block", "Unresolved", false),
+    ("must_use_candidate", "MachineApplicable", false),
+    ("must_use_unit", "MachineApplicable", false),
+    ("mut_from_ref", "Unresolved", false),
+    ("mut_mut", "Unresolved", false),
+    ("mut_mutex_lock", "MaybeIncorrect", false),
+    ("mut_range_bound", "Unresolved", false),
+    ("mutable_key_type", "Unresolved", false),
+    ("mutex_atomic", "Unresolved", false),
+    ("mutex_integer", "Unresolved", false),
+    ("naive_bytecount", "MaybeIncorrect", false),
+    ("needless_arbitrary_self_type", "MachineApplicable", false),
+    ("needless_bitwise_bool", "MachineApplicable", false),
+    ("needless_bool", "Unresolved", false),
+    ("needless_bool_assign", "MachineApplicable", false),
+    ("needless_borrow", "MachineApplicable", false),
+    ("needless_borrowed_reference", "MachineApplicable", false),
+    ("needless_borrows_for_generic_args", "MachineApplicable", false),
+    ("needless_collect", "MachineApplicable", false),
+    ("needless_continue", "Unresolved", false),
+    ("needless_doctest_main", "Unresolved", false),
+    ("needless_else", "MachineApplicable", false),
+    ("needless_for_each", "MachineApplicable", false),
+    ("needless_if", "MachineApplicable", false),
+    ("needless_late_init", "Unresolved", false),
+    ("needless_lifetimes", "MachineApplicable", false),
+    ("needless_match", "MachineApplicable", false),
+    ("needless_option_as_deref", "MachineApplicable", false),
+    ("needless_option_take", "MachineApplicable", false),
+    ("needless_parens_on_range_literals", "MachineApplicable", false),
+    ("needless_pass_by_ref_mut", "Unspecified", false),
+    ("needless_pass_by_value", "Unresolved", false),
+    ("needless_pub_self", "MachineApplicable", false),
+    ("needless_question_mark", "MachineApplicable", false),
+    ("needless_range_loop", "Unresolved", false),
+    ("needless_raw_string_hashes", "MachineApplicable", false),
+    ("needless_raw_strings", "MachineApplicable", false),
+    ("needless_return", "Unresolved", false),
+    ("needless_return_with_question_mark", "MachineApplicable", false),
+    ("needless_splitn", "MachineApplicable", false),
+    ("needless_update", "Unresolved", false),
+    ("neg_cmp_op_on_partial_ord", "Unresolved", false),
+    ("neg_multiply", "MachineApplicable", false),
+    ("negative_feature_names", "Unresolved", false),
+    ("never_loop", "Unspecified", false),
+    ("new_ret_no_self", "Unresolved", false),
+    ("new_without_default", "MachineApplicable", false),
+    ("no_effect", "MaybeIncorrect", false),
+    ("no_effect_replace", "Unresolved", false),
+    ("no_effect_underscore_binding", "Unresolved", false),
+    ("no_mangle_with_rust_abi", "MaybeIncorrect", false),
+    ("non_ascii_literal", "MachineApplicable", false),
+    ("non_canonical_clone_impl", "MaybeIncorrect", false),
+    ("non_canonical_partial_ord_impl", "Unspecified", false),
+    ("non_minimal_cfg", "Unresolved", false),
+    ("non_octal_unix_permissions", "MachineApplicable", false),
+    ("non_send_fields_in_send_ty", "Unresolved", false),
+    ("nonminimal_bool", "MachineApplicable", false),
+    ("nonsensical_open_options", "Unresolved", false),
+    ("nonstandard_macro_braces", "MachineApplicable", false),
+    ("not_unsafe_ptr_arg_deref", "Unresolved", false),
+    ("obfuscated_if_else", "MachineApplicable", false),
+    ("octal_escapes", "MaybeIncorrect", false),
+    ("ok_expect", "Unresolved", false),
+    ("only_used_in_recursion", "MaybeIncorrect", false),
+    ("op_ref", "MaybeIncorrect", false),
+    ("option_as_ref_deref", "MachineApplicable", true),
+    ("option_env_unwrap", "Unresolved", false),
+    ("option_filter_map", "MachineApplicable", false),
+    ("option_if_let_else", "MaybeIncorrect", false),
+    ("option_map_or_err_ok", "MachineApplicable", false),
+    ("option_map_or_none", "MachineApplicable", false),
+    ("option_map_unit_fn", "MachineApplicable", false),
+    ("option_option", "Unresolved", false),
+    ("or_fun_call", "HasPlaceholders", false),
+    ("or_then_unwrap", "MachineApplicable", false),
+    ("out_of_bounds_indexing", "Unresolved", false),
+    ("overflow_check_conditional", "Unresolved", false),
+    ("overly_complex_bool_expr", "Unspecified", false),
+    ("panic", "Unresolved", false),
+    ("panic_in_result_fn", "Unresolved", false),
+    ("panicking_unwrap", "Unresolved", false),
+    ("partial_pub_fields", "Unresolved", false),
+    ("partialeq_ne_impl", "Unresolved", false),
+    ("partialeq_to_none", "MachineApplicable", false),
+    ("path_buf_push_overwrite", "MachineApplicable", false),
+    ("path_ends_with_ext", "MaybeIncorrect", false),
+    ("pattern_type_mismatch", "Unresolved", false),
+    ("permissions_set_readonly_false", "Unresolved", false),
+    ("possible_missing_comma", "Unresolved", false),
+    ("precedence", "MachineApplicable", false),
+    ("print_in_format_impl", "HasPlaceholders", false),
+    ("print_literal", "MachineApplicable", false),
+    ("print_stderr", "Unresolved", false),
+    ("print_stdout", "Unresolved", false),
+    ("print_with_newline", "MachineApplicable", false),
+    ("println_empty_string", "MachineApplicable", false),
+    ("ptr_arg", "Unspecified", false),
+    ("ptr_as_ptr", "MachineApplicable", true),
+    ("ptr_cast_constness", "MachineApplicable", true),
+    ("ptr_eq", "MachineApplicable", false),
+    ("ptr_offset_with_cast", "Unresolved", false),
+    ("pub_use", "Unresolved", false),
+    ("pub_with_shorthand", "MachineApplicable", false),
+    ("pub_without_shorthand", "MachineApplicable", false),
+    ("question_mark", "MachineApplicable", false),
+    ("question_mark_used", "Unresolved", false),
+    ("range_minus_one", "MachineApplicable", false),
+    ("range_plus_one", "MachineApplicable", false),
+    ("range_zip_with_len", "Unresolved", false),
+    ("rc_buffer", "Unspecified", false),
+    ("rc_clone_in_vec_init", "HasPlaceholders", false),
+    ("rc_mutex", "Unresolved", false),
+    ("read_line_without_trim", "MachineApplicable", false),
+    ("read_zero_byte_vec", "Unresolved", false),
+    ("readonly_write_lock", "MaybeIncorrect", false),
+    ("recursive_format_impl", "Unresolved", false),
+    ("redundant_allocation", "Unresolved", false),
+    ("redundant_as_str", "MachineApplicable", false),
+    ("redundant_async_block", "MachineApplicable", false),
+    ("redundant_at_rest_pattern", "MachineApplicable", false),
+    ("redundant_clone", "Unresolved", false),
+    ("redundant_closure", "MachineApplicable", false),
+    ("redundant_closure_call", "Unresolved", false),
+    ("redundant_closure_for_method_calls", "MachineApplicable", false),
+    ("redundant_comparisons", "Unresolved", false),
+    ("redundant_else", "Unresolved", false),
+    ("redundant_feature_names", "Unresolved", false),
+    ("redundant_field_names", "MachineApplicable", true),
+    ("redundant_guards", "MaybeIncorrect", false),
+    ("redundant_locals", "Unresolved", false),
+    ("redundant_pattern", "MachineApplicable", false),
+    ("redundant_pattern_matching", "MachineApplicable", false),
+    ("redundant_pub_crate", "MachineApplicable", false),
+    ("redundant_slicing", "Unresolved", false),
+    ("redundant_static_lifetimes", "MachineApplicable", true),
+    ("redundant_type_annotations", "Unresolved", false),
+    ("ref_binding_to_reference", "Unresolved", false),
+    ("ref_option_ref", "MaybeIncorrect", false),
+    ("ref_patterns", "Unresolved", false),
+    ("regex_creation_in_loops", "Unresolved", false),
+    ("repeat_once", "MachineApplicable", false),
+    ("repeat_vec_with_capacity", "MaybeIncorrect", false),
+    ("reserve_after_initialization", "HasPlaceholders", false),
+    ("rest_pat_in_fully_bound_structs", "Unresolved", false),
+    ("result_large_err", "Unresolved", false),
+    ("result_map_or_into_option", "MachineApplicable", false),
+    ("result_map_unit_fn", "MachineApplicable", false),
+    ("result_unit_err", "Unresolved", false),
+    ("return_self_not_must_use", "Unresolved", false),
+    ("reversed_empty_ranges", "MaybeIncorrect", false),
+    ("same_functions_in_if_condition", "Unresolved", false),
+    ("same_item_push", "Unresolved", false),
+    ("same_name_method", "Unresolved", false),
+    ("search_is_some", "MachineApplicable", false),
+    ("seek_from_current", "MachineApplicable", true),
+    ("seek_to_start_instead_of_rewind", "MachineApplicable", true),
+    ("self_assignment", "Unresolved", false),
+    ("self_named_constructors", "Unresolved", false),
+    ("self_named_module_files", "Unresolved", false),
+    ("semicolon_if_nothing_returned", "Unresolved", false),
+    ("semicolon_inside_block", "MachineApplicable", false),
+    ("semicolon_outside_block", "MachineApplicable", false),
+    ("separated_literal_suffix", "MachineApplicable", false),
+    ("serde_api_misuse", "Unresolved", false),
+    ("serde_default_without_default", "Unresolved", false),
+    ("serde_duplicate_name", "Unresolved", false),
+    ("serde_flatten_with_deny_unknown_fields", "Unresolved", false),
+    ("serde_unwrap_in_visitor", "Unresolved", false),
+    ("shadow_reuse", "Unresolved", false),
+    ("shadow_same", "Unresolved", false),
+    ("shadow_unrelated", "Unresolved", false),
+    ("short_circuit_statement", "MachineApplicable", false),
+    ("should_implement_trait", "Unresolved", false),
+    ("should_panic_without_expect", "HasPlaceholders", false),
+    ("significant_drop_in_scrutinee", "Unresolved", false),
+    ("significant_drop_tightening", "MaybeIncorrect", false),
+    ("similar_names", "Unresolved", false),
+    ("single_call_fn", "Unresolved", false),
+    ("single_char_add_str", "MachineApplicable", false),
+    ("single_char_lifetime_names", "Unresolved", false),
+    ("single_char_pattern", "Unresolved", false),
+    ("single_component_path_imports", "Unresolved", false),
+    ("single_element_loop", "MachineApplicable", false),
+    ("single_match", "HasPlaceholders", false),
+    ("single_match_else", "HasPlaceholders", false),
+    ("single_range_in_vec_init", "MaybeIncorrect", false),
+    ("size_of_in_element_count", "Unresolved", false),
+    ("size_of_ref", "Unresolved", false),
+    ("skip_while_next", "Unresolved", false),
+    ("slow_vector_initialization", "Unspecified", false),
+    ("stable_sort_primitive", "MachineApplicable", false),
+    ("std_instead_of_alloc", "MachineApplicable", false),
+    ("std_instead_of_core", "MachineApplicable", false),
+    ("str_to_string", "Unresolved", false),
+    ("string_add", "Unresolved", false),
+    ("string_add_assign", "Unresolved", false),
+    ("string_extend_chars", "MachineApplicable", false),
+    ("string_from_utf8_as_bytes", "MachineApplicable", false),
+    ("string_lit_as_bytes", "MachineApplicable", false),
+    ("string_lit_chars_any", "MachineApplicable", true),
+    ("string_slice", "Unresolved", false),
+    ("string_to_string", "Unresolved", false),
+    ("strlen_on_c_strings", "MachineApplicable", false),
+    ("struct_excessive_bools", "Unresolved", false),
+    ("struct_field_names", "Unresolved", false),
+    ("suboptimal_flops", "MachineApplicable", false),
+    ("suspicious_arithmetic_impl", "Unresolved", false),
+    ("suspicious_assignment_formatting", "Unresolved", false),
+    ("suspicious_command_arg_space", "MaybeIncorrect", false),
+    ("suspicious_doc_comments", "MaybeIncorrect", false),
+    ("suspicious_else_formatting", "Unresolved", false),
+    ("suspicious_map", "Unresolved", false),
+    ("suspicious_op_assign_impl", "Unresolved", false),
+    ("suspicious_operation_groupings", "Unresolved", false),
+    ("suspicious_splitn", "Unresolved", false),
+    ("suspicious_to_owned", "MaybeIncorrect", false),
+    ("suspicious_unary_op_formatting", "Unresolved", false),
+    ("suspicious_xor_used_as_pow", "MaybeIncorrect", false),
+    ("swap_ptr_to_ref", "MachineApplicable", false),
+    ("tabs_in_doc_comments", "MaybeIncorrect", false),
+    ("temporary_assignment", "Unresolved", false),
+    ("test_attr_in_doctest", "Unresolved", false),
+    ("tests_outside_test_module", "Unresolved", false),
+    ("to_digit_is_some", "MachineApplicable", false),
+    ("to_string_in_format_args", "MachineApplicable", false),
+    ("todo", "Unresolved", false),
+    ("too_many_arguments", "Unresolved", false),
+    ("too_many_lines", "Unresolved", false),
+    ("toplevel_ref_arg", "MachineApplicable", false),
+    ("trailing_empty_array", "Unresolved", false),
+    ("trait_duplication_in_bounds", "MachineApplicable", false),
+    ("transmute_bytes_to_str", "MaybeIncorrect", false),
+    ("transmute_float_to_int", "Unspecified", false),
+    ("transmute_int_to_bool", "Unspecified", false),
+    ("transmute_int_to_char", "Unspecified", false),
+    ("transmute_int_to_float", "Unspecified", false),
+    ("transmute_int_to_non_zero", "Unspecified", false),
+    ("transmute_null_to_fn", "Unresolved", false),
+    ("transmute_num_to_bytes", "Unspecified", false),
+    ("transmute_ptr_to_ptr", "Unspecified", false),
+    ("transmute_ptr_to_ref", "MachineApplicable", false),
+    ("transmute_undefined_repr", "Unresolved", false),
+    ("transmutes_expressible_as_ptr_casts", "MachineApplicable", false),
+    ("transmuting_null", "Unresolved", false),
+    ("trim_split_whitespace", "MachineApplicable", false),
+    ("trivial_regex", "Unresolved", false),
+    ("trivially_copy_pass_by_ref", "Unspecified", false),
+    ("try_err", "MachineApplicable", false),
+    ("tuple_array_conversions", "Unresolved", true),
+    ("type_complexity", "Unresolved", false),
+    ("type_id_on_box", "MaybeIncorrect", false),
+    ("type_repetition_in_bounds", "Unresolved", false),
+    ("unchecked_duration_subtraction", "MachineApplicable", true),
+    ("undocumented_unsafe_blocks", "Unresolved", false),
+    ("unicode_not_nfc", "MachineApplicable", false),
+    ("unimplemented", "Unresolved", false),
+    ("uninit_assumed_init", "Unresolved", false),
+    ("uninit_vec", "Unresolved", false),
+    ("uninlined_format_args", "MachineApplicable", true),
+    ("unit_arg", "MachineApplicable", false),
+    ("unit_cmp", "Unresolved", false),
+    ("unit_hash", "MaybeIncorrect", false),
+    ("unit_return_expecting_ord", "Unresolved", false),
+    ("unnecessary_box_returns", "Unspecified", false),
+    ("unnecessary_cast", "MachineApplicable", false),
+    ("unnecessary_fallible_conversions", "MachineApplicable", false),
+    ("unnecessary_filter_map", "Unresolved", false),
+    ("unnecessary_find_map", "Unresolved", false),
+    ("unnecessary_fold", "MachineApplicable", false),
+    ("unnecessary_join", "MachineApplicable", false),
+    ("unnecessary_lazy_evaluations", "MachineApplicable", true),
+    ("unnecessary_literal_unwrap", "MachineApplicable", false),
+    ("unnecessary_map_on_constructor", "MachineApplicable", false),
+    ("unnecessary_mut_passed", "Unresolved", false),
+    ("unnecessary_operation", "MachineApplicable", false),
+    ("unnecessary_owned_empty_strings", "MachineApplicable", false),
+    ("unnecessary_safety_comment", "Unresolved", false),
+    ("unnecessary_safety_doc", "Unresolved", false),
+    ("unnecessary_self_imports", "MaybeIncorrect", false),
+    ("unnecessary_sort_by", "MachineApplicable", false),
+    ("unnecessary_struct_initialization", "Unresolved", false),
+    ("unnecessary_to_owned", "MachineApplicable", false),
+    ("unnecessary_unwrap", "Unspecified", false),
+    ("unnecessary_wraps", "MaybeIncorrect", false),
+    ("unneeded_field_pattern", "Unresolved", false),
+    ("unneeded_wildcard_pattern", "MachineApplicable", false),
+    ("unnested_or_patterns", "MachineApplicable", true),
+    ("unreachable", "Unresolved", false),
+    ("unreadable_literal", "MachineApplicable", false),
+    ("unsafe_derive_deserialize", "Unresolved", false),
+    ("unsafe_removed_from_name", "Unresolved", false),
+    ("unseparated_literal_suffix", "MachineApplicable", false),
+    ("unsound_collection_transmute", "Unresolved", false),
+    ("unused_async", "Unresolved", false),
+    ("unused_enumerate_index", "Unresolved", false),
+    ("unused_format_specs", "MaybeIncorrect", false),
+    ("unused_io_amount", "Unresolved", false),
+    ("unused_peekable", "Unresolved", false),
+    ("unused_rounding", "MachineApplicable", false),
+    ("unused_self", "Unresolved", false),
+    ("unused_unit", "MachineApplicable", false),
+    ("unusual_byte_groupings", "MachineApplicable", false),
+    ("unwrap_in_result", "Unresolved", false),
+    ("unwrap_or_default", "MachineApplicable", false),
+    ("unwrap_used", "Unresolved", false),
+    ("upper_case_acronyms", "MaybeIncorrect", false),
+    ("use_debug", "Unresolved", false),
+    ("use_self", "MachineApplicable", true),
+    ("used_underscore_binding", "Unresolved", false),
+    ("useless_asref", "MachineApplicable", false),
+    ("useless_attribute", "MaybeIncorrect", false),
+    ("useless_conversion", "MachineApplicable", false),
+    ("useless_format", "Unresolved", false),
+    ("useless_let_if_seq", "HasPlaceholders", false),
+    ("useless_transmute", "Unspecified", false),
+    ("useless_vec", "MachineApplicable", true),
+    ("vec_box", "Unspecified", false),
+    ("vec_init_then_push", "HasPlaceholders", false),
+    ("vec_resize_to_zero", "MaybeIncorrect", false),
+    ("verbose_bit_mask", "MaybeIncorrect", false),
+    ("verbose_file_reads", "Unresolved", false),
+    ("vtable_address_comparisons", "Unresolved", false),
+    ("waker_clone_wake", "MachineApplicable", false),
+    ("while_immutable_condition", "Unresolved", false),
+    ("while_let_loop", "HasPlaceholders", false),
+    ("while_let_on_iterator", "MachineApplicable", false),
+    ("wildcard_dependencies", "Unresolved", false),
+    ("wildcard_enum_match_arm", "MaybeIncorrect", false),
+    ("wildcard_imports", "MachineApplicable", false),
+    ("wildcard_in_or_patterns", "Unresolved", false),
+    ("write_literal", "MachineApplicable", false),
+    ("write_with_newline", "MachineApplicable", false),
+    ("writeln_empty_string", "MachineApplicable", false),
+    ("wrong_self_convention", "Unresolved", false),
+    ("wrong_transmute", "Unresolved", false),
+    ("zero_divided_by_zero", "Unresolved", false),
+    ("zero_prefixed_literal", "MaybeIncorrect", false),
+    ("zero_ptr", "MachineApplicable", false),
+    ("zero_sized_map_values", "Unresolved", false),
+    ("zst_offset", "Unresolved", false),
+];
@@ -205,7 +205,11 @@ impl<'tcx> LateLintPass<'tcx> for DerivableImpls {
         {
             if adt_def.is_struct() {
                 check_struct(cx, item, self_ty, func_expr, adt_def, args, cx.tcx.typeck_body(*b));
-            } else if adt_def.is_enum() && self.msrv.meets(msrvs::DEFAULT_ENUM_ATTRIBUTE) {
+            } else if adt_def.is_enum()
+                && self
+                    .msrv
+                    .meets_or_report(DERIVABLE_IMPLS, item.span, msrvs::DEFAULT_ENUM_ATTRIBUTE)
+            {
                 check_enum(cx, item, func_expr, adt_def);
             }
         }
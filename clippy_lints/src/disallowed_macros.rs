@@ -1,5 +1,6 @@
 use clippy_config::types::DisallowedPath;
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::disallowed_path_res;
 use clippy_utils::macros::macro_backtrace;
 use rustc_ast::Attribute;
 use rustc_data_structures::fx::FxHashSet;
@@ -55,14 +56,16 @@ declare_clippy_lint! {
 
 pub struct DisallowedMacros {
     conf_disallowed: Vec<DisallowedPath>,
+    error_on_unresolved: bool,
     disallowed: DefIdMap<usize>,
     seen: FxHashSet<ExpnId>,
 }
 
 impl DisallowedMacros {
-    pub fn new(conf_disallowed: Vec<DisallowedPath>) -> Self {
+    pub fn new(conf_disallowed: Vec<DisallowedPath>, error_on_unresolved: bool) -> Self {
         Self {
             conf_disallowed,
+            error_on_unresolved,
             disallowed: DefIdMap::default(),
             seen: FxHashSet::default(),
         }
@@ -102,9 +105,10 @@ impl_lint_pass!(DisallowedMacros => [DISALLOWED_MACROS]);
 impl LateLintPass<'_> for DisallowedMacros {
     fn check_crate(&mut self, cx: &LateContext<'_>) {
         for (index, conf) in self.conf_disallowed.iter().enumerate() {
-            let segs: Vec<_> = conf.path().split("::").collect();
-            for id in clippy_utils::def_path_def_ids(cx, &segs) {
-                self.disallowed.insert(id, index);
+            for res in disallowed_path_res(cx, conf, self.error_on_unresolved) {
+                if let Some(id) = res.opt_def_id() {
+                    self.disallowed.insert(id, index);
+                }
             }
         }
     }
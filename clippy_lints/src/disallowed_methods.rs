@@ -1,6 +1,6 @@
 use clippy_config::types::DisallowedPath;
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::{fn_def_id, get_parent_expr, path_def_id};
+use clippy_utils::{disallowed_path_res, fn_def_id, get_parent_expr, path_def_id};
 use rustc_hir::def_id::DefIdMap;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
@@ -58,13 +58,15 @@ declare_clippy_lint! {
 #[derive(Clone, Debug)]
 pub struct DisallowedMethods {
     conf_disallowed: Vec<DisallowedPath>,
+    error_on_unresolved: bool,
     disallowed: DefIdMap<usize>,
 }
 
 impl DisallowedMethods {
-    pub fn new(conf_disallowed: Vec<DisallowedPath>) -> Self {
+    pub fn new(conf_disallowed: Vec<DisallowedPath>, error_on_unresolved: bool) -> Self {
         Self {
             conf_disallowed,
+            error_on_unresolved,
             disallowed: DefIdMap::default(),
         }
     }
@@ -75,9 +77,10 @@ impl_lint_pass!(DisallowedMethods => [DISALLOWED_METHODS]);
 impl<'tcx> LateLintPass<'tcx> for DisallowedMethods {
     fn check_crate(&mut self, cx: &LateContext<'_>) {
         for (index, conf) in self.conf_disallowed.iter().enumerate() {
-            let segs: Vec<_> = conf.path().split("::").collect();
-            for id in clippy_utils::def_path_def_ids(cx, &segs) {
-                self.disallowed.insert(id, index);
+            for res in disallowed_path_res(cx, conf, self.error_on_unresolved) {
+                if let Some(id) = res.opt_def_id() {
+                    self.disallowed.insert(id, index);
+                }
             }
         }
     }
@@ -1,5 +1,6 @@
 use clippy_config::types::DisallowedPath;
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::disallowed_path_res;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::def::Res;
 use rustc_hir::def_id::DefId;
@@ -52,14 +53,16 @@ declare_clippy_lint! {
 #[derive(Clone, Debug)]
 pub struct DisallowedTypes {
     conf_disallowed: Vec<DisallowedPath>,
+    error_on_unresolved: bool,
     def_ids: FxHashMap<DefId, usize>,
     prim_tys: FxHashMap<PrimTy, usize>,
 }
 
 impl DisallowedTypes {
-    pub fn new(conf_disallowed: Vec<DisallowedPath>) -> Self {
+    pub fn new(conf_disallowed: Vec<DisallowedPath>, error_on_unresolved: bool) -> Self {
         Self {
             conf_disallowed,
+            error_on_unresolved,
             def_ids: FxHashMap::default(),
             prim_tys: FxHashMap::default(),
         }
@@ -87,9 +90,7 @@ impl_lint_pass!(DisallowedTypes => [DISALLOWED_TYPES]);
 impl<'tcx> LateLintPass<'tcx> for DisallowedTypes {
     fn check_crate(&mut self, cx: &LateContext<'_>) {
         for (index, conf) in self.conf_disallowed.iter().enumerate() {
-            let segs: Vec<_> = conf.path().split("::").collect();
-
-            for res in clippy_utils::def_path_res(cx, &segs) {
+            for res in disallowed_path_res(cx, conf, self.error_on_unresolved) {
                 match res {
                     Res::Def(_, id) => {
                         self.def_ids.insert(id, index);
@@ -0,0 +1,148 @@
+use std::ops::Range;
+
+use clippy_utils::def_path_res;
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId, LOCAL_CRATE};
+use rustc_hir::ItemKind;
+use rustc_lint::LateContext;
+use rustc_middle::middle::privacy::Level;
+use rustc_middle::ty::Visibility;
+
+use super::{Fragments, DOC_LINK_TO_PRIVATE_ITEM};
+
+/// The disambiguators of intra-doc links, as in `struct@Foo`
+const DISAMBIGUATORS: &[&str] = &[
+    "struct",
+    "enum",
+    "trait",
+    "union",
+    "mod",
+    "module",
+    "const",
+    "constant",
+    "fn",
+    "function",
+    "method",
+    "derive",
+    "type",
+    "value",
+    "macro",
+    "static",
+    "field",
+    "variant",
+    "tyalias",
+    "prim",
+    "primitive",
+];
+
+pub fn check(cx: &LateContext<'_>, owner_id: LocalDefId, target: &str, range: Range<usize>, fragments: Fragments<'_>) {
+    let Some(path) = intra_doc_path(target) else {
+        return;
+    };
+
+    let owner_vis = visibility(cx, owner_id);
+    if let Some(def_id) = resolve(cx, owner_id, &path).into_iter().find_map(|def_id| {
+        def_id
+            .as_local()
+            .filter(|&id| !visibility(cx, id).is_at_least(owner_vis, cx.tcx))
+    }) && let Some(span) = fragments.span(cx, range)
+    {
+        span_lint_and_help(
+            cx,
+            DOC_LINK_TO_PRIVATE_ITEM,
+            span,
+            &format!(
+                "the docs link to `{}`, which is less visible than the documented item",
+                cx.tcx.def_path_str(def_id)
+            ),
+            None,
+            "the link is broken in the documentation that doesn't include the linked item",
+        );
+    }
+}
+
+/// The visibility of an item including its re-exports, e.g. a `pub` item of a private module that
+/// isn't re-exported is only visible in the crate
+fn visibility(cx: &LateContext<'_>, def_id: LocalDefId) -> Visibility {
+    cx.effective_visibilities.effective_vis(def_id).map_or_else(
+        || cx.tcx.visibility(def_id).expect_local(),
+        |vis| *vis.at_level(Level::Reexported),
+    )
+}
+
+/// Returns the segments of the path of an intra-doc link, e.g. `crate::Foo` for
+/// ``[`struct@crate::Foo`]``
+fn intra_doc_path(target: &str) -> Option<Vec<&str>> {
+    let target = target.trim().trim_matches('`');
+    let target = target.split('#').next()?;
+    let target = match target.split_once('@') {
+        Some((disambiguator, path)) if DISAMBIGUATORS.contains(&disambiguator) => path,
+        _ => target,
+    };
+    let target = target
+        .strip_suffix("()")
+        .or_else(|| target.strip_suffix('!'))
+        .unwrap_or(target);
+
+    let segments: Vec<&str> = target.split("::").collect();
+    segments
+        .iter()
+        .all(|segment| {
+            segment.starts_with(|c: char| c.is_alphabetic() || c == '_')
+                && segment.chars().all(|c| c.is_alphanumeric() || c == '_')
+        })
+        .then_some(segments)
+}
+
+/// Resolves the path of an intra-doc link, relative to the module of the documented item
+fn resolve(cx: &LateContext<'_>, owner_id: LocalDefId, path: &[&str]) -> Vec<DefId> {
+    let tcx = cx.tcx;
+    let crate_name = tcx.crate_name(LOCAL_CRATE);
+
+    let module = tcx.parent_module_from_def_id(owner_id).to_def_id();
+    let mut module_path: Vec<String> = vec![crate_name.to_string()];
+    module_path.extend(
+        tcx.def_path(module)
+            .data
+            .iter()
+            .filter_map(|data| data.data.get_opt_name())
+            .map(|name| name.to_string()),
+    );
+
+    let (mut absolute, rest) = match path {
+        ["Self", ..] => return Vec::new(),
+        ["crate", rest @ ..] => (vec![crate_name.to_string()], rest),
+        ["self", rest @ ..] => (module_path, rest),
+        ["super", ..] => {
+            let mut rest = path;
+            while let ["super", tail @ ..] = rest {
+                rest = tail;
+                module_path.pop();
+            }
+            (module_path, rest)
+        },
+        // the items of other crates are not checked
+        _ => (module_path, path),
+    };
+    absolute.extend(rest.iter().map(ToString::to_string));
+    resolve_absolute(cx, &absolute)
+}
+
+/// Resolves an absolute path with [`def_path_res`], following the `use` items
+fn resolve_absolute(cx: &LateContext<'_>, path: &[String]) -> Vec<DefId> {
+    if path.len() < 2 {
+        return Vec::new();
+    }
+    let path: Vec<&str> = path.iter().map(String::as_str).collect();
+    def_path_res(cx, &path)
+        .into_iter()
+        .flat_map(|res| match res {
+            Res::Def(DefKind::Use, def_id) => match def_id.as_local().map(|id| &cx.tcx.hir().expect_item(id).kind) {
+                Some(ItemKind::Use(path, _)) => path.res.iter().filter_map(Res::opt_def_id).collect(),
+                _ => Vec::new(),
+            },
+            res => res.opt_def_id().into_iter().collect(),
+        })
+        .collect()
+}
@@ -0,0 +1,63 @@
+use clippy_config::types::{DocItemKind, DocVisibility};
+use clippy_utils::diagnostics::span_lint;
+use clippy_utils::is_doc_hidden;
+use rustc_hir::def_id::CRATE_DEF_ID;
+use rustc_hir::OwnerId;
+use rustc_lint::LateContext;
+use rustc_middle::ty::Visibility;
+
+use super::{DocHeaders, MISSING_EXAMPLES_DOC};
+
+pub fn check(
+    cx: &LateContext<'_>,
+    owner_id: OwnerId,
+    kind: DocItemKind,
+    headers: DocHeaders,
+    min_visibility: DocVisibility,
+) {
+    if headers.examples {
+        return;
+    }
+
+    let is_visible = match min_visibility {
+        DocVisibility::Public => cx.effective_visibilities.is_exported(owner_id.def_id),
+        DocVisibility::Crate => cx
+            .tcx
+            .visibility(owner_id)
+            .is_at_least(Visibility::Restricted(CRATE_DEF_ID.to_def_id()), cx.tcx),
+        DocVisibility::Private => true,
+    };
+    if !is_visible {
+        return;
+    }
+
+    // do not lint if any parent has `#[doc(hidden)]` attribute
+    if cx
+        .tcx
+        .hir()
+        .parent_iter(owner_id.into())
+        .any(|(id, _node)| is_doc_hidden(cx.tcx.hir().attrs(id)))
+    {
+        return;
+    }
+
+    let kind = match kind {
+        DocItemKind::Fn => "function",
+        DocItemKind::Method => "method",
+        DocItemKind::Struct => "struct",
+        DocItemKind::Enum => "enum",
+        DocItemKind::Union => "union",
+        DocItemKind::Trait => "trait",
+        DocItemKind::TypeAlias => "type alias",
+        DocItemKind::Const => "constant",
+        DocItemKind::Static => "static",
+        DocItemKind::Macro => "macro",
+        DocItemKind::Mod => "module",
+    };
+    span_lint(
+        cx,
+        MISSING_EXAMPLES_DOC,
+        cx.tcx.def_span(owner_id),
+        &format!("docs for {kind} missing `# Examples` section"),
+    );
+}
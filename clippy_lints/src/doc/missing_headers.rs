@@ -1,12 +1,15 @@
-use clippy_utils::diagnostics::{span_lint, span_lint_and_note};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
 use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
-use clippy_utils::{is_doc_hidden, return_ty};
+use clippy_utils::{is_doc_hidden, is_lint_allowed, return_ty};
+use rustc_errors::MultiSpan;
 use rustc_hir::{BodyId, FnSig, OwnerId, Unsafety};
 use rustc_lint::LateContext;
 use rustc_middle::ty;
-use rustc_span::{sym, Span};
+use rustc_span::sym;
 
-use super::{DocHeaders, MISSING_ERRORS_DOC, MISSING_PANICS_DOC, MISSING_SAFETY_DOC, UNNECESSARY_SAFETY_DOC};
+use super::{
+    find_panic, DocHeaders, MISSING_ERRORS_DOC, MISSING_PANICS_DOC, MISSING_SAFETY_DOC, UNNECESSARY_SAFETY_DOC,
+};
 
 pub fn check(
     cx: &LateContext<'_>,
@@ -14,7 +17,7 @@ pub fn check(
     sig: &FnSig<'_>,
     headers: DocHeaders,
     body_id: Option<BodyId>,
-    panic_span: Option<Span>,
+    panics_call_depth: u64,
     check_private_items: bool,
 ) {
     if !check_private_items && !cx.effective_visibilities.is_exported(owner_id.def_id) {
@@ -48,14 +51,37 @@ pub fn check(
         ),
         _ => (),
     }
-    if !headers.panics && panic_span.is_some() {
-        span_lint_and_note(
+    // following the calls is expensive, only look for a panic if it would be linted
+    if !headers.panics
+        && body_id.is_some()
+        && !is_lint_allowed(cx, MISSING_PANICS_DOC, cx.tcx.local_def_id_to_hir_id(owner_id.def_id))
+        && let Some(panic) = find_panic(cx, owner_id.def_id, panics_call_depth)
+    {
+        span_lint_and_then(
             cx,
             MISSING_PANICS_DOC,
             span,
             "docs for function which may panic missing `# Panics` section",
-            panic_span,
-            "first possible panic found here",
+            |diag| {
+                if panic.calls.is_empty() {
+                    diag.span_note(panic.span, "first possible panic found here");
+                } else {
+                    let chain = panic
+                        .calls
+                        .iter()
+                        .map(|&(_, def_id)| format!("`{}`", cx.tcx.def_path_str(def_id)))
+                        .collect::<Vec<_>>()
+                        .join(" -> ");
+                    let mut spans = MultiSpan::from_span(panic.span);
+                    for &(call_span, def_id) in &panic.calls {
+                        spans.push_span_label(call_span, format!("`{}` is called here", cx.tcx.def_path_str(def_id)));
+                    }
+                    diag.span_note(
+                        spans,
+                        format!("first possible panic found here, reached through {chain}"),
+                    );
+                }
+            },
         );
     }
     if !headers.errors {
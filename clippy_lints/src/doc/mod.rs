@@ -1,19 +1,22 @@
+use clippy_config::types::{DocItemKind, DocVisibility};
 use clippy_utils::attrs::is_doc_hidden;
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
 use clippy_utils::macros::{is_panic, root_macro_call_first_node};
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::visitors::Visitable;
-use clippy_utils::{is_entrypoint_fn, method_chain_args};
+use clippy_utils::{is_entrypoint_fn, is_lint_allowed, method_chain_args};
 use pulldown_cmark::Event::{
     Code, End, FootnoteReference, HardBreak, Html, Rule, SoftBreak, Start, TaskListMarker, Text,
 };
 use pulldown_cmark::Tag::{CodeBlock, Heading, Item, Link, Paragraph};
-use pulldown_cmark::{BrokenLink, CodeBlockKind, CowStr, Options};
+use pulldown_cmark::{BrokenLink, CodeBlockKind, CowStr, LinkType, Options};
 use rustc_ast::ast::Attribute;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{LocalDefId, CRATE_DEF_ID};
 use rustc_hir::intravisit::{self, Visitor};
-use rustc_hir::{AnonConst, Expr};
+use rustc_hir::{AnonConst, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::nested_filter;
 use rustc_middle::lint::in_external_macro;
@@ -27,8 +30,10 @@ use rustc_span::{sym, Span};
 use std::ops::Range;
 use url::Url;
 
+mod link_to_private_item;
 mod link_with_quotes;
 mod markdown;
+mod missing_examples;
 mod missing_headers;
 mod needless_doctest_main;
 mod suspicious_doc_comments;
@@ -144,6 +149,10 @@ declare_clippy_lint! {
     /// Checks the doc comments of publicly visible functions that
     /// may panic and warns if there is no `# Panics` section.
     ///
+    /// By default, only the body of the function is checked. The calls to the functions of the
+    /// current crate can be followed with the `missing-panics-doc-call-depth` configuration
+    /// option.
+    ///
     /// ### Why is this bad?
     /// Documenting the scenarios in which panicking occurs
     /// can help callers who do not want to panic to avoid those situations.
@@ -338,19 +347,115 @@ declare_clippy_lint! {
     "suspicious usage of (outer) doc comments"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for items whose docs have no `# Examples` section. The kinds of items that are
+    /// checked and their minimum visibility are set with the `missing-examples-doc-item-kinds`
+    /// and `missing-examples-doc-min-visibility` configuration options.
+    ///
+    /// ### Why is this bad?
+    /// Examples show how an item is meant to be used, and are tested as doctests.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// /// Returns the sum of `a` and `b`.
+    /// pub fn add(a: u32, b: u32) -> u32 {
+    ///     a + b
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// /// Returns the sum of `a` and `b`.
+    /// ///
+    /// /// # Examples
+    /// ///
+    /// /// ```
+    /// /// assert_eq!(add(1, 2), 3);
+    /// /// ```
+    /// pub fn add(a: u32, b: u32) -> u32 {
+    ///     a + b
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub MISSING_EXAMPLES_DOC,
+    restriction,
+    "items without an `# Examples` section in their docs"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for intra-doc links that point to items that are less visible than the documented
+    /// item, e.g. in the docs of an exported item to an item that is not exported, or in the docs
+    /// of a `pub(crate)` item to a private one.
+    ///
+    /// ### Why is this bad?
+    /// The documentation that includes the documented item doesn't include the linked item, so
+    /// the link is broken. Rustdoc only reports it when the documentation is built.
+    ///
+    /// ### Known problems
+    /// The links are resolved by Clippy, not by rustdoc. Only paths to items of the current crate
+    /// are resolved, relative to the module of the documented item, and a `use` item is only
+    /// followed if it's the last segment of the path. Links starting with `Self::`, links to items
+    /// brought into scope by a glob import and links through a re-exported module are never
+    /// checked.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// /// Parses the input with [`Parser`].
+    /// pub fn parse(input: &str) {}
+    ///
+    /// struct Parser;
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// /// Parses the input.
+    /// pub fn parse(input: &str) {}
+    ///
+    /// struct Parser;
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub DOC_LINK_TO_PRIVATE_ITEM,
+    pedantic,
+    "intra-doc links to items that are less visible than the documented item"
+}
+
 #[derive(Clone)]
 pub struct Documentation {
     valid_idents: FxHashSet<String>,
     in_trait_impl: bool,
     check_private_items: bool,
+    examples_item_kinds: Vec<DocItemKind>,
+    examples_min_visibility: DocVisibility,
+    panics_call_depth: u64,
 }
 
 impl Documentation {
-    pub fn new(valid_idents: &[String], check_private_items: bool) -> Self {
+    pub fn new(
+        valid_idents: &[String],
+        check_private_items: bool,
+        examples_item_kinds: &[DocItemKind],
+        examples_min_visibility: DocVisibility,
+        panics_call_depth: u64,
+    ) -> Self {
         Self {
             valid_idents: valid_idents.iter().cloned().collect(),
             in_trait_impl: false,
             check_private_items,
+            examples_item_kinds: examples_item_kinds.to_vec(),
+            examples_min_visibility,
+            panics_call_depth,
+        }
+    }
+
+    fn check_missing_examples(
+        &self,
+        cx: &LateContext<'_>,
+        owner_id: hir::OwnerId,
+        kind: DocItemKind,
+        headers: DocHeaders,
+    ) {
+        if self.examples_item_kinds.contains(&kind) {
+            missing_examples::check(cx, owner_id, kind, headers, self.examples_min_visibility);
         }
     }
 }
@@ -364,33 +469,50 @@ impl_lint_pass!(Documentation => [
     NEEDLESS_DOCTEST_MAIN,
     TEST_ATTR_IN_DOCTEST,
     UNNECESSARY_SAFETY_DOC,
-    SUSPICIOUS_DOC_COMMENTS
+    SUSPICIOUS_DOC_COMMENTS,
+    MISSING_EXAMPLES_DOC,
+    DOC_LINK_TO_PRIVATE_ITEM
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Documentation {
     fn check_crate(&mut self, cx: &LateContext<'tcx>) {
         let attrs = cx.tcx.hir().attrs(hir::CRATE_HIR_ID);
-        check_attrs(cx, &self.valid_idents, attrs);
+        check_attrs(cx, &self.valid_idents, CRATE_DEF_ID, attrs);
     }
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
         let attrs = cx.tcx.hir().attrs(item.hir_id());
-        let Some(headers) = check_attrs(cx, &self.valid_idents, attrs) else {
+        let Some(headers) = check_attrs(cx, &self.valid_idents, item.owner_id.def_id, attrs) else {
             return;
         };
+        if !in_external_macro(cx.tcx.sess, item.span) {
+            let kind = match item.kind {
+                hir::ItemKind::Fn(..) if !is_entrypoint_fn(cx, item.owner_id.to_def_id()) => Some(DocItemKind::Fn),
+                hir::ItemKind::Struct(..) => Some(DocItemKind::Struct),
+                hir::ItemKind::Enum(..) => Some(DocItemKind::Enum),
+                hir::ItemKind::Union(..) => Some(DocItemKind::Union),
+                hir::ItemKind::Trait(..) => Some(DocItemKind::Trait),
+                hir::ItemKind::TyAlias(..) => Some(DocItemKind::TypeAlias),
+                hir::ItemKind::Const(..) => Some(DocItemKind::Const),
+                hir::ItemKind::Static(..) => Some(DocItemKind::Static),
+                hir::ItemKind::Macro(..) => Some(DocItemKind::Macro),
+                hir::ItemKind::Mod(..) => Some(DocItemKind::Mod),
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                self.check_missing_examples(cx, item.owner_id, kind, headers);
+            }
+        }
         match item.kind {
             hir::ItemKind::Fn(ref sig, _, body_id) => {
                 if !(is_entrypoint_fn(cx, item.owner_id.to_def_id()) || in_external_macro(cx.tcx.sess, item.span)) {
-                    let body = cx.tcx.hir().body(body_id);
-
-                    let panic_span = FindPanicUnwrap::find_span(cx, cx.tcx.typeck(item.owner_id), body.value);
                     missing_headers::check(
                         cx,
                         item.owner_id,
                         sig,
                         headers,
                         Some(body_id),
-                        panic_span,
+                        self.panics_call_depth,
                         self.check_private_items,
                     );
                 }
@@ -425,37 +547,44 @@ impl<'tcx> LateLintPass<'tcx> for Documentation {
 
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'_>) {
         let attrs = cx.tcx.hir().attrs(item.hir_id());
-        let Some(headers) = check_attrs(cx, &self.valid_idents, attrs) else {
+        let Some(headers) = check_attrs(cx, &self.valid_idents, item.owner_id.def_id, attrs) else {
             return;
         };
         if let hir::TraitItemKind::Fn(ref sig, ..) = item.kind {
             if !in_external_macro(cx.tcx.sess, item.span) {
-                missing_headers::check(cx, item.owner_id, sig, headers, None, None, self.check_private_items);
+                missing_headers::check(
+                    cx,
+                    item.owner_id,
+                    sig,
+                    headers,
+                    None,
+                    self.panics_call_depth,
+                    self.check_private_items,
+                );
+                self.check_missing_examples(cx, item.owner_id, DocItemKind::Method, headers);
             }
         }
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'_>) {
         let attrs = cx.tcx.hir().attrs(item.hir_id());
-        let Some(headers) = check_attrs(cx, &self.valid_idents, attrs) else {
+        let Some(headers) = check_attrs(cx, &self.valid_idents, item.owner_id.def_id, attrs) else {
             return;
         };
         if self.in_trait_impl || in_external_macro(cx.tcx.sess, item.span) {
             return;
         }
         if let hir::ImplItemKind::Fn(ref sig, body_id) = item.kind {
-            let body = cx.tcx.hir().body(body_id);
-
-            let panic_span = FindPanicUnwrap::find_span(cx, cx.tcx.typeck(item.owner_id), body.value);
             missing_headers::check(
                 cx,
                 item.owner_id,
                 sig,
                 headers,
                 Some(body_id),
-                panic_span,
+                self.panics_call_depth,
                 self.check_private_items,
             );
+            self.check_missing_examples(cx, item.owner_id, DocItemKind::Method, headers);
         }
     }
 }
@@ -473,10 +602,12 @@ impl Fragments<'_> {
 }
 
 #[derive(Copy, Clone, Default)]
+#[expect(clippy::struct_excessive_bools)]
 struct DocHeaders {
     safety: bool,
     errors: bool,
     panics: bool,
+    examples: bool,
 }
 
 /// Does some pre-processing on raw, desugared `#[doc]` attributes such as parsing them and
@@ -486,13 +617,19 @@ struct DocHeaders {
 /// Others are checked elsewhere, e.g. in `check_doc` if they need access to markdown, or
 /// back in the various late lint pass methods if they need the final doc headers, like "Safety" or
 /// "Panics" sections.
-fn check_attrs(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs: &[Attribute]) -> Option<DocHeaders> {
+fn check_attrs(
+    cx: &LateContext<'_>,
+    valid_idents: &FxHashSet<String>,
+    owner_id: LocalDefId,
+    attrs: &[Attribute],
+) -> Option<DocHeaders> {
     /// We don't want the parser to choke on intra doc links. Since we don't
     /// actually care about rendering them, just pretend that all broken links
-    /// point to a fake address.
+    /// point to a fake address. The reference is kept as the title of the link
+    /// for `DOC_LINK_TO_PRIVATE_ITEM`.
     #[expect(clippy::unnecessary_wraps)] // we're following a type signature
-    fn fake_broken_link_callback<'a>(_: BrokenLink<'_>) -> Option<(CowStr<'a>, CowStr<'a>)> {
-        Some(("fake".into(), "fake".into()))
+    fn fake_broken_link_callback(link: BrokenLink<'_>) -> Option<(CowStr<'_>, CowStr<'_>)> {
+        Some(("fake".into(), link.reference))
     }
 
     if is_doc_hidden(attrs) {
@@ -521,6 +658,7 @@ fn check_attrs(cx: &LateContext<'_>, valid_idents: &FxHashSet<String>, attrs: &[
     Some(check_doc(
         cx,
         valid_idents,
+        owner_id,
         parser.into_offset_iter(),
         Fragments {
             fragments: &fragments,
@@ -539,6 +677,7 @@ const RUST_CODE: &[&str] = &["rust", "no_run", "should_panic", "compile_fail"];
 fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize>)>>(
     cx: &LateContext<'_>,
     valid_idents: &FxHashSet<String>,
+    owner_id: LocalDefId,
     events: Events,
     fragments: Fragments<'_>,
 ) -> DocHeaders {
@@ -554,6 +693,7 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
     let mut ticks_unbalanced = false;
     let mut text_to_check: Vec<(CowStr<'_>, Range<usize>)> = Vec::new();
     let mut paragraph_range = 0..0;
+    let check_links = !is_lint_allowed(cx, DOC_LINK_TO_PRIVATE_ITEM, cx.tcx.local_def_id_to_hir_id(owner_id));
     for (event, range) in events {
         match event {
             Start(CodeBlock(ref kind)) => {
@@ -582,7 +722,18 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                 is_rust = false;
                 ignore = false;
             },
-            Start(Link(_, url, _)) => in_link = Some(url),
+            Start(Link(link_type, url, title)) => {
+                // the reference of unknown links is their title, see `fake_broken_link_callback`
+                let target = match link_type {
+                    LinkType::ShortcutUnknown | LinkType::CollapsedUnknown | LinkType::ReferenceUnknown => Some(&title),
+                    LinkType::Inline | LinkType::Shortcut | LinkType::Collapsed | LinkType::Reference => Some(&url),
+                    LinkType::Autolink | LinkType::Email => None,
+                };
+                if check_links && let Some(target) = target {
+                    link_to_private_item::check(cx, owner_id, target, range, fragments);
+                }
+                in_link = Some(url);
+            },
             End(Link(..)) => in_link = None,
             Start(Heading(_, _, _) | Paragraph | Item) => {
                 if let Start(Heading(_, _, _)) = event {
@@ -631,6 +782,7 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
                 headers.safety |= in_heading && trimmed_text == "Implementation Safety";
                 headers.errors |= in_heading && trimmed_text == "Errors";
                 headers.panics |= in_heading && trimmed_text == "Panics";
+                headers.examples |= in_heading && (trimmed_text == "Examples" || trimmed_text == "Example");
                 if in_code {
                     if is_rust && !no_test {
                         let edition = edition.unwrap_or_else(|| cx.tcx.sess.edition());
@@ -655,25 +807,90 @@ fn check_doc<'a, Events: Iterator<Item = (pulldown_cmark::Event<'a>, Range<usize
     headers
 }
 
+/// A possible panic in a function, found in its body or in the body of a function it calls
+struct Panic {
+    span: Span,
+    /// The calls leading from the function to the one that panics, with the called functions
+    calls: Vec<(Span, LocalDefId)>,
+}
+
+/// Finds a possible panic in the function `owner_id`, following the calls to the functions of the
+/// current crate up to `call_depth` levels.
+fn find_panic(cx: &LateContext<'_>, owner_id: LocalDefId, call_depth: u64) -> Option<Panic> {
+    // the functions that are checked, with the index of their caller and the span of the call
+    let mut fns: Vec<(LocalDefId, Option<(usize, Span)>)> = vec![(owner_id, None)];
+    let mut level = 0..1;
+    for depth in 0..=call_depth {
+        for index in level.clone() {
+            let def_id = fns[index].0;
+            let body = cx.tcx.hir().body(cx.tcx.hir().body_owned_by(def_id));
+            let vis = FindPanicUnwrap::find(cx, cx.tcx.typeck(def_id), body.value, depth < call_depth);
+            if let Some(span) = vis.panic_span {
+                let mut calls = Vec::new();
+                let mut current = index;
+                while let (def_id, Some((caller, call_span))) = fns[current] {
+                    calls.push((call_span, def_id));
+                    current = caller;
+                }
+                calls.reverse();
+                return Some(Panic { span, calls });
+            }
+            for (def_id, span) in vis.calls {
+                if !fns.iter().any(|&(id, _)| id == def_id) {
+                    fns.push((def_id, Some((index, span))));
+                }
+            }
+        }
+        level = level.end..fns.len();
+    }
+    None
+}
+
 struct FindPanicUnwrap<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     panic_span: Option<Span>,
     typeck_results: &'tcx ty::TypeckResults<'tcx>,
+    /// Whether the calls to the functions of the current crate are collected in `calls`
+    collect_calls: bool,
+    calls: Vec<(LocalDefId, Span)>,
 }
 
 impl<'a, 'tcx> FindPanicUnwrap<'a, 'tcx> {
-    pub fn find_span(
+    pub fn find(
         cx: &'a LateContext<'tcx>,
         typeck_results: &'tcx ty::TypeckResults<'tcx>,
         body: impl Visitable<'tcx>,
-    ) -> Option<Span> {
+        collect_calls: bool,
+    ) -> Self {
         let mut vis = Self {
             cx,
             panic_span: None,
             typeck_results,
+            collect_calls,
+            calls: Vec::new(),
         };
         body.visit(&mut vis);
-        vis.panic_span
+        vis
+    }
+
+    /// Returns the function of the current crate called by `expr`, if it has a body. Trait
+    /// methods are not followed since the called impl is unknown.
+    fn local_callee(&self, expr: &Expr<'_>) -> Option<LocalDefId> {
+        let def_id = match expr.kind {
+            ExprKind::Call(callee, _) => match callee.kind {
+                ExprKind::Path(ref qpath) => self.typeck_results.qpath_res(qpath, callee.hir_id).opt_def_id(),
+                _ => None,
+            },
+            ExprKind::MethodCall(..) => self.typeck_results.type_dependent_def_id(expr.hir_id),
+            _ => None,
+        }?;
+        if !matches!(self.cx.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+            || self.cx.tcx.trait_of_item(def_id).is_some()
+        {
+            return None;
+        }
+        let def_id = def_id.as_local()?;
+        self.cx.tcx.hir().maybe_body_owned_by(def_id).map(|_| def_id)
     }
 }
 
@@ -706,6 +923,12 @@ impl<'a, 'tcx> Visitor<'tcx> for FindPanicUnwrap<'a, 'tcx> {
             }
         }
 
+        if self.collect_calls
+            && let Some(def_id) = self.local_callee(expr)
+        {
+            self.calls.push((def_id, expr.span));
+        }
+
         // and check sub-expressions
         intravisit::walk_expr(self, expr);
     }
@@ -1,3 +1,4 @@
+use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint_and_help;
 use clippy_utils::source::snippet;
 use rustc_ast::node_id::NodeSet;
@@ -65,7 +66,8 @@ impl_lint_pass!(ExcessiveNesting => [EXCESSIVE_NESTING]);
 
 #[derive(Clone)]
 pub struct ExcessiveNesting {
-    pub excessive_nesting_threshold: u64,
+    /// Used for `excessive_nesting_threshold`, which can be overridden per path
+    pub conf: &'static Conf,
     pub nodes: NodeSet,
 }
 
@@ -86,7 +88,8 @@ impl ExcessiveNesting {
 
 impl EarlyLintPass for ExcessiveNesting {
     fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &Crate) {
-        if self.excessive_nesting_threshold == 0 {
+        // the lint is disabled unless a threshold is set, either globally or for some paths
+        if self.conf.excessive_nesting_threshold == 0 && self.conf.overrides.is_empty() {
             return;
         }
 
@@ -118,7 +121,12 @@ struct NestingVisitor<'conf, 'cx> {
 
 impl NestingVisitor<'_, '_> {
     fn check_indent(&mut self, span: Span, id: NodeId) -> bool {
-        if self.nest_level > self.conf.excessive_nesting_threshold && !in_external_macro(self.cx.sess(), span) {
+        let threshold = self
+            .conf
+            .conf
+            .for_span(self.cx.sess(), span)
+            .excessive_nesting_threshold;
+        if threshold != 0 && self.nest_level > threshold && !in_external_macro(self.cx.sess(), span) {
             self.conf.nodes.insert(id);
 
             return true;
@@ -213,7 +213,10 @@ impl<'tcx> LateLintPass<'tcx> for FormatArgs {
                 }
             }
 
-            if self.msrv.meets(msrvs::FORMAT_ARGS_CAPTURE) {
+            if self
+                .msrv
+                .meets_or_report(UNINLINED_FORMAT_ARGS, macro_call.span, msrvs::FORMAT_ARGS_CAPTURE)
+            {
                 check_uninlined_args(cx, &format_args, macro_call.span, macro_call.def_id, self.ignore_mixed);
             }
         }
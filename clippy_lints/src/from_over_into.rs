@@ -64,7 +64,11 @@ impl_lint_pass!(FromOverInto => [FROM_OVER_INTO]);
 
 impl<'tcx> LateLintPass<'tcx> for FromOverInto {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
-        if !self.msrv.meets(msrvs::RE_REBALANCING_COHERENCE) || !span_is_local(item.span) {
+        if !self
+            .msrv
+            .meets_or_report(FROM_OVER_INTO, item.span, msrvs::RE_REBALANCING_COHERENCE)
+            || !span_is_local(item.span)
+        {
             return;
         }
 
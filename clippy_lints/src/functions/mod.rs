@@ -6,9 +6,10 @@ mod result;
 mod too_many_arguments;
 mod too_many_lines;
 
+use clippy_config::Conf;
 use rustc_hir as hir;
 use rustc_hir::intravisit;
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_session::impl_lint_pass;
 use rustc_span::def_id::LocalDefId;
 use rustc_span::Span;
@@ -360,24 +361,18 @@ declare_clippy_lint! {
 }
 
 #[derive(Copy, Clone)]
-#[allow(clippy::struct_field_names)]
 pub struct Functions {
-    too_many_arguments_threshold: u64,
-    too_many_lines_threshold: u64,
+    /// Used for the thresholds of `too_many_arguments` and `too_many_lines`, which can be
+    /// overridden per path
+    conf: &'static Conf,
     large_error_threshold: u64,
     avoid_breaking_exported_api: bool,
 }
 
 impl Functions {
-    pub fn new(
-        too_many_arguments_threshold: u64,
-        too_many_lines_threshold: u64,
-        large_error_threshold: u64,
-        avoid_breaking_exported_api: bool,
-    ) -> Self {
+    pub fn new(conf: &'static Conf, large_error_threshold: u64, avoid_breaking_exported_api: bool) -> Self {
         Self {
-            too_many_arguments_threshold,
-            too_many_lines_threshold,
+            conf,
             large_error_threshold,
             avoid_breaking_exported_api,
         }
@@ -408,8 +403,9 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
         def_id: LocalDefId,
     ) {
         let hir_id = cx.tcx.local_def_id_to_hir_id(def_id);
-        too_many_arguments::check_fn(cx, kind, decl, span, hir_id, self.too_many_arguments_threshold);
-        too_many_lines::check_fn(cx, kind, span, body, self.too_many_lines_threshold);
+        let conf = self.conf.for_span(cx.sess(), span);
+        too_many_arguments::check_fn(cx, kind, decl, span, hir_id, conf.too_many_arguments_threshold);
+        too_many_lines::check_fn(cx, kind, span, body, conf.too_many_lines_threshold);
         not_unsafe_ptr_arg_deref::check_fn(cx, kind, decl, body, def_id);
         misnamed_getters::check_fn(cx, kind, decl, body, span);
         impl_trait_in_params::check_fn(cx, &kind, body, hir_id);
@@ -427,7 +423,8 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'_>) {
-        too_many_arguments::check_trait_item(cx, item, self.too_many_arguments_threshold);
+        let conf = self.conf.for_span(cx.sess(), item.span);
+        too_many_arguments::check_trait_item(cx, item, conf.too_many_arguments_threshold);
         not_unsafe_ptr_arg_deref::check_trait_item(cx, item);
         must_use::check_trait_item(cx, item);
         result::check_trait_item(cx, item, self.large_error_threshold);
@@ -61,7 +61,10 @@ impl_lint_pass!(IfThenSomeElseNone => [IF_THEN_SOME_ELSE_NONE]);
 
 impl<'tcx> LateLintPass<'tcx> for IfThenSomeElseNone {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
-        if !self.msrv.meets(msrvs::BOOL_THEN) {
+        if !self
+            .msrv
+            .meets_or_report(IF_THEN_SOME_ELSE_NONE, expr.span, msrvs::BOOL_THEN)
+        {
             return;
         }
 
@@ -0,0 +1,145 @@
+use clippy_config::msrvs::Msrv;
+use clippy_utils::diagnostics::span_lint;
+use rustc_attr::{StabilityLevel, StableSince};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, QPath, Ty, TyKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::TyCtxt;
+use rustc_semver::RustcVersion;
+use rustc_session::impl_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for uses of items of the standard library that were stabilized after the
+    /// configured MSRV (minimum supported Rust version), as written in their `#[stable]`
+    /// attribute.
+    ///
+    /// The MSRV is read from the `msrv` configuration, the `rust-version` field of `Cargo.toml`
+    /// or a `#[clippy::msrv]` attribute.
+    ///
+    /// ### Why is this bad?
+    /// The crate doesn't compile with the Rust versions it claims to support.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// // MSRV of 1.50, `Option::is_some_and` is stable since 1.70.0
+    /// let _ = Some(1).is_some_and(|x| x > 0);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let _ = Some(1).map_or(false, |x| x > 0);
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub INCOMPATIBLE_MSRV,
+    suspicious,
+    "use of an item of the standard library that is newer than the MSRV"
+}
+
+pub struct IncompatibleMsrv {
+    msrv: Msrv,
+    /// The version in which an item was stabilized, `None` if it isn't a stable item of a crate
+    /// with stability attributes
+    versions: FxHashMap<DefId, Option<RustcVersion>>,
+    /// The span of the last diagnostic, so that the type in a path like `NonZeroU8::new` isn't
+    /// linted again
+    last_span: Option<Span>,
+}
+
+impl IncompatibleMsrv {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self {
+            msrv,
+            versions: FxHashMap::default(),
+            last_span: None,
+        }
+    }
+
+    /// The version in which `def_id` was stabilized. Items without a `#[stable]` attribute, like
+    /// the items of an `impl` block, take the version of their parent.
+    fn stable_since(&mut self, tcx: TyCtxt<'_>, def_id: DefId) -> Option<RustcVersion> {
+        if let Some(&version) = self.versions.get(&def_id) {
+            return version;
+        }
+
+        let version = match tcx.lookup_stability(def_id).map(|stability| stability.level) {
+            Some(StabilityLevel::Stable { since, .. }) => match since {
+                StableSince::Version(version) => Some(RustcVersion::new(
+                    version.major.into(),
+                    version.minor.into(),
+                    version.patch.into(),
+                )),
+                StableSince::Current | StableSince::Err => None,
+            },
+            Some(StabilityLevel::Unstable { .. }) => None,
+            None => tcx
+                .opt_parent(def_id)
+                .filter(|parent| !parent.is_crate_root())
+                .and_then(|parent| self.stable_since(tcx, parent)),
+        };
+        self.versions.insert(def_id, version);
+        version
+    }
+
+    fn check(&mut self, cx: &LateContext<'_>, def_id: DefId, span: Span) {
+        if def_id.is_local()
+            || in_external_macro(cx.sess(), span)
+            || self.last_span.is_some_and(|last_span| last_span.contains(span))
+        {
+            return;
+        }
+        let Some(msrv) = self.msrv.current() else {
+            return;
+        };
+
+        // compared directly rather than with `Msrv::meets`, this lint is not suppressed by the MSRV
+        // but enabled by it
+        if let Some(version) = self.stable_since(cx.tcx, def_id)
+            && !msrv.meets(version)
+        {
+            self.last_span = Some(span);
+            span_lint(
+                cx,
+                INCOMPATIBLE_MSRV,
+                span,
+                &format!("current MSRV is `{msrv}` but this item is stable since `{version}`"),
+            );
+        }
+    }
+}
+
+impl_lint_pass!(IncompatibleMsrv => [INCOMPATIBLE_MSRV]);
+
+impl<'tcx> LateLintPass<'tcx> for IncompatibleMsrv {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::MethodCall(_, _, _, span) => {
+                if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) {
+                    self.check(cx, def_id, span);
+                }
+            },
+            // lang item paths are the desugaring of syntax like `a..=b`
+            ExprKind::Path(ref qpath) if !matches!(qpath, QPath::LangItem(..)) => {
+                if let Some(def_id) = cx.qpath_res(qpath, expr.hir_id).opt_def_id() {
+                    self.check(cx, def_id, expr.span);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'tcx>, ty: &'tcx Ty<'tcx>) {
+        if let TyKind::Path(ref qpath) = ty.kind
+            && let Res::Def(DefKind::Struct | DefKind::Enum | DefKind::Union | DefKind::TyAlias, def_id) =
+                cx.qpath_res(qpath, ty.hir_id)
+        {
+            self.check(cx, def_id, ty.span);
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
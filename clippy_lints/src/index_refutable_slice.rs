@@ -72,7 +72,9 @@ impl<'tcx> LateLintPass<'tcx> for IndexRefutableSlice {
         if (!expr.span.from_expansion() || is_expn_of(expr.span, "if_chain").is_some())
             && let Some(IfLet { let_pat, if_then, .. }) = IfLet::hir(cx, expr)
             && !is_lint_allowed(cx, INDEX_REFUTABLE_SLICE, expr.hir_id)
-            && self.msrv.meets(msrvs::SLICE_PATTERNS)
+            && self
+                .msrv
+                .meets_or_report(INDEX_REFUTABLE_SLICE, expr.span, msrvs::SLICE_PATTERNS)
             && let found_slices = find_slice_values(cx, let_pat)
             && !found_slices.is_empty()
             && let filtered_slices = filter_lintable_slices(cx, found_slices, self.max_suggested_slice, if_then)
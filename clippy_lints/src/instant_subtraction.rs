@@ -95,7 +95,9 @@ impl LateLintPass<'_> for InstantSubtraction {
             {
                 print_manual_instant_elapsed_sugg(cx, expr, sugg);
             } else if !expr.span.from_expansion()
-                && self.msrv.meets(msrvs::TRY_FROM)
+                && self
+                    .msrv
+                    .meets_or_report(UNCHECKED_DURATION_SUBTRACTION, expr.span, msrvs::TRY_FROM)
                 && is_an_instant(cx, lhs)
                 && is_a_duration(cx, rhs)
             {
@@ -25,6 +25,7 @@ extern crate pulldown_cmark;
 extern crate rustc_arena;
 extern crate rustc_ast;
 extern crate rustc_ast_pretty;
+extern crate rustc_attr;
 extern crate rustc_data_structures;
 extern crate rustc_driver;
 extern crate rustc_errors;
@@ -52,13 +53,17 @@ extern crate declare_clippy_lint;
 
 use rustc_data_structures::fx::FxHashSet;
 use rustc_lint::{Lint, LintId};
+use rustc_session::Session;
+use serde::Serialize;
 
 #[cfg(feature = "internal")]
 pub mod deprecated_lints;
 #[cfg_attr(feature = "internal", allow(clippy::missing_clippy_version_attribute))]
 mod utils;
 
+mod collected_metadata;
 mod declared_lints;
+pub mod pass_timings;
 mod renamed_lints;
 
 // begin lints modules, do not remove this comment, it’s used in `update_lints`
@@ -71,6 +76,7 @@ mod as_conversions;
 mod asm_syntax;
 mod assertions_on_constants;
 mod assertions_on_result_states;
+mod async_blocking_call;
 mod async_yields_async;
 mod attrs;
 mod await_holding_invalid;
@@ -150,6 +156,7 @@ mod implicit_return;
 mod implicit_saturating_add;
 mod implicit_saturating_sub;
 mod implied_bounds_in_impls;
+mod incompatible_msrv;
 mod inconsistent_struct_constructor;
 mod index_refutable_slice;
 mod indexing_slicing;
@@ -427,6 +434,22 @@ pub(crate) enum LintCategory {
 use LintCategory::*;
 
 impl LintCategory {
+    fn name(self) -> &'static str {
+        match self {
+            Cargo => "cargo",
+            Complexity => "complexity",
+            Correctness => "correctness",
+            Nursery => "nursery",
+            Pedantic => "pedantic",
+            Perf => "perf",
+            Restriction => "restriction",
+            Style => "style",
+            Suspicious => "suspicious",
+            #[cfg(feature = "internal")]
+            Internal => "internal",
+        }
+    }
+
     fn is_all(self) -> bool {
         matches!(self, Correctness | Suspicious | Style | Complexity | Perf)
     }
@@ -453,6 +476,65 @@ pub(crate) struct LintInfo {
     lint: &'static &'static Lint,
     category: LintCategory,
     explanation: &'static str,
+    /// The `#[clippy::version]` of the lint, the version it was added in
+    version: Option<&'static str>,
+}
+
+/// The metadata of a lint printed by `cargo clippy --list-lints` and `cargo clippy --explain`
+#[derive(Serialize)]
+pub struct LintMetadata {
+    /// The name of the lint without the `clippy::` prefix
+    pub name: String,
+    /// The short description of the lint
+    pub description: &'static str,
+    pub group: &'static str,
+    pub default_level: &'static str,
+    /// The Clippy version the lint was added in
+    pub version: Option<&'static str>,
+    /// Whether the lint is disabled if the `msrv` configuration is lower than the version its
+    /// suggestion requires, i.e. it's checked with `Msrv::meets_or_report`
+    pub msrv_gated: bool,
+    /// The applicability of the lint's suggestions, `Unresolved` if it has none or it couldn't be
+    /// resolved by `cargo collect-metadata`
+    pub applicability: &'static str,
+    /// The keys of the configuration values that affect the lint, as written in `clippy.toml`
+    pub config_keys: Vec<String>,
+    pub docs: &'static str,
+}
+
+/// Returns the metadata of all lints
+pub fn lint_metadata() -> Vec<LintMetadata> {
+    let configs = get_configuration_metadata();
+    declared_lints::LINTS
+        .iter()
+        .map(|info| {
+            let name = info.lint.name_lower().trim_start_matches("clippy::").to_string();
+            let config_keys: Vec<String> = configs
+                .iter()
+                .filter(|conf| conf.deprecation_reason.is_none() && conf.lints.contains(&name))
+                .map(|conf| conf.name.replace('_', "-"))
+                .collect();
+
+            let (applicability, msrv_gated) = collected_metadata::COLLECTED_METADATA
+                .binary_search_by_key(&name.as_str(), |&(name, ..)| name)
+                .map_or(("Unresolved", false), |index| {
+                    let (_, applicability, msrv_gated) = collected_metadata::COLLECTED_METADATA[index];
+                    (applicability, msrv_gated)
+                });
+
+            LintMetadata {
+                msrv_gated,
+                applicability,
+                description: info.lint.desc,
+                group: info.category.name(),
+                default_level: info.lint.default_level.as_str(),
+                version: info.version,
+                config_keys,
+                docs: info.explanation,
+                name,
+            }
+        })
+        .collect()
 }
 
 pub fn explain(name: &str) -> i32 {
@@ -504,12 +586,10 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         accept_comment_above_attributes,
         accept_comment_above_statement,
         allow_dbg_in_tests,
-        allow_expect_in_tests,
         allow_mixed_uninlined_format_args,
         allow_one_hash_in_raw_strings,
         allow_print_in_tests,
         allow_private_module_inception,
-        allow_unwrap_in_tests,
         ref allowed_dotfiles,
         ref allowed_idents_below_min_chars,
         ref allowed_scripts,
@@ -519,8 +599,9 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         array_size_threshold,
         avoid_breaking_exported_api,
         ref await_holding_invalid_types,
+        ref blocking_offload_paths,
+        ref blocking_paths,
         cargo_ignore_publish,
-        cognitive_complexity_threshold,
         ref disallowed_macros,
         ref disallowed_methods,
         ref disallowed_names,
@@ -531,10 +612,11 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         ref enforced_import_renames,
         enum_variant_name_threshold,
         enum_variant_size_threshold,
-        excessive_nesting_threshold,
+        error_on_unresolved_disallowed_paths,
         future_size_threshold,
         ref ignore_interior_mutability,
         large_error_threshold,
+        large_regex_threshold,
         literal_representation_threshold,
         matches_for_let_else,
         max_fn_params_bools,
@@ -544,6 +626,9 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         max_trait_bounds,
         min_ident_chars_threshold,
         missing_docs_in_crate_items,
+        ref missing_examples_doc_item_kinds,
+        missing_examples_doc_min_visibility,
+        missing_panics_doc_call_depth,
         ref msrv,
         pass_by_value_size_limit,
         semicolon_inside_block_ignore_singleline,
@@ -554,8 +639,6 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         struct_field_name_threshold,
         suppress_restriction_lint_in_const,
         too_large_for_stack,
-        too_many_arguments_threshold,
-        too_many_lines_threshold,
         trivial_copy_size_limit,
         type_complexity_threshold,
         unnecessary_box_size,
@@ -568,6 +651,16 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
 
         blacklisted_names: _,
         cyclomatic_complexity_threshold: _,
+        // these are read with `Conf::for_span` by the passes so that `[[overrides]]` apply
+        allow_expect_in_tests: _,
+        allow_unwrap_in_tests: _,
+        cognitive_complexity_threshold: _,
+        excessive_nesting_threshold: _,
+        too_many_arguments_threshold: _,
+        too_many_lines_threshold: _,
+        lints: _,
+        overrides: _,
+        override_indices: _,
     } = *conf;
     let msrv = || msrv.clone();
 
@@ -630,6 +723,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| {
         Box::new(await_holding_invalid::AwaitHolding::new(
             await_holding_invalid_types.clone(),
+            error_on_unresolved_disallowed_paths,
         ))
     });
     store.register_late_pass(|_| Box::new(serde_api::SerdeApi));
@@ -670,8 +764,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         Box::new(methods::Methods::new(
             avoid_breaking_exported_api,
             msrv(),
-            allow_expect_in_tests,
-            allow_unwrap_in_tests,
+            conf,
             allowed_dotfiles.clone(),
         ))
     });
@@ -713,11 +806,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(no_effect::NoEffect));
     store.register_late_pass(|_| Box::new(temporary_assignment::TemporaryAssignment));
     store.register_late_pass(move |_| Box::new(transmute::Transmute::new(msrv())));
-    store.register_late_pass(move |_| {
-        Box::new(cognitive_complexity::CognitiveComplexity::new(
-            cognitive_complexity_threshold,
-        ))
-    });
+    store.register_late_pass(move |_| Box::new(cognitive_complexity::CognitiveComplexity::new(conf)));
     store.register_late_pass(move |_| Box::new(escape::BoxedLocal { too_large_for_stack }));
     store.register_late_pass(move |_| {
         Box::new(vec::UselessVec {
@@ -732,7 +821,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(drop_forget_ref::DropForgetRef));
     store.register_late_pass(|_| Box::new(empty_enum::EmptyEnum));
     store.register_late_pass(|_| Box::new(invalid_upcast_comparisons::InvalidUpcastComparisons));
-    store.register_late_pass(|_| Box::<regex::Regex>::default());
+    store.register_late_pass(move |_| Box::new(regex::Regex::new(msrv(), large_regex_threshold)));
     store.register_late_pass(move |_| Box::new(copies::CopyAndPaste::new(ignore_interior_mutability.clone())));
     store.register_late_pass(|_| Box::new(copy_iterator::CopyIterator));
     store.register_late_pass(|_| Box::new(format::UselessFormat));
@@ -742,13 +831,20 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| Box::new(disallowed_names::DisallowedNames::new(disallowed_names)));
     store.register_late_pass(move |_| {
         Box::new(functions::Functions::new(
-            too_many_arguments_threshold,
-            too_many_lines_threshold,
+            conf,
             large_error_threshold,
             avoid_breaking_exported_api,
         ))
     });
-    store.register_late_pass(move |_| Box::new(doc::Documentation::new(doc_valid_idents, check_private_items)));
+    store.register_late_pass(move |_| {
+        Box::new(doc::Documentation::new(
+            doc_valid_idents,
+            check_private_items,
+            missing_examples_doc_item_kinds,
+            missing_examples_doc_min_visibility,
+            missing_panics_doc_call_depth,
+        ))
+    });
     store.register_late_pass(|_| Box::new(neg_multiply::NegMultiply));
     store.register_late_pass(|_| Box::new(let_if_seq::LetIfSeq));
     store.register_late_pass(|_| Box::new(mixed_read_write_in_expression::EvalOrderDependence));
@@ -884,8 +980,18 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(unwrap_in_result::UnwrapInResult));
     store.register_late_pass(|_| Box::new(semicolon_if_nothing_returned::SemicolonIfNothingReturned));
     store.register_late_pass(|_| Box::new(async_yields_async::AsyncYieldsAsync));
-    store.register_late_pass(move |_| Box::new(disallowed_macros::DisallowedMacros::new(disallowed_macros.clone())));
-    store.register_late_pass(move |_| Box::new(disallowed_methods::DisallowedMethods::new(disallowed_methods.clone())));
+    store.register_late_pass(move |_| {
+        Box::new(disallowed_macros::DisallowedMacros::new(
+            disallowed_macros.clone(),
+            error_on_unresolved_disallowed_paths,
+        ))
+    });
+    store.register_late_pass(move |_| {
+        Box::new(disallowed_methods::DisallowedMethods::new(
+            disallowed_methods.clone(),
+            error_on_unresolved_disallowed_paths,
+        ))
+    });
     store.register_early_pass(|| Box::new(asm_syntax::InlineAsmX86AttSyntax));
     store.register_early_pass(|| Box::new(asm_syntax::InlineAsmX86IntelSyntax));
     store.register_late_pass(|_| Box::new(empty_drop::EmptyDrop));
@@ -899,7 +1005,12 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(bool_assert_comparison::BoolAssertComparison));
     store.register_early_pass(move || Box::new(module_style::ModStyle));
     store.register_late_pass(|_| Box::<unused_async::UnusedAsync>::default());
-    store.register_late_pass(move |_| Box::new(disallowed_types::DisallowedTypes::new(disallowed_types.clone())));
+    store.register_late_pass(move |_| {
+        Box::new(disallowed_types::DisallowedTypes::new(
+            disallowed_types.clone(),
+            error_on_unresolved_disallowed_paths,
+        ))
+    });
     store.register_late_pass(move |_| {
         Box::new(missing_enforced_import_rename::ImportRename::new(
             enforced_import_renames.clone(),
@@ -1005,7 +1116,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(manual_slice_size_calculation::ManualSliceSizeCalculation));
     store.register_early_pass(move || {
         Box::new(excessive_nesting::ExcessiveNesting {
-            excessive_nesting_threshold,
+            conf,
             nodes: rustc_ast::node_id::NodeSet::new(),
         })
     });
@@ -1071,7 +1182,22 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(iter_over_hash_type::IterOverHashType));
     store.register_late_pass(|_| Box::new(impl_hash_with_borrow_str_and_bytes::ImplHashWithBorrowStrBytes));
     store.register_late_pass(|_| Box::new(repeat_vec_with_capacity::RepeatVecWithCapacity));
+    store.register_late_pass(move |_| Box::new(incompatible_msrv::IncompatibleMsrv::new(msrv())));
+    store.register_late_pass(move |_| {
+        Box::new(async_blocking_call::AsyncBlockingCall::new(
+            blocking_paths.clone(),
+            blocking_offload_paths.clone(),
+            error_on_unresolved_disallowed_paths,
+        ))
+    });
     // add lints here, do not remove this comment, it's used in `new_lint`
+
+    // registered last so that the other passes have linted a body or item before its failed MSRV
+    // checks are cleared
+    if clippy_config::msrvs::is_report_mode() {
+        store.register_early_pass(|| Box::new(utils::msrv_report::ClearUnmetMsrvChecks));
+        store.register_late_pass(|_| Box::new(utils::msrv_report::ClearUnmetMsrvChecks));
+    }
 }
 
 #[rustfmt::skip]
@@ -1127,6 +1253,56 @@ pub fn register_renamed(ls: &mut rustc_lint::LintStore) {
     }
 }
 
+/// Returns the new name of a renamed lint, e.g. `clippy::box_vec` becomes `clippy::box_collection`
+pub fn renamed_lint(name: &str) -> Option<&'static str> {
+    renamed_lints::RENAMED_LINTS
+        .iter()
+        .find(|(old_name, _)| *old_name == name)
+        .map(|(_, new_name)| *new_name)
+}
+
+/// Checks if `name` is the full name of a Clippy lint group, e.g. `clippy::pedantic`
+pub fn is_lint_group(name: &str) -> bool {
+    let Some(short_name) = name.strip_prefix("clippy::") else {
+        return false;
+    };
+
+    short_name == "all"
+        || declared_lints::LINTS
+            .iter()
+            .any(|info| info.category.name() == short_name)
+}
+
+/// Checks if `name` is the full name of a Clippy lint or lint group, e.g. `clippy::pedantic`
+pub fn is_lint_or_group(name: &str) -> bool {
+    is_lint_group(name) || declared_lints::LINTS.iter().any(|info| info.lint.name_lower() == name)
+}
+
+/// Reports the unknown, removed and renamed lints and the lints of rustc set in the `[lints]` table
+/// of `clippy.toml`
+///
+/// Used in `./src/driver.rs`.
+pub fn check_configured_lints(sess: &Session, store: &rustc_lint::LintStore, conf: &Conf) {
+    for lint in &conf.lints {
+        if let Some(new_name) = renamed_lint(&lint.name) {
+            sess.span_warn(
+                lint.span,
+                format!("lint `{}` has been renamed to `{new_name}`", lint.name),
+            );
+        } else if store.find_lints(&lint.name).is_err() {
+            let short_name = lint.name.trim_start_matches("clippy::");
+            if store.find_lints(short_name).is_ok() {
+                sess.span_err(
+                    lint.span,
+                    format!("`{short_name}` is not a Clippy lint, only Clippy lints can be set in `[lints]`"),
+                );
+            } else {
+                sess.span_err(lint.span, format!("unknown or removed lint `{}`", lint.name));
+            }
+        }
+    }
+}
+
 // only exists to let the dogfood integration test works.
 // Don't run clippy as an executable directly
 #[allow(dead_code)]
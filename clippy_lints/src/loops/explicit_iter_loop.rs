@@ -25,13 +25,13 @@ pub(super) fn check(
     };
     if let ty::Array(_, count) = *ty.peel_refs().kind() {
         if !ty.is_ref() {
-            if !msrv.meets(msrvs::ARRAY_INTO_ITERATOR) {
+            if !msrv.meets_or_report(EXPLICIT_ITER_LOOP, call_expr.span, msrvs::ARRAY_INTO_ITERATOR) {
                 return;
             }
         } else if count
             .try_eval_target_usize(cx.tcx, cx.param_env)
             .map_or(true, |x| x > 32)
-            && !msrv.meets(msrvs::ARRAY_IMPL_ANY_LEN)
+            && !msrv.meets_or_report(EXPLICIT_ITER_LOOP, call_expr.span, msrvs::ARRAY_IMPL_ANY_LEN)
         {
             return;
         }
@@ -49,7 +49,7 @@ impl_lint_pass!(ManualBits => [MANUAL_BITS]);
 
 impl<'tcx> LateLintPass<'tcx> for ManualBits {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if !self.msrv.meets(msrvs::MANUAL_BITS) {
+        if !self.msrv.meets_or_report(MANUAL_BITS, expr.span, msrvs::MANUAL_BITS) {
             return;
         }
 
@@ -89,21 +89,13 @@ enum CharRange {
 
 impl<'tcx> LateLintPass<'tcx> for ManualIsAsciiCheck {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if !self.msrv.meets(msrvs::IS_ASCII_DIGIT) {
-            return;
-        }
-
-        if in_constant(cx, expr.hir_id) && !self.msrv.meets(msrvs::IS_ASCII_DIGIT_CONST) {
-            return;
-        }
-
-        if let Some(macro_call) = root_macro_call(expr.span)
+        let (span, recv, range) = if let Some(macro_call) = root_macro_call(expr.span)
             && is_matches_macro(cx, macro_call.def_id)
         {
-            if let ExprKind::Match(recv, [arm, ..], _) = expr.kind {
-                let range = check_pat(&arm.pat.kind);
-                check_is_ascii(cx, macro_call.span, recv, &range);
-            }
+            let ExprKind::Match(recv, [arm, ..], _) = expr.kind else {
+                return;
+            };
+            (macro_call.span, recv, check_pat(&arm.pat.kind))
         } else if let ExprKind::MethodCall(path, receiver, [arg], ..) = expr.kind
             && path.ident.name == sym!(contains)
             && let Some(higher::Range {
@@ -112,12 +104,28 @@ impl<'tcx> LateLintPass<'tcx> for ManualIsAsciiCheck {
                 limits: RangeLimits::Closed,
             }) = higher::Range::hir(receiver)
         {
-            let range = check_range(start, end);
-            if let ExprKind::AddrOf(BorrowKind::Ref, _, e) = arg.kind {
-                check_is_ascii(cx, expr.span, e, &range);
+            let recv = if let ExprKind::AddrOf(BorrowKind::Ref, _, e) = arg.kind {
+                e
             } else {
-                check_is_ascii(cx, expr.span, arg, &range);
-            }
+                arg
+            };
+            (expr.span, recv, check_range(start, end))
+        } else {
+            return;
+        };
+
+        if !matches!(
+            range,
+            CharRange::Otherwise | CharRange::LowerHexLetter | CharRange::UpperHexLetter
+        ) && self
+            .msrv
+            .meets_or_report(MANUAL_IS_ASCII_CHECK, span, msrvs::IS_ASCII_DIGIT)
+            && (!in_constant(cx, expr.hir_id)
+                || self
+                    .msrv
+                    .meets_or_report(MANUAL_IS_ASCII_CHECK, span, msrvs::IS_ASCII_DIGIT_CONST))
+        {
+            check_is_ascii(cx, span, recv, &range);
         }
     }
 
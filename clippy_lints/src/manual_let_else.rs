@@ -49,7 +49,9 @@ declare_clippy_lint! {
 
 impl<'tcx> QuestionMark {
     pub(crate) fn check_manual_let_else(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) {
-        if !self.msrv.meets(msrvs::LET_ELSE) || in_external_macro(cx.sess(), stmt.span) {
+        if !self.msrv.meets_or_report(MANUAL_LET_ELSE, stmt.span, msrvs::LET_ELSE)
+            || in_external_macro(cx.sess(), stmt.span)
+        {
             return;
         }
 
@@ -47,7 +47,9 @@ impl_lint_pass!(ManualMainSeparatorStr => [MANUAL_MAIN_SEPARATOR_STR]);
 
 impl LateLintPass<'_> for ManualMainSeparatorStr {
     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
-        if self.msrv.meets(msrvs::PATH_MAIN_SEPARATOR_STR)
+        if self
+            .msrv
+            .meets_or_report(MANUAL_MAIN_SEPARATOR_STR, expr.span, msrvs::PATH_MAIN_SEPARATOR_STR)
             && let (target, _) = peel_hir_expr_refs(expr)
             && is_trait_method(cx, target, sym::ToString)
             && let ExprKind::MethodCall(path, receiver, &[], _) = target.kind
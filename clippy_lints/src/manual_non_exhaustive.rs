@@ -79,7 +79,8 @@ impl_lint_pass!(ManualNonExhaustiveStruct => [MANUAL_NON_EXHAUSTIVE]);
 pub struct ManualNonExhaustiveEnum {
     msrv: Msrv,
     constructed_enum_variants: FxHashSet<(DefId, DefId)>,
-    potential_enums: Vec<(LocalDefId, LocalDefId, Span, Span)>,
+    /// The enums that may be linted if their variant isn't constructed, with the MSRV at the enum
+    potential_enums: Vec<(LocalDefId, LocalDefId, Span, Span, Msrv)>,
 }
 
 impl ManualNonExhaustiveEnum {
@@ -97,7 +98,10 @@ impl_lint_pass!(ManualNonExhaustiveEnum => [MANUAL_NON_EXHAUSTIVE]);
 
 impl EarlyLintPass for ManualNonExhaustiveStruct {
     fn check_item(&mut self, cx: &EarlyContext<'_>, item: &ast::Item) {
-        if !self.msrv.meets(msrvs::NON_EXHAUSTIVE) {
+        if !self
+            .msrv
+            .meets_or_report(MANUAL_NON_EXHAUSTIVE, item.span, msrvs::NON_EXHAUSTIVE)
+        {
             return;
         }
 
@@ -148,10 +152,6 @@ impl EarlyLintPass for ManualNonExhaustiveStruct {
 
 impl<'tcx> LateLintPass<'tcx> for ManualNonExhaustiveEnum {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
-        if !self.msrv.meets(msrvs::NON_EXHAUSTIVE) {
-            return;
-        }
-
         if let hir::ItemKind::Enum(def, _) = &item.kind
             && def.variants.len() > 1
         {
@@ -164,7 +164,8 @@ impl<'tcx> LateLintPass<'tcx> for ManualNonExhaustiveEnum {
             if let Some((id, span)) = iter.next()
                 && iter.next().is_none()
             {
-                self.potential_enums.push((item.owner_id.def_id, id, item.span, span));
+                self.potential_enums
+                    .push((item.owner_id.def_id, id, item.span, span, self.msrv.clone()));
             }
         }
     }
@@ -181,12 +182,15 @@ impl<'tcx> LateLintPass<'tcx> for ManualNonExhaustiveEnum {
     }
 
     fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
-        for &(enum_id, _, enum_span, variant_span) in
-            self.potential_enums.iter().filter(|&&(enum_id, variant_id, _, _)| {
-                !self
-                    .constructed_enum_variants
-                    .contains(&(enum_id.to_def_id(), variant_id.to_def_id()))
-            })
+        for &(enum_id, _, enum_span, variant_span, _) in
+            self.potential_enums
+                .iter()
+                .filter(|&(enum_id, variant_id, enum_span, _, msrv)| {
+                    !self
+                        .constructed_enum_variants
+                        .contains(&(enum_id.to_def_id(), variant_id.to_def_id()))
+                        && msrv.meets_or_report(MANUAL_NON_EXHAUSTIVE, *enum_span, msrvs::NON_EXHAUSTIVE)
+                })
         {
             let hir_id = cx.tcx.local_def_id_to_hir_id(enum_id);
             span_lint_hir_and_then(
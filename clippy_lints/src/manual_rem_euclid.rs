@@ -48,11 +48,18 @@ impl_lint_pass!(ManualRemEuclid => [MANUAL_REM_EUCLID]);
 
 impl<'tcx> LateLintPass<'tcx> for ManualRemEuclid {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if !self.msrv.meets(msrvs::REM_EUCLID) {
+        if !self
+            .msrv
+            .meets_or_report(MANUAL_REM_EUCLID, expr.span, msrvs::REM_EUCLID)
+        {
             return;
         }
 
-        if in_constant(cx, expr.hir_id) && !self.msrv.meets(msrvs::REM_EUCLID_CONST) {
+        if in_constant(cx, expr.hir_id)
+            && !self
+                .msrv
+                .meets_or_report(MANUAL_REM_EUCLID, expr.span, msrvs::REM_EUCLID_CONST)
+        {
             return;
         }
 
@@ -134,7 +134,7 @@ fn check_to_owned(
     target_expr: &hir::Expr<'_>,
     msrv: &Msrv,
 ) {
-    if msrv.meets(msrvs::STRING_RETAIN)
+    if msrv.meets_or_report(MANUAL_RETAIN, parent_expr.span, msrvs::STRING_RETAIN)
         && let hir::ExprKind::MethodCall(_, filter_expr, [], _) = &target_expr.kind
         && let Some(to_owned_def_id) = cx.typeck_results().type_dependent_def_id(target_expr.hir_id)
         && cx.tcx.is_diagnostic_item(sym::to_owned_method, to_owned_def_id)
@@ -226,6 +226,8 @@ fn match_acceptable_type(cx: &LateContext<'_>, expr: &hir::Expr<'_>, msrv: &Msrv
     let expr_ty = cx.typeck_results().expr_ty(expr).peel_refs();
     ACCEPTABLE_TYPES.iter().any(|(ty, acceptable_msrv)| {
         is_type_diagnostic_item(cx, expr_ty, *ty)
-            && acceptable_msrv.map_or(true, |acceptable_msrv| msrv.meets(acceptable_msrv))
+            && acceptable_msrv.map_or(true, |acceptable_msrv| {
+                msrv.meets_or_report(MANUAL_RETAIN, expr.span, acceptable_msrv)
+            })
     })
 }
@@ -66,7 +66,10 @@ enum StripKind {
 
 impl<'tcx> LateLintPass<'tcx> for ManualStrip {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if !self.msrv.meets(msrvs::STR_STRIP_PREFIX) {
+        if !self
+            .msrv
+            .meets_or_report(MANUAL_STRIP, expr.span, msrvs::STR_STRIP_PREFIX)
+        {
             return;
         }
 
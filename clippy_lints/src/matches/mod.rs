@@ -1043,7 +1043,11 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
 
             if !from_expansion && !contains_cfg_arm(cx, expr, ex, arms) {
                 if source == MatchSource::Normal {
-                    if !(self.msrv.meets(msrvs::MATCHES_MACRO) && match_like_matches::check_match(cx, expr, ex, arms)) {
+                    if !(self
+                        .msrv
+                        .meets_or_report(MATCH_LIKE_MATCHES_MACRO, expr.span, msrvs::MATCHES_MACRO)
+                        && match_like_matches::check_match(cx, expr, ex, arms))
+                    {
                         match_same_arms::check(cx, arms);
                     }
 
@@ -1076,7 +1080,10 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
             collapsible_match::check_if_let(cx, if_let.let_pat, if_let.if_then, if_let.if_else);
             if !from_expansion {
                 if let Some(else_expr) = if_let.if_else {
-                    if self.msrv.meets(msrvs::MATCHES_MACRO) {
+                    if self
+                        .msrv
+                        .meets_or_report(MATCH_LIKE_MATCHES_MACRO, expr.span, msrvs::MATCHES_MACRO)
+                    {
                         match_like_matches::check_if_let(
                             cx,
                             expr,
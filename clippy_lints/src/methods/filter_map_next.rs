@@ -17,7 +17,7 @@ pub(super) fn check<'tcx>(
     msrv: &Msrv,
 ) {
     if is_trait_method(cx, expr, sym::Iterator) {
-        if !msrv.meets(msrvs::ITERATOR_FIND_MAP) {
+        if !msrv.meets_or_report(FILTER_MAP_NEXT, expr.span, msrvs::ITERATOR_FIND_MAP) {
             return;
         }
 
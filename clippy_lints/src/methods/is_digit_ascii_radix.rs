@@ -16,7 +16,7 @@ pub(super) fn check<'tcx>(
     radix: &'tcx Expr<'_>,
     msrv: &Msrv,
 ) {
-    if !msrv.meets(msrvs::IS_ASCII_DIGIT) {
+    if !msrv.meets_or_report(IS_DIGIT_ASCII_RADIX, expr.span, msrvs::IS_ASCII_DIGIT) {
         return;
     }
 
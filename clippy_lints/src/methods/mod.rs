@@ -122,6 +122,7 @@ mod zst_offset;
 
 use bind_instead_of_map::BindInsteadOfMap;
 use clippy_config::msrvs::{self, Msrv};
+use clippy_config::Conf;
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
 use clippy_utils::ty::{contains_ty_adt_constructor_opaque, implements_trait, is_copy, is_type_diagnostic_item};
@@ -3755,8 +3756,9 @@ declare_clippy_lint! {
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
-    allow_expect_in_tests: bool,
-    allow_unwrap_in_tests: bool,
+    /// Used for `allow_expect_in_tests` and `allow_unwrap_in_tests`, which can be overridden per
+    /// path
+    conf: &'static Conf,
     allowed_dotfiles: FxHashSet<String>,
 }
 
@@ -3765,8 +3767,7 @@ impl Methods {
     pub fn new(
         avoid_breaking_exported_api: bool,
         msrv: Msrv,
-        allow_expect_in_tests: bool,
-        allow_unwrap_in_tests: bool,
+        conf: &'static Conf,
         mut allowed_dotfiles: FxHashSet<String>,
     ) -> Self {
         allowed_dotfiles.extend(DEFAULT_ALLOWED_DOTFILES.iter().map(ToString::to_string));
@@ -3774,8 +3775,7 @@ impl Methods {
         Self {
             avoid_breaking_exported_api,
             msrv,
-            allow_expect_in_tests,
-            allow_unwrap_in_tests,
+            conf,
             allowed_dotfiles,
         }
     }
@@ -4162,7 +4162,10 @@ impl Methods {
                             format_collect::check(cx, expr, m_arg, m_ident_span);
                         },
                         Some(("take", take_self_arg, [take_arg], _, _)) => {
-                            if self.msrv.meets(msrvs::STR_REPEAT) {
+                            if self
+                                .msrv
+                                .meets_or_report(MANUAL_STR_REPEAT, expr.span, msrvs::STR_REPEAT)
+                            {
                                 manual_str_repeat::check(cx, expr, recv, take_self_arg, take_arg);
                             }
                         },
@@ -4211,7 +4214,7 @@ impl Methods {
                             expr,
                             recv,
                             false,
-                            self.allow_expect_in_tests,
+                            self.conf.for_span(cx.sess(), expr.span).allow_expect_in_tests,
                             unwrap_expect_used::Variant::Expect,
                         ),
                     }
@@ -4224,7 +4227,7 @@ impl Methods {
                         expr,
                         recv,
                         true,
-                        self.allow_expect_in_tests,
+                        self.conf.for_span(cx.sess(), expr.span).allow_expect_in_tests,
                         unwrap_expect_used::Variant::Expect,
                     );
                 },
@@ -4430,7 +4433,9 @@ impl Methods {
                     no_effect_replace::check(cx, expr, arg1, arg2);
 
                     // Check for repeated `str::replace` calls to perform `collapsible_str_replace` lint
-                    if self.msrv.meets(msrvs::PATTERN_TRAIT_CHAR_ARRAY)
+                    if self
+                        .msrv
+                        .meets_or_report(COLLAPSIBLE_STR_REPLACE, expr.span, msrvs::PATTERN_TRAIT_CHAR_ARRAY)
                         && name == "replace"
                         && let Some(("replace", ..)) = method_call(recv)
                     {
@@ -4441,10 +4446,16 @@ impl Methods {
                     vec_resize_to_zero::check(cx, expr, count_arg, default_arg, span);
                 },
                 ("seek", [arg]) => {
-                    if self.msrv.meets(msrvs::SEEK_FROM_CURRENT) {
+                    if self
+                        .msrv
+                        .meets_or_report(SEEK_FROM_CURRENT, expr.span, msrvs::SEEK_FROM_CURRENT)
+                    {
                         seek_from_current::check(cx, expr, recv, arg);
                     }
-                    if self.msrv.meets(msrvs::SEEK_REWIND) {
+                    if self
+                        .msrv
+                        .meets_or_report(SEEK_TO_START_INSTEAD_OF_REWIND, expr.span, msrvs::SEEK_REWIND)
+                    {
                         seek_to_start_instead_of_rewind::check(cx, expr, recv, arg, span);
                     }
                 },
@@ -4499,7 +4510,10 @@ impl Methods {
                 },
                 ("take", []) => needless_option_take::check(cx, expr, recv),
                 ("then", [arg]) => {
-                    if !self.msrv.meets(msrvs::BOOL_THEN_SOME) {
+                    if !self
+                        .msrv
+                        .meets_or_report(UNNECESSARY_LAZY_EVALUATIONS, expr.span, msrvs::BOOL_THEN_SOME)
+                    {
                         return;
                     }
                     unnecessary_lazy_eval::check(cx, expr, recv, arg, "then_some");
@@ -4537,7 +4551,7 @@ impl Methods {
                         expr,
                         recv,
                         false,
-                        self.allow_unwrap_in_tests,
+                        self.conf.for_span(cx.sess(), expr.span).allow_unwrap_in_tests,
                         unwrap_expect_used::Variant::Unwrap,
                     );
                 },
@@ -4548,7 +4562,7 @@ impl Methods {
                         expr,
                         recv,
                         true,
-                        self.allow_unwrap_in_tests,
+                        self.conf.for_span(cx.sess(), expr.span).allow_unwrap_in_tests,
                         unwrap_expect_used::Variant::Unwrap,
                     );
                 },
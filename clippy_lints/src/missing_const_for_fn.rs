@@ -94,7 +94,10 @@ impl<'tcx> LateLintPass<'tcx> for MissingConstForFn {
         span: Span,
         def_id: LocalDefId,
     ) {
-        if !self.msrv.meets(msrvs::CONST_IF_MATCH) {
+        if !self
+            .msrv
+            .meets_or_report(MISSING_CONST_FOR_FN, span, msrvs::CONST_IF_MATCH)
+        {
             return;
         }
 
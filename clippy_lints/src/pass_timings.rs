@@ -0,0 +1,165 @@
+//! Measures the time spent in each of Clippy's lint passes.
+//!
+//! The passes registered after a [`PassCounts`] was taken are wrapped by [`instrument`] in passes
+//! that time each call of their `check_*` methods. When a pass is dropped, after it has checked the
+//! crate, its time is added to the totals returned by [`take`].
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintPass, LintStore};
+use serde::{Deserialize, Serialize};
+use std::mem;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// The time spent in a lint pass
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PassTiming {
+    /// The name of the type implementing the pass, e.g. `Methods`
+    pub name: String,
+    /// The wall time in seconds
+    pub secs: f64,
+}
+
+static TIMINGS: Mutex<Option<FxHashMap<&'static str, Duration>>> = Mutex::new(None);
+
+/// Adds `elapsed` to the time spent in `name`, used for the instrumented passes and for other steps
+/// of Clippy like reading the configuration
+pub fn record(name: &'static str, elapsed: Duration) {
+    *TIMINGS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_or_insert_with(FxHashMap::default)
+        .entry(name)
+        .or_default() += elapsed;
+}
+
+/// Returns the time spent in each instrumented pass and recorded step so far, slowest first, and
+/// resets it
+pub fn take() -> Vec<PassTiming> {
+    let timings = mem::take(&mut *TIMINGS.lock().unwrap_or_else(PoisonError::into_inner)).unwrap_or_default();
+    let mut timings: Vec<_> = timings
+        .into_iter()
+        .map(|(name, elapsed)| PassTiming {
+            name: name.to_string(),
+            secs: elapsed.as_secs_f64(),
+        })
+        .collect();
+    timings.sort_by(|a, b| b.secs.total_cmp(&a.secs).then_with(|| a.name.cmp(&b.name)));
+    timings
+}
+
+/// The number of passes of each kind in a [`LintStore`]
+pub struct PassCounts {
+    pre_expansion: usize,
+    early: usize,
+    late: usize,
+    late_module: usize,
+}
+
+impl PassCounts {
+    pub fn new(store: &LintStore) -> Self {
+        Self {
+            pre_expansion: store.pre_expansion_passes.len(),
+            early: store.early_passes.len(),
+            late: store.late_passes.len(),
+            late_module: store.late_module_passes.len(),
+        }
+    }
+}
+
+/// Instruments the passes that were registered to `store` after `counts` was taken
+pub fn instrument(store: &mut LintStore, counts: &PassCounts) {
+    for factory in store
+        .pre_expansion_passes
+        .iter_mut()
+        .skip(counts.pre_expansion)
+        .chain(store.early_passes.iter_mut().skip(counts.early))
+    {
+        let inner = mem::replace(factory, Box::new(|| unreachable!()));
+        *factory = Box::new(move || Box::new(TimedEarlyPass::new(inner())));
+    }
+
+    for factory in store
+        .late_passes
+        .iter_mut()
+        .skip(counts.late)
+        .chain(store.late_module_passes.iter_mut().skip(counts.late_module))
+    {
+        let inner = mem::replace(factory, Box::new(|_| unreachable!()));
+        *factory = Box::new(move |tcx| Box::new(TimedLatePass::new(inner(tcx))));
+    }
+}
+
+/// Implements the `check_*` methods of a lint pass to call the ones of the wrapped pass and add up
+/// the time they take
+macro_rules! timed_methods {
+    ([$cx:ty], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => {
+        $(fn $name(&mut self, cx: &$cx, $($param: $arg),*) {
+            let start = Instant::now();
+            self.pass.$name(cx, $($param),*);
+            self.elapsed += start.elapsed();
+        })*
+    };
+}
+
+struct TimedEarlyPass {
+    pass: Box<dyn EarlyLintPass>,
+    elapsed: Duration,
+}
+
+impl TimedEarlyPass {
+    fn new(pass: Box<dyn EarlyLintPass>) -> Self {
+        Self {
+            pass,
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+impl Drop for TimedEarlyPass {
+    fn drop(&mut self) {
+        record(self.pass.name(), self.elapsed);
+    }
+}
+
+#[expect(rustc::lint_pass_impl_without_macro)]
+impl LintPass for TimedEarlyPass {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+impl EarlyLintPass for TimedEarlyPass {
+    rustc_lint::early_lint_methods!(timed_methods, [EarlyContext<'_>]);
+}
+
+struct TimedLatePass<'tcx> {
+    pass: Box<dyn LateLintPass<'tcx> + 'tcx>,
+    elapsed: Duration,
+}
+
+impl<'tcx> TimedLatePass<'tcx> {
+    fn new(pass: Box<dyn LateLintPass<'tcx> + 'tcx>) -> Self {
+        Self {
+            pass,
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+impl Drop for TimedLatePass<'_> {
+    fn drop(&mut self) {
+        record(self.pass.name(), self.elapsed);
+    }
+}
+
+#[expect(rustc::lint_pass_impl_without_macro)]
+impl LintPass for TimedLatePass<'_> {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for TimedLatePass<'tcx> {
+    rustc_lint::late_lint_methods!(timed_methods, [LateContext<'tcx>]);
+}
@@ -180,7 +180,10 @@ impl_lint_pass!(Ranges => [
 impl<'tcx> LateLintPass<'tcx> for Ranges {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let ExprKind::Binary(ref op, l, r) = expr.kind {
-            if self.msrv.meets(msrvs::RANGE_CONTAINS) {
+            if self
+                .msrv
+                .meets_or_report(MANUAL_RANGE_CONTAINS, expr.span, msrvs::RANGE_CONTAINS)
+            {
                 check_possible_range_contains(cx, op.node, l, r, expr, expr.span);
             }
         }
@@ -50,7 +50,10 @@ impl_lint_pass!(RedundantFieldNames => [REDUNDANT_FIELD_NAMES]);
 
 impl EarlyLintPass for RedundantFieldNames {
     fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
-        if !self.msrv.meets(msrvs::FIELD_INIT_SHORTHAND) {
+        if !self
+            .msrv
+            .meets_or_report(REDUNDANT_FIELD_NAMES, expr.span, msrvs::FIELD_INIT_SHORTHAND)
+        {
             return;
         }
 
@@ -96,7 +96,10 @@ impl RedundantStaticLifetimes {
 
 impl EarlyLintPass for RedundantStaticLifetimes {
     fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
-        if !self.msrv.meets(msrvs::STATIC_IN_CONST) {
+        if !self
+            .msrv
+            .meets_or_report(REDUNDANT_STATIC_LIFETIMES, item.span, msrvs::STATIC_IN_CONST)
+        {
             return;
         }
 
@@ -1,13 +1,16 @@
 use std::fmt::Display;
 
+use clippy_config::msrvs::{self, Msrv};
 use clippy_utils::consts::{constant, Constant};
-use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_then};
 use clippy_utils::source::snippet_opt;
-use clippy_utils::{def_path_def_ids, path_def_id, paths};
+use clippy_utils::{def_path_def_ids, get_enclosing_loop_or_multi_call_closure, path_def_id, paths};
+use regex_syntax::hir::{Class, Hir, HirKind};
 use rustc_ast::ast::{LitKind, StrStyle};
 use rustc_hir::def_id::DefIdMap;
-use rustc_hir::{BorrowKind, Expr, ExprKind};
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_hir::{BorrowKind, Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
 use rustc_session::impl_lint_pass;
 use rustc_span::{BytePos, Span};
 
@@ -55,6 +58,67 @@ declare_clippy_lint! {
     "trivial regular expressions"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for [regex](https://crates.io/crates/regex) compilation of constant patterns
+    /// inside loops, and inside closures that may be called multiple times, such as the
+    /// closures passed to `Iterator::map` or `Iterator::filter`.
+    ///
+    /// ### Why is this bad?
+    /// Compiling a regex is expensive, usually far more than matching it. A pattern that doesn't
+    /// change is compiled again on every iteration.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// for haystack in haystacks {
+    ///     let regex = Regex::new(MY_REGEX).unwrap();
+    ///     if regex.is_match(haystack) {
+    ///         // Perform operation
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// let regex = Regex::new(MY_REGEX).unwrap();
+    /// for haystack in haystacks {
+    ///     if regex.is_match(haystack) {
+    ///         // Perform operation
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub REGEX_CREATION_IN_LOOPS,
+    perf,
+    "regular expressions compiled in a loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for [regex](https://crates.io/crates/regex) creation of constant patterns whose
+    /// compiled size is estimated to be larger than the configured `large-regex-threshold`.
+    ///
+    /// ### Why is this bad?
+    /// Large regexes are slow to compile and use a lot of memory, and may even fail to compile
+    /// once they exceed the size limit of the regex crate. Bounded repetitions of Unicode classes,
+    /// e.g. `\w{50}`, are easily far larger than intended.
+    ///
+    /// ### Known problems
+    /// The size is an estimate, the actual size depends on the regex engine used.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// Regex::new(r"\w{100}")
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// Regex::new(r"(?-u:\w){100}")
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub LARGE_REGEX,
+    pedantic,
+    "regular expressions with a large compiled size"
+}
+
 #[derive(Copy, Clone)]
 enum RegexKind {
     Unicode,
@@ -63,12 +127,24 @@ enum RegexKind {
     BytesSet,
 }
 
-#[derive(Default)]
 pub struct Regex {
     definitions: DefIdMap<RegexKind>,
+    msrv: Msrv,
+    large_regex_threshold: u64,
 }
 
-impl_lint_pass!(Regex => [INVALID_REGEX, TRIVIAL_REGEX]);
+impl Regex {
+    #[must_use]
+    pub fn new(msrv: Msrv, large_regex_threshold: u64) -> Self {
+        Self {
+            definitions: DefIdMap::default(),
+            msrv,
+            large_regex_threshold,
+        }
+    }
+}
+
+impl_lint_pass!(Regex => [INVALID_REGEX, TRIVIAL_REGEX, REGEX_CREATION_IN_LOOPS, LARGE_REGEX]);
 
 impl<'tcx> LateLintPass<'tcx> for Regex {
     fn check_crate(&mut self, cx: &LateContext<'tcx>) {
@@ -96,14 +172,68 @@ impl<'tcx> LateLintPass<'tcx> for Regex {
             && let Some(def_id) = path_def_id(cx, fun)
             && let Some(regex_kind) = self.definitions.get(&def_id)
         {
-            match regex_kind {
-                RegexKind::Unicode => check_regex(cx, arg, true),
-                RegexKind::UnicodeSet => check_set(cx, arg, true),
-                RegexKind::Bytes => check_regex(cx, arg, false),
-                RegexKind::BytesSet => check_set(cx, arg, false),
+            let threshold = self.large_regex_threshold;
+            let is_const = match regex_kind {
+                RegexKind::Unicode => check_regex(cx, arg, true, threshold),
+                RegexKind::UnicodeSet => check_set(cx, arg, true, threshold),
+                RegexKind::Bytes => check_regex(cx, arg, false, threshold),
+                RegexKind::BytesSet => check_set(cx, arg, false, threshold),
+            };
+
+            if is_const
+                && !in_external_macro(cx.sess(), expr.span)
+                && let Some(enclosing) = get_enclosing_loop_or_multi_call_closure(cx, expr)
+                && !is_lazily_initialized(cx, expr, enclosing)
+            {
+                lint_creation_in_loop(cx, expr, enclosing, &self.msrv);
             }
         }
     }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Whether `expr` is in a closure passed to e.g. `OnceLock::get_or_init` inside of `enclosing`,
+/// which is only called once
+fn is_lazily_initialized(cx: &LateContext<'_>, expr: &Expr<'_>, enclosing: &Expr<'_>) -> bool {
+    let mut child_id = expr.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        if parent_id == enclosing.hir_id {
+            break;
+        }
+        if let Node::Expr(parent) = node
+            && let ExprKind::MethodCall(path, _, args, _) = parent.kind
+            && matches!(
+                path.ident.as_str(),
+                "get_or_init" | "get_or_try_init" | "call_once" | "call_once_force"
+            )
+            && args.iter().any(|arg| arg.hir_id == child_id)
+        {
+            return true;
+        }
+        child_id = parent_id;
+    }
+    false
+}
+
+fn lint_creation_in_loop(cx: &LateContext<'_>, expr: &Expr<'_>, enclosing: &Expr<'_>, msrv: &Msrv) {
+    let (msg, label) = if matches!(enclosing.kind, ExprKind::Closure(_)) {
+        (
+            "compiling a regex in a closure that may be called multiple times",
+            "the closure is here",
+        )
+    } else {
+        ("compiling a regex in a loop", "the loop is here")
+    };
+
+    span_lint_and_then(cx, REGEX_CREATION_IN_LOOPS, expr.span, msg, |diag| {
+        diag.span_label(enclosing.span.source_callsite(), label);
+        if msrv.meets(msrvs::ONCE_LOCK) {
+            diag.help("move the regex construction out of the loop, or into a `static` initialized with `OnceLock`");
+        } else {
+            diag.help("move the regex construction out of the loop");
+        }
+    });
 }
 
 fn lint_syntax_error(cx: &LateContext<'_>, error: &regex_syntax::Error, unescaped: &str, base: Span, offset: u8) {
@@ -190,17 +320,61 @@ fn is_trivial_regex(s: &regex_syntax::hir::Hir) -> Option<&'static str> {
     }
 }
 
-fn check_set<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool) {
+/// Estimates the size of the compiled regex, roughly the number of states of its automaton.
+/// Bounded repetitions are unrolled, and classes count one state per range.
+fn regex_size(hir: &Hir) -> u64 {
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => 1,
+        HirKind::Literal(lit) => lit.0.len() as u64,
+        HirKind::Class(Class::Unicode(class)) => class.ranges().len() as u64,
+        HirKind::Class(Class::Bytes(class)) => class.ranges().len() as u64,
+        HirKind::Repetition(rep) => {
+            let count = rep.max.unwrap_or(rep.min.saturating_add(1)).max(1);
+            regex_size(&rep.sub).saturating_mul(u64::from(count))
+        },
+        HirKind::Capture(capture) => regex_size(&capture.sub).saturating_add(2),
+        HirKind::Concat(hirs) | HirKind::Alternation(hirs) => {
+            hirs.iter().fold(0, |size, hir| size.saturating_add(regex_size(hir)))
+        },
+    }
+}
+
+fn check_parsed_regex(cx: &LateContext<'_>, expr: &Expr<'_>, hir: &Hir, threshold: u64) {
+    if let Some(repl) = is_trivial_regex(hir) {
+        span_lint_and_help(cx, TRIVIAL_REGEX, expr.span, "trivial regex", None, repl);
+    }
+
+    let size = regex_size(hir);
+    if size > threshold {
+        span_lint_and_help(
+            cx,
+            LARGE_REGEX,
+            expr.span,
+            &format!("this regex has an estimated compiled size of {size}"),
+            None,
+            &format!(
+                "the configured limit is {threshold}, consider reducing the bounded repetitions or \
+                disabling Unicode with `(?-u)`"
+            ),
+        );
+    }
+}
+
+/// Checks the patterns of a `RegexSet`, returns whether they are all constant
+fn check_set<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool, threshold: u64) -> bool {
     if let ExprKind::AddrOf(BorrowKind::Ref, _, expr) = expr.kind
         && let ExprKind::Array(exprs) = expr.kind
     {
-        for expr in exprs {
-            check_regex(cx, expr, utf8);
-        }
+        exprs.iter().fold(true, |is_const, expr| {
+            check_regex(cx, expr, utf8, threshold) && is_const
+        })
+    } else {
+        false
     }
 }
 
-fn check_regex<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool) {
+/// Checks the pattern of a `Regex`, returns whether it is constant
+fn check_regex<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool, threshold: u64) -> bool {
     let mut parser = regex_syntax::ParserBuilder::new().unicode(true).utf8(utf8).build();
 
     if let ExprKind::Lit(lit) = expr.kind {
@@ -208,22 +382,17 @@ fn check_regex<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, utf8: bool) {
             let r = r.as_str();
             let offset = if let StrStyle::Raw(n) = style { 2 + n } else { 1 };
             match parser.parse(r) {
-                Ok(r) => {
-                    if let Some(repl) = is_trivial_regex(&r) {
-                        span_lint_and_help(cx, TRIVIAL_REGEX, expr.span, "trivial regex", None, repl);
-                    }
-                },
+                Ok(r) => check_parsed_regex(cx, expr, &r, threshold),
                 Err(e) => lint_syntax_error(cx, &e, r, expr.span, offset),
             }
+            return true;
         }
     } else if let Some(r) = const_str(cx, expr) {
         match parser.parse(&r) {
-            Ok(r) => {
-                if let Some(repl) = is_trivial_regex(&r) {
-                    span_lint_and_help(cx, TRIVIAL_REGEX, expr.span, "trivial regex", None, repl);
-                }
-            },
+            Ok(r) => check_parsed_regex(cx, expr, &r, threshold),
             Err(e) => span_lint(cx, INVALID_REGEX, expr.span, &e.to_string()),
         }
+        return true;
     }
+    false
 }
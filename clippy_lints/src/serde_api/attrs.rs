@@ -0,0 +1,221 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_note};
+use clippy_utils::ty::implements_trait_with_env;
+use rustc_hir::{HirId, Item, ItemKind, VariantData};
+use rustc_lint::LateContext;
+use rustc_span::symbol::Ident;
+use rustc_span::{sym, Span, Symbol};
+
+use super::{SERDE_DEFAULT_WITHOUT_DEFAULT, SERDE_DUPLICATE_NAME, SERDE_FLATTEN_WITH_DENY_UNKNOWN_FIELDS};
+
+/// The `#[serde(..)]` attributes of a container, a field or a variant that are checked
+#[derive(Default)]
+#[expect(clippy::struct_excessive_bools)]
+struct SerdeAttrs {
+    /// The names set with `rename`, for serialization and deserialization
+    serialize_name: Option<Symbol>,
+    deserialize_name: Option<Symbol>,
+    aliases: Vec<Symbol>,
+    skip_serializing: bool,
+    skip_deserializing: bool,
+    /// Whether the names of the fields or variants are changed with `rename_all`
+    rename_all: bool,
+    rename_all_fields: bool,
+    untagged: bool,
+    deny_unknown_fields: Option<Span>,
+    flatten: Option<Span>,
+    /// `default` without a path to a function
+    default: Option<Span>,
+}
+
+impl SerdeAttrs {
+    fn new(cx: &LateContext<'_>, hir_id: HirId) -> Self {
+        let mut attrs = Self::default();
+        for attr in cx.tcx.hir().attrs(hir_id) {
+            if !attr.has_name(sym!(serde)) {
+                continue;
+            }
+            for item in attr.meta_item_list().unwrap_or_default() {
+                let Some(meta) = item.meta_item() else {
+                    continue;
+                };
+                match meta.name_or_empty().as_str() {
+                    "rename" => {
+                        if let Some(name) = meta.value_str() {
+                            attrs.serialize_name = Some(name);
+                            attrs.deserialize_name = Some(name);
+                        }
+                        for nested in meta.meta_item_list().unwrap_or_default() {
+                            match nested.name_or_empty().as_str() {
+                                "serialize" => attrs.serialize_name = nested.value_str(),
+                                "deserialize" => attrs.deserialize_name = nested.value_str(),
+                                _ => {},
+                            }
+                        }
+                    },
+                    "alias" => attrs.aliases.extend(meta.value_str()),
+                    "skip" => {
+                        attrs.skip_serializing = true;
+                        attrs.skip_deserializing = true;
+                    },
+                    "skip_serializing" => attrs.skip_serializing = true,
+                    "skip_deserializing" => attrs.skip_deserializing = true,
+                    "rename_all" => attrs.rename_all = true,
+                    "rename_all_fields" => attrs.rename_all_fields = true,
+                    "untagged" => attrs.untagged = true,
+                    "deny_unknown_fields" => attrs.deny_unknown_fields = Some(meta.span),
+                    "flatten" => attrs.flatten = Some(meta.span),
+                    "default" if meta.is_word() => attrs.default = Some(meta.span),
+                    _ => {},
+                }
+            }
+        }
+        attrs
+    }
+}
+
+/// A field or a variant, with its attributes
+struct Member {
+    ident: Ident,
+    span: Span,
+    attrs: SerdeAttrs,
+}
+
+/// Checks the `#[serde(..)]` attributes of a struct or an enum
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>) {
+    let container = SerdeAttrs::new(cx, item.hir_id());
+    match item.kind {
+        ItemKind::Struct(ref data, _) => {
+            check_fields(cx, item, data, &container, container.rename_all);
+        },
+        ItemKind::Enum(ref def, _) => {
+            let mut variants = Vec::new();
+            for variant in def.variants {
+                let attrs = SerdeAttrs::new(cx, variant.hir_id);
+                check_fields(
+                    cx,
+                    item,
+                    &variant.data,
+                    &container,
+                    container.rename_all_fields || attrs.rename_all,
+                );
+                variants.push(Member {
+                    ident: variant.ident,
+                    span: variant.span,
+                    attrs,
+                });
+            }
+            if !container.untagged {
+                check_duplicate_names(cx, &variants, container.rename_all, "variant");
+            }
+        },
+        _ => {},
+    }
+}
+
+fn check_fields(
+    cx: &LateContext<'_>,
+    item: &Item<'_>,
+    data: &VariantData<'_>,
+    container: &SerdeAttrs,
+    rename_all: bool,
+) {
+    let mut fields = Vec::new();
+    for field in data.fields() {
+        let attrs = SerdeAttrs::new(cx, field.hir_id);
+
+        if let Some(flatten) = attrs.flatten
+            && let Some(deny_unknown_fields) = container.deny_unknown_fields
+        {
+            span_lint_and_note(
+                cx,
+                SERDE_FLATTEN_WITH_DENY_UNKNOWN_FIELDS,
+                flatten,
+                "`#[serde(flatten)]` is not supported in a type with `#[serde(deny_unknown_fields)]`",
+                Some(deny_unknown_fields),
+                "the unknown fields are denied here",
+            );
+        }
+
+        if let Some(default) = attrs.default
+            && let Some(default_trait) = cx.tcx.get_diagnostic_item(sym::Default)
+            && let ty = cx.tcx.type_of(field.def_id).instantiate_identity()
+            && !implements_trait_with_env(cx.tcx, cx.tcx.param_env(item.owner_id), ty, default_trait, &[])
+        {
+            span_lint_and_help(
+                cx,
+                SERDE_DEFAULT_WITHOUT_DEFAULT,
+                default,
+                &format!("`#[serde(default)]` is used on a field of type `{ty}`, which doesn't implement `Default`"),
+                None,
+                &format!(
+                    "the derived `Deserialize` impl requires `{ty}: Default`, consider adding the bound to the type"
+                ),
+            );
+        }
+
+        fields.push(Member {
+            ident: field.ident,
+            span: field.span,
+            attrs,
+        });
+    }
+
+    // the fields of tuple structs and variants are not named
+    if matches!(data, VariantData::Struct(..)) {
+        check_duplicate_names(cx, &fields, rename_all, "field");
+    }
+}
+
+/// Lints the fields or variants that share a name with a previous one, for serialization or for
+/// deserialization
+fn check_duplicate_names(cx: &LateContext<'_>, members: &[Member], rename_all: bool, kind: &str) {
+    let mut serialize_names: Vec<(Symbol, &Member)> = Vec::new();
+    let mut deserialize_names: Vec<(Symbol, &Member)> = Vec::new();
+
+    for member in members {
+        let attrs = &member.attrs;
+        if attrs.flatten.is_some() {
+            continue;
+        }
+        // without `rename`, the name is unknown if it's changed with `rename_all`
+        let name = (!rename_all).then_some(member.ident.name);
+
+        let serialize_name = attrs.serialize_name.or(name).filter(|_| !attrs.skip_serializing);
+        let mut own_deserialize_names = Vec::new();
+        if !attrs.skip_deserializing {
+            own_deserialize_names.extend(attrs.deserialize_name.or(name));
+            own_deserialize_names.extend(attrs.aliases.iter().copied());
+            own_deserialize_names.dedup();
+        }
+
+        let mut collisions: Vec<(Symbol, &Member)> = Vec::new();
+        let seen = serialize_name
+            .iter()
+            .map(|&name| (name, &serialize_names))
+            .chain(own_deserialize_names.iter().map(|&name| (name, &deserialize_names)));
+        for (name, seen) in seen {
+            if let Some(&(_, other)) = seen.iter().find(|&&(seen_name, _)| seen_name == name)
+                && !collisions.iter().any(|&(n, m)| n == name && m.ident == other.ident)
+            {
+                collisions.push((name, other));
+            }
+        }
+
+        for (name, other) in collisions {
+            span_lint_and_note(
+                cx,
+                SERDE_DUPLICATE_NAME,
+                member.span,
+                &format!(
+                    "the serde name `{name}` of {kind} `{}` is also used by {kind} `{}`",
+                    member.ident, other.ident
+                ),
+                Some(other.span),
+                &format!("`{}` is declared here", other.ident),
+            );
+        }
+
+        serialize_names.extend(serialize_name.map(|name| (name, member)));
+        deserialize_names.extend(own_deserialize_names.into_iter().map(|name| (name, member)));
+    }
+}
@@ -0,0 +1,171 @@
+mod attrs;
+mod visitor;
+
+use clippy_utils::{get_trait_def_id, paths};
+use rustc_hir::{Impl, Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for mis-uses of the serde API.
+    ///
+    /// ### Why is this bad?
+    /// Serde is very finnicky about how its API should be
+    /// used, but the type system can't be used to enforce it (yet?).
+    ///
+    /// ### Example
+    /// Implementing `Visitor::visit_string` but not
+    /// `Visitor::visit_str`.
+    #[clippy::version = "pre 1.29.0"]
+    pub SERDE_API_MISUSE,
+    correctness,
+    "various things that will negatively affect your serde experience"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for fields or variants of a type deriving `Serialize` or `Deserialize` whose
+    /// names, set with `#[serde(rename = "..")]` and `#[serde(alias = "..")]`, collide.
+    ///
+    /// ### Why is this bad?
+    /// Only one of the fields can be deserialized from the name, and the serialized output
+    /// contains the name twice.
+    ///
+    /// ### Known problems
+    /// The names of the fields that are not renamed are unknown if the type uses
+    /// `#[serde(rename_all = "..")]`, they are not checked.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct Config {
+    ///     #[serde(alias = "name")]
+    ///     user_name: String,
+    ///     name: String,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct Config {
+    ///     #[serde(alias = "user")]
+    ///     user_name: String,
+    ///     name: String,
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub SERDE_DUPLICATE_NAME,
+    suspicious,
+    "fields or variants with the same serde name"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `#[serde(flatten)]` fields in types with `#[serde(deny_unknown_fields)]`.
+    ///
+    /// ### Why is this bad?
+    /// Serde doesn't support this combination: the fields of the flattened type are unknown to
+    /// the outer type, so deserialization fails on valid input.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// #[serde(deny_unknown_fields)]
+    /// struct Outer {
+    ///     a: u32,
+    ///     #[serde(flatten)]
+    ///     inner: Inner,
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub SERDE_FLATTEN_WITH_DENY_UNKNOWN_FIELDS,
+    suspicious,
+    "`#[serde(flatten)]` in a type with `#[serde(deny_unknown_fields)]`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `#[serde(default)]` on fields whose type doesn't implement `Default`, i.e.
+    /// fields of a generic type.
+    ///
+    /// ### Why is this bad?
+    /// The derived `Deserialize` impl is given a hidden `Default` bound on the type parameter, so
+    /// the type can't be deserialized for the type arguments that don't implement `Default`.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct Wrapper<T> {
+    ///     #[serde(default)]
+    ///     value: T,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #[derive(Deserialize)]
+    /// struct Wrapper<T: Default> {
+    ///     #[serde(default)]
+    ///     value: T,
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub SERDE_DEFAULT_WITHOUT_DEFAULT,
+    pedantic,
+    "`#[serde(default)]` on a field whose type doesn't implement `Default`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unwrap` and `expect` on `Option` and `Result` in the `visit_*` methods of
+    /// `serde::de::Visitor` impls.
+    ///
+    /// ### Why is this bad?
+    /// Deserializing malformed input panics instead of returning an error.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+    ///     Ok(v.parse().unwrap())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+    ///     v.parse().map_err(E::custom)
+    /// }
+    /// ```
+    #[clippy::version = "1.76.0"]
+    pub SERDE_UNWRAP_IN_VISITOR,
+    suspicious,
+    "`unwrap` or `expect` in the `visit_*` methods of a serde `Visitor`"
+}
+
+declare_lint_pass!(SerdeApi => [
+    SERDE_API_MISUSE,
+    SERDE_DUPLICATE_NAME,
+    SERDE_FLATTEN_WITH_DENY_UNKNOWN_FIELDS,
+    SERDE_DEFAULT_WITHOUT_DEFAULT,
+    SERDE_UNWRAP_IN_VISITOR,
+]);
+
+impl<'tcx> LateLintPass<'tcx> for SerdeApi {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        match item.kind {
+            ItemKind::Impl(Impl {
+                of_trait: Some(ref trait_ref),
+                items,
+                ..
+            }) => {
+                let did = trait_ref.path.res.def_id();
+                if let Some(visit_did) = get_trait_def_id(cx, &paths::SERDE_DE_VISITOR)
+                    && did == visit_did
+                {
+                    visitor::check(cx, item, items);
+                }
+            },
+            ItemKind::Struct(..) | ItemKind::Enum(..) => attrs::check(cx, item),
+            _ => {},
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::ops::ControlFlow;
+
+use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use rustc_hir::{ExprKind, ImplItemKind, ImplItemRef, Item};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::{SERDE_API_MISUSE, SERDE_UNWRAP_IN_VISITOR};
+
+/// Checks an impl of `serde::de::Visitor`
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, items: &[ImplItemRef]) {
+    let mut seen_str = None;
+    let mut seen_string = None;
+    for item in items {
+        match item.ident.as_str() {
+            "visit_str" => seen_str = Some(item.span),
+            "visit_string" => seen_string = Some(item.span),
+            _ => {},
+        }
+    }
+    if let Some(span) = seen_string {
+        if seen_str.is_none() {
+            span_lint(
+                cx,
+                SERDE_API_MISUSE,
+                span,
+                "you should not implement `visit_string` without also implementing `visit_str`",
+            );
+        }
+    }
+
+    if !item.span.from_expansion() {
+        for item in items {
+            if item.ident.as_str().starts_with("visit_") {
+                check_unwrap(cx, item);
+            }
+        }
+    }
+}
+
+fn check_unwrap(cx: &LateContext<'_>, item: &ImplItemRef) {
+    let impl_item = cx.tcx.hir().impl_item(item.id);
+    let ImplItemKind::Fn(_, body_id) = impl_item.kind else {
+        return;
+    };
+    let body = cx.tcx.hir().body(body_id);
+    let typeck = cx.tcx.typeck_body(body_id);
+
+    let mut spans = Vec::new();
+    let _: Option<!> = for_each_expr(body.value, |e| {
+        if let ExprKind::MethodCall(path, receiver, _, _) = e.kind
+            && matches!(path.ident.as_str(), "unwrap" | "expect")
+            && !e.span.from_expansion()
+        {
+            let receiver_ty = typeck.expr_ty(receiver).peel_refs();
+            if is_type_diagnostic_item(cx, receiver_ty, sym::Option)
+                || is_type_diagnostic_item(cx, receiver_ty, sym::Result)
+            {
+                spans.push(e.span);
+            }
+        }
+        ControlFlow::Continue(())
+    });
+
+    if !spans.is_empty() {
+        span_lint_and_then(
+            cx,
+            SERDE_UNWRAP_IN_VISITOR,
+            impl_item.span,
+            &format!("used `unwrap` or `expect` in `{}`", item.ident),
+            |diag| {
+                diag.span_note(spans, "this panics on malformed input");
+                diag.help("return an error instead, e.g. with `serde::de::Error::custom`");
+            },
+        );
+    }
+}
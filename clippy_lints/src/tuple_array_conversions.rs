@@ -49,7 +49,11 @@ pub struct TupleArrayConversions {
 
 impl LateLintPass<'_> for TupleArrayConversions {
     fn check_expr<'tcx>(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
-        if in_external_macro(cx.sess(), expr.span) || !self.msrv.meets(msrvs::TUPLE_ARRAY_CONVERSIONS) {
+        if in_external_macro(cx.sess(), expr.span)
+            || !self
+                .msrv
+                .meets_or_report(TUPLE_ARRAY_CONVERSIONS, expr.span, msrvs::TUPLE_ARRAY_CONVERSIONS)
+        {
             return;
         }
 
@@ -61,35 +61,27 @@ impl_lint_pass!(UnnestedOrPatterns => [UNNESTED_OR_PATTERNS]);
 
 impl EarlyLintPass for UnnestedOrPatterns {
     fn check_arm(&mut self, cx: &EarlyContext<'_>, a: &ast::Arm) {
-        if self.msrv.meets(msrvs::OR_PATTERNS) {
-            lint_unnested_or_patterns(cx, &a.pat);
-        }
+        lint_unnested_or_patterns(cx, &self.msrv, &a.pat);
     }
 
     fn check_expr(&mut self, cx: &EarlyContext<'_>, e: &ast::Expr) {
-        if self.msrv.meets(msrvs::OR_PATTERNS) {
-            if let ast::ExprKind::Let(pat, _, _, _) = &e.kind {
-                lint_unnested_or_patterns(cx, pat);
-            }
+        if let ast::ExprKind::Let(pat, _, _, _) = &e.kind {
+            lint_unnested_or_patterns(cx, &self.msrv, pat);
         }
     }
 
     fn check_param(&mut self, cx: &EarlyContext<'_>, p: &ast::Param) {
-        if self.msrv.meets(msrvs::OR_PATTERNS) {
-            lint_unnested_or_patterns(cx, &p.pat);
-        }
+        lint_unnested_or_patterns(cx, &self.msrv, &p.pat);
     }
 
     fn check_local(&mut self, cx: &EarlyContext<'_>, l: &ast::Local) {
-        if self.msrv.meets(msrvs::OR_PATTERNS) {
-            lint_unnested_or_patterns(cx, &l.pat);
-        }
+        lint_unnested_or_patterns(cx, &self.msrv, &l.pat);
     }
 
     extract_msrv_attr!(EarlyContext);
 }
 
-fn lint_unnested_or_patterns(cx: &EarlyContext<'_>, pat: &Pat) {
+fn lint_unnested_or_patterns(cx: &EarlyContext<'_>, msrv: &Msrv, pat: &Pat) {
     if let Ident(.., None) | Lit(_) | Wild | Path(..) | Range(..) | Rest | MacCall(_) = pat.kind {
         // This is a leaf pattern, so cloning is unprofitable.
         return;
@@ -101,7 +93,7 @@ fn lint_unnested_or_patterns(cx: &EarlyContext<'_>, pat: &Pat) {
     remove_all_parens(&mut pat);
 
     // Transform all unnested or-patterns into nested ones, and if there were none, quit.
-    if !unnest_or_patterns(&mut pat) {
+    if !unnest_or_patterns(&mut pat) || !msrv.meets_or_report(UNNESTED_OR_PATTERNS, pat.span, msrvs::OR_PATTERNS) {
         return;
     }
 
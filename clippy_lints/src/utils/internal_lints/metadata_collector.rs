@@ -39,6 +39,9 @@ use std::process::Command;
 const JSON_OUTPUT_FILE: &str = "../util/gh-pages/lints.json";
 /// This is the markdown output file of the lint collector.
 const MARKDOWN_OUTPUT_FILE: &str = "../book/src/lint_configuration.md";
+/// This is the Rust output file of the lint collector, it's included by `clippy_lints` for
+/// `cargo clippy --list-lints --format json`
+const RUST_OUTPUT_FILE: &str = "../clippy_lints/src/collected_metadata.rs";
 /// These groups will be ignored by the lint group matcher. This is useful for collections like
 /// `clippy::all`
 const IGNORED_LINT_GROUPS: [&str; 1] = ["clippy::all"];
@@ -143,6 +146,8 @@ pub struct MetadataCollector {
     /// We use a Heap here to have the lints added in alphabetic order in the export
     lints: BinaryHeap<LintMetadata>,
     applicability_info: FxHashMap<String, ApplicabilityInfo>,
+    /// The lints that are skipped by a `Msrv::meets_or_report` check
+    msrv_gated: BTreeSet<String>,
     config: Vec<ClippyConfiguration>,
     clippy_project_root: PathBuf,
 }
@@ -152,6 +157,7 @@ impl MetadataCollector {
         Self {
             lints: BinaryHeap::<LintMetadata>::default(),
             applicability_info: FxHashMap::<String, ApplicabilityInfo>::default(),
+            msrv_gated: BTreeSet::new(),
             config: get_configuration_metadata(),
             clippy_project_root: std::env::current_dir()
                 .expect("failed to get current dir")
@@ -225,6 +231,31 @@ impl Drop for MetadataCollector {
 
         collect_renames(&mut lints);
 
+        // Outputting the metadata used by `cargo clippy --list-lints`
+        let mut rust = String::from(
+            "// This file was generated by `cargo collect-metadata`.
+// Use that command to update this file and do not edit by hand.
+// Manual edits will be overwritten.
+
+/// The name, the applicability of the suggestions and whether the lint is gated on the MSRV of
+/// each lint, sorted by name
+pub(crate) static COLLECTED_METADATA: &[(&str, &str, bool)] = &[
+",
+        );
+        for lint in lints.iter().filter(|lint| lint.group != DEPRECATED_LINT_GROUP_STR) {
+            let applicability = lint
+                .applicability
+                .as_ref()
+                .and_then(|info| info.applicability)
+                .map_or(APPLICABILITY_UNRESOLVED_STR, |index| {
+                    paths::APPLICABILITY_VALUES[index][APPLICABILITY_NAME_INDEX]
+                });
+            let msrv_gated = self.msrv_gated.contains(&lint.id);
+            writeln!(rust, "    (\"{}\", \"{applicability}\", {msrv_gated}),", lint.id).unwrap();
+        }
+        rust.push_str("];\n");
+        fs::write(RUST_OUTPUT_FILE, rust).unwrap();
+
         // Outputting json
         fs::write(JSON_OUTPUT_FILE, serde_json::to_string_pretty(&lints).unwrap()).unwrap();
 
@@ -600,6 +631,15 @@ impl<'hir> LateLintPass<'hir> for MetadataCollector {
     /// );
     /// ```
     fn check_expr(&mut self, cx: &LateContext<'hir>, expr: &'hir hir::Expr<'_>) {
+        // Collecting the lints that are gated on the MSRV like:
+        // `self.msrv.meets_or_report(SOME_LINT, expr.span, msrvs::SOME_FEATURE)`
+        if let ExprKind::MethodCall(_, _, [lint_arg, ..], _) = expr.kind
+            && let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+            && match_def_path(cx, def_id, &paths::MSRV_MEETS_OR_REPORT)
+        {
+            self.msrv_gated.extend(resolve_lints(cx, lint_arg));
+        }
+
         if let Some(args) = match_lint_emission(cx, expr) {
             let emission_info = extract_emission_info(cx, args);
             if emission_info.is_empty() {
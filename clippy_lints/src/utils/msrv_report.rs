@@ -0,0 +1,27 @@
+use clippy_config::msrvs;
+use rustc_ast::Item;
+use rustc_hir as hir;
+use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_lint_pass!(
+    /// With `cargo clippy --msrv-report`, forgets the failed MSRV checks of a body or item once
+    /// every other pass has linted it. Registered after all the other passes.
+    ClearUnmetMsrvChecks => []
+);
+
+impl EarlyLintPass for ClearUnmetMsrvChecks {
+    fn check_item_post(&mut self, _: &EarlyContext<'_>, _: &Item) {
+        msrvs::clear_unmet();
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for ClearUnmetMsrvChecks {
+    fn check_body_post(&mut self, _: &LateContext<'tcx>, _: &'tcx hir::Body<'tcx>) {
+        msrvs::clear_unmet();
+    }
+
+    fn check_item_post(&mut self, _: &LateContext<'tcx>, _: &'tcx hir::Item<'tcx>) {
+        msrvs::clear_unmet();
+    }
+}
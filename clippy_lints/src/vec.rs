@@ -122,7 +122,9 @@ impl<'tcx> LateLintPass<'tcx> for UselessVec {
         // search for `for _ in vec![…]`
         if let Some(higher::ForLoop { arg, .. }) = higher::ForLoop::hir(expr)
             && let Some(vec_args) = higher::VecArgs::hir(cx, arg)
-            && self.msrv.meets(msrvs::ARRAY_INTO_ITERATOR)
+            && self
+                .msrv
+                .meets_or_report(USELESS_VEC, arg.span, msrvs::ARRAY_INTO_ITERATOR)
         {
             // report the error around the `vec!` not inside `<std macros>:`
             let span = arg.span.ctxt().outer_expn_data().call_site;
@@ -0,0 +1,115 @@
+//! A summary of the Clippy diagnostics of a run: the number of diagnostics of each lint, group and
+//! crate, the files with the most diagnostics and how many of them can be fixed automatically.
+//!
+//! It's printed by `cargo clippy --summary` and written by lintcheck.
+
+#![cfg_attr(feature = "deny-warnings", deny(warnings))]
+#![warn(rust_2018_idioms, unused_lifetimes)]
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::{self, Write as _};
+
+/// The number of files listed in the table
+const TOP_FILES: usize = 10;
+
+#[derive(Default)]
+pub struct Summary {
+    /// The number of diagnostics of each lint, e.g. `clippy::needless_borrow`
+    pub lints: HashMap<String, usize>,
+    pub groups: HashMap<String, usize>,
+    pub crates: HashMap<String, usize>,
+    pub files: HashMap<String, usize>,
+    /// The number of diagnostics with a `MachineApplicable` suggestion
+    pub fixable: usize,
+    pub total: usize,
+}
+
+#[derive(Serialize)]
+struct Count<'a> {
+    name: &'a str,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct JsonSummary<'a> {
+    total: usize,
+    fixable: usize,
+    not_fixable: usize,
+    lints: Vec<Count<'a>>,
+    groups: Vec<Count<'a>>,
+    crates: Vec<Count<'a>>,
+    files: Vec<Count<'a>>,
+}
+
+impl Summary {
+    /// Records a diagnostic of `lint`
+    pub fn add(&mut self, lint: &str, group: &str, krate: &str, file: &str, fixable: bool) {
+        for (counts, key) in [
+            (&mut self.lints, lint),
+            (&mut self.groups, group),
+            (&mut self.crates, krate),
+            (&mut self.files, file),
+        ] {
+            *counts.entry(key.to_owned()).or_default() += 1;
+        }
+        self.fixable += usize::from(fixable);
+        self.total += 1;
+    }
+
+    /// The summary as markdown tables, the lints first
+    #[must_use]
+    pub fn to_table(&self) -> String {
+        let mut table = String::new();
+        write_table(&mut table, "lint", &sorted(&self.lints));
+        table.push('\n');
+        write_table(&mut table, "group", &sorted(&self.groups));
+        table.push('\n');
+        write_table(&mut table, "crate", &sorted(&self.crates));
+        table.push('\n');
+        let files = sorted(&self.files);
+        write_table(&mut table, "file", &files[..files.len().min(TOP_FILES)]);
+        let _: fmt::Result = write!(
+            table,
+            "\n{} diagnostics, {} can be fixed automatically and {} can't\n",
+            self.total,
+            self.fixable,
+            self.total - self.fixable
+        );
+        table
+    }
+
+    /// The summary as JSON, the counts are sorted from the highest
+    ///
+    /// # Panics
+    ///
+    /// Never, the counts can always be serialized
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let summary = JsonSummary {
+            total: self.total,
+            fixable: self.fixable,
+            not_fixable: self.total - self.fixable,
+            lints: sorted(&self.lints),
+            groups: sorted(&self.groups),
+            crates: sorted(&self.crates),
+            files: sorted(&self.files),
+        };
+        serde_json::to_string_pretty(&summary).unwrap()
+    }
+}
+
+/// The counts from the highest, the ones that are equal by name
+fn sorted(counts: &HashMap<String, usize>) -> Vec<Count<'_>> {
+    let mut counts: Vec<Count<'_>> = counts.iter().map(|(name, &count)| Count { name, count }).collect();
+    counts.sort_by(|a, b| b.count.cmp(&a.count).then(a.name.cmp(b.name)));
+    counts
+}
+
+fn write_table(table: &mut String, column: &str, counts: &[Count<'_>]) {
+    let _: fmt::Result = writeln!(table, "| {column:<50} | count |");
+    let _: fmt::Result = writeln!(table, "| {:-<50} | ----- |", "");
+    for Count { name, count } in counts {
+        let _: fmt::Result = writeln!(table, "| {name:<50} | {count:>5} |");
+    }
+}
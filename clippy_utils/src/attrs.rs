@@ -42,6 +42,10 @@ impl LimitStack {
     pub fn limit(&self) -> u64 {
         *self.stack.last().expect("there should always be a value in the stack")
     }
+    /// Returns the limit set by the innermost attribute, if any
+    pub fn attr_limit(&self) -> Option<u64> {
+        self.stack[1..].last().copied()
+    }
     pub fn push_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
         let stack = &mut self.stack;
         parse_attrs(sess, attrs, name, |val| stack.push(val));
@@ -8,6 +8,7 @@
 //! Thank you!
 //! ~The `INTERNAL_METADATA_COLLECTOR` lint
 
+use clippy_config::msrvs::{self, MSRV_REPORT_NOTE};
 use rustc_errors::{Applicability, Diagnostic, MultiSpan};
 use rustc_hir::HirId;
 use rustc_lint::{LateContext, Lint, LintContext};
@@ -28,6 +29,16 @@ fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
     }
 }
 
+/// With `cargo clippy --msrv-report`, notes that the diagnostic would be suppressed by the MSRV,
+/// see [`msrvs::Msrv::meets_or_report`]
+fn msrv_report_note(diag: &mut Diagnostic, lint: &'static Lint) {
+    if msrvs::is_report_mode()
+        && let Some((msrv, required)) = msrvs::take_unmet(lint, diag.span.primary_spans())
+    {
+        diag.note(format!("{MSRV_REPORT_NOTE} `{msrv}`, it requires Rust `{required}`"));
+    }
+}
+
 /// Emit a basic lint message with a `msg` and a `span`.
 ///
 /// This is the most primitive of our lint emission methods and can
@@ -49,6 +60,7 @@ pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<Mult
     #[expect(clippy::disallowed_methods)]
     cx.struct_span_lint(lint, sp, msg.to_string(), |diag| {
         docs_link(diag, lint);
+        msrv_report_note(diag, lint);
         diag
     });
 }
@@ -90,6 +102,7 @@ pub fn span_lint_and_help<T: LintContext>(
             diag.help(help);
         }
         docs_link(diag, lint);
+        msrv_report_note(diag, lint);
         diag
     });
 }
@@ -134,6 +147,7 @@ pub fn span_lint_and_note<T: LintContext>(
             diag.note(note);
         }
         docs_link(diag, lint);
+        msrv_report_note(diag, lint);
         diag
     });
 }
@@ -152,6 +166,7 @@ where
     cx.struct_span_lint(lint, sp, msg.to_string(), |diag| {
         f(diag);
         docs_link(diag, lint);
+        msrv_report_note(diag, lint);
         diag
     });
 }
@@ -160,6 +175,7 @@ pub fn span_lint_hir(cx: &LateContext<'_>, lint: &'static Lint, hir_id: HirId, s
     #[expect(clippy::disallowed_methods)]
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, msg.to_string(), |diag| {
         docs_link(diag, lint);
+        msrv_report_note(diag, lint);
         diag
     });
 }
@@ -176,6 +192,7 @@ pub fn span_lint_hir_and_then(
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, msg.to_string(), |diag| {
         f(diag);
         docs_link(diag, lint);
+        msrv_report_note(diag, lint);
         diag
     });
 }
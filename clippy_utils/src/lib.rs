@@ -118,6 +118,7 @@ use crate::ty::{
     ty_is_fn_once_param,
 };
 use crate::visitors::for_each_expr;
+use clippy_config::types::DisallowedPath;
 
 use rustc_middle::hir::nested_filter;
 
@@ -681,6 +682,48 @@ pub fn def_path_def_ids(cx: &LateContext<'_>, path: &[&str]) -> impl Iterator<It
     def_path_res(cx, path).into_iter().filter_map(|res| res.opt_def_id())
 }
 
+/// Resolves the path of a [`DisallowedPath`] from the configuration, see [`def_path_res`].
+///
+/// If the path doesn't match any item it's reported against its entry in the configuration file,
+/// as an error if `error_on_unresolved` is set. This includes the paths that start with the name
+/// of a crate that is not in the dependency graph of the current crate, e.g. a misspelled `sdt`.
+pub fn disallowed_path_res(cx: &LateContext<'_>, disallowed: &DisallowedPath, error_on_unresolved: bool) -> Vec<Res> {
+    let path: Vec<_> = disallowed.path().split("::").collect();
+    let resolutions = def_path_res(cx, &path);
+    if resolutions.iter().any(|res| !matches!(res, Res::Err)) {
+        return resolutions;
+    }
+
+    let tcx = cx.tcx;
+    let mut msg = format!(
+        "`{}` in Clippy's configuration file does not refer to an existing item",
+        disallowed.path()
+    );
+    if let [base, _, ..] = *path {
+        let base_sym = Symbol::intern(base);
+        let base_exists = tcx.crate_name(LOCAL_CRATE) == base_sym
+            || tcx.crates(()).iter().any(|&num| tcx.crate_name(num) == base_sym)
+            || find_primitive_impls(tcx, base).next().is_some();
+        if !base_exists {
+            msg = format!("{msg}, `{base}` is not a crate in the dependency graph");
+        }
+    }
+
+    let span = disallowed.span();
+    if !error_on_unresolved {
+        if span.is_dummy() {
+            tcx.sess.warn(msg);
+        } else {
+            tcx.sess.span_warn(span, msg);
+        }
+    } else if span.is_dummy() {
+        tcx.sess.err(msg);
+    } else {
+        tcx.sess.span_err(span, msg);
+    }
+    resolutions
+}
+
 /// Convenience function to get the `DefId` of a trait by path.
 /// It could be a trait or trait alias.
 ///
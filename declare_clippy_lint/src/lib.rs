@@ -25,6 +25,7 @@ fn parse_attr<const LEN: usize>(path: [&'static str; LEN], attr: &Attribute) ->
 struct ClippyLint {
     attrs: Vec<Attribute>,
     explanation: String,
+    version: Option<LitStr>,
     name: Ident,
     category: Ident,
     description: LitStr,
@@ -70,6 +71,7 @@ impl Parse for ClippyLint {
         Ok(Self {
             attrs,
             explanation,
+            version,
             name,
             category,
             description,
@@ -125,6 +127,7 @@ pub fn declare_clippy_lint(input: TokenStream) -> TokenStream {
     let ClippyLint {
         attrs,
         explanation,
+        version,
         name,
         category,
         description,
@@ -143,6 +146,10 @@ pub fn declare_clippy_lint(input: TokenStream) -> TokenStream {
     );
 
     let info_name = format_ident!("{name}_INFO");
+    let version = match version {
+        Some(version) => quote!(Some(#version)),
+        None => quote!(None),
+    };
 
     (&mut category[0..1]).make_ascii_uppercase();
     let category_variant = format_ident!("{category}");
@@ -160,6 +167,7 @@ pub fn declare_clippy_lint(input: TokenStream) -> TokenStream {
             lint: &#name,
             category: crate::LintCategory::#category_variant,
             explanation: #explanation,
+            version: #version,
         };
     };
 
@@ -3,6 +3,7 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
 #[derive(Clone, Debug, Parser)]
+#[allow(clippy::struct_excessive_bools)]
 pub(crate) struct LintcheckConfig {
     /// Number of threads to use (default: all unless --fix or --recursive)
     #[clap(
@@ -41,6 +42,36 @@ pub(crate) struct LintcheckConfig {
     /// Run clippy on the dependencies of crates specified in crates-toml
     #[clap(long, conflicts_with("max_jobs"))]
     pub recursive: bool,
+    /// Compare the warnings of this old `clippy-driver` with the ones of the new one, and write the
+    /// added, removed and changed warnings as markdown and JSON
+    #[clap(long, value_name = "OLD_CLIPPY_DRIVER", conflicts_with_all(["fix", "recursive"]))]
+    pub diff: Option<PathBuf>,
+    /// The new `clippy-driver` for `--diff`, defaults to the one built from this repo
+    #[clap(long, value_name = "NEW_CLIPPY_DRIVER", requires("diff"))]
+    pub diff_new: Option<PathBuf>,
+    /// File to save the diff of `--diff` to, without extension
+    #[clap(skip = "")]
+    pub diff_results_path: PathBuf, // Overridden in new()
+    /// Read the crates.io crates from this `cargo vendor` directory or local registry instead of
+    /// downloading them, and run cargo with `--offline`
+    #[clap(long, value_name = "PATH", env = "LINTCHECK_REGISTRY", hide_env = true)]
+    pub registry: Option<PathBuf>,
+    /// Record the time spent in each lint pass and report the slowest ones
+    #[clap(long, conflicts_with_all(["fix", "recursive", "diff"]))]
+    pub profile: bool,
+    /// File to save the lint pass timings of `--profile` to
+    #[clap(skip = "")]
+    pub profile_results_path: PathBuf, // Overridden in new()
+    /// Apply the `MachineApplicable` suggestions of each lint on its own, then check that the
+    /// crates still compile and that their tests still pass
+    #[clap(long, conflicts_with_all(["fix", "recursive", "diff", "profile"]))]
+    pub verify_fixes: bool,
+    /// File to save the report of `--verify-fixes` to, without extension
+    #[clap(skip = "")]
+    pub verify_fixes_results_path: PathBuf, // Overridden in new()
+    /// File to save the summary of the warnings to as JSON
+    #[clap(skip = "")]
+    pub summary_results_path: PathBuf, // Overridden in new()
 }
 
 impl LintcheckConfig {
@@ -55,10 +86,15 @@ impl LintcheckConfig {
             filename.display(),
             if config.markdown { "md" } else { "txt" }
         ));
+        config.diff_results_path = PathBuf::from(format!("lintcheck-logs/{}_diff", filename.display()));
+        config.profile_results_path = PathBuf::from(format!("lintcheck-logs/{}_profile.json", filename.display()));
+        config.verify_fixes_results_path =
+            PathBuf::from(format!("lintcheck-logs/{}_fix_verification", filename.display()));
+        config.summary_results_path = PathBuf::from(format!("lintcheck-logs/{}_summary.json", filename.display()));
 
         // look at the --threads arg, if 0 is passed, use the threads count
         if config.max_jobs == 0 {
-            config.max_jobs = if config.fix || config.recursive {
+            config.max_jobs = if config.fix || config.recursive || config.profile {
                 1
             } else {
                 std::thread::available_parallelism().map_or(1, NonZeroUsize::get)
@@ -0,0 +1,197 @@
+//! In `--diff` mode the crates are checked with two `clippy-driver`s, an old and a new one, and the
+//! warnings they issue are compared one by one. Warnings are matched by their crate, position and
+//! lint, a matched warning whose message or suggestions differ is reported as changed.
+
+use crate::config::LintcheckConfig;
+use crate::{clippy_project_root, ClippyWarning, Crate};
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use rayon::prelude::*;
+use serde::Serialize;
+
+/// The `clippy-driver` a side of the diff was produced with
+#[derive(Serialize)]
+struct Driver {
+    path: String,
+    version: String,
+}
+
+impl Driver {
+    fn new(path: &Path) -> Self {
+        let version = Command::new(path).arg("--version").output().map_or_else(
+            |_| String::from("unknown"),
+            |o| String::from_utf8_lossy(&o.stdout).trim().to_owned(),
+        );
+
+        Self {
+            path: path.display().to_string(),
+            version,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Changed<'a> {
+    old: &'a ClippyWarning,
+    new: &'a ClippyWarning,
+}
+
+#[derive(Serialize)]
+struct Diff<'a> {
+    old: Driver,
+    new: Driver,
+    added: Vec<&'a ClippyWarning>,
+    removed: Vec<&'a ClippyWarning>,
+    changed: Vec<Changed<'a>>,
+}
+
+impl<'a> Diff<'a> {
+    fn new(old: Driver, new: Driver, old_warnings: &'a [ClippyWarning], new_warnings: &'a [ClippyWarning]) -> Self {
+        type Key<'a> = (&'a str, &'a str, usize, usize, &'a str);
+        fn group(warnings: &[ClippyWarning]) -> BTreeMap<Key<'_>, Vec<&ClippyWarning>> {
+            let mut map: BTreeMap<Key<'_>, Vec<&ClippyWarning>> = BTreeMap::new();
+            for warning in warnings {
+                let key = (
+                    warning.crate_name.as_str(),
+                    warning.file.as_str(),
+                    warning.line,
+                    warning.column,
+                    warning.lint_type.as_str(),
+                );
+                map.entry(key).or_default().push(warning);
+            }
+            map
+        }
+
+        let mut diff = Self {
+            old,
+            new,
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+        };
+
+        let mut old_map = group(old_warnings);
+        let mut new_map = group(new_warnings);
+        let mut keys: Vec<_> = old_map.keys().chain(new_map.keys()).copied().collect();
+        keys.sort_unstable();
+        keys.dedup();
+
+        for key in keys {
+            let mut old = old_map.remove(&key).unwrap_or_default();
+            let mut new = new_map.remove(&key).unwrap_or_default();
+
+            // warnings that are the same in both runs are not part of the diff
+            old.retain(|old| {
+                if let Some(i) = new
+                    .iter()
+                    .position(|new| new.message == old.message && new.suggestions == old.suggestions)
+                {
+                    new.remove(i);
+                    false
+                } else {
+                    true
+                }
+            });
+
+            let mut old = old.into_iter();
+            let mut new = new.into_iter();
+            loop {
+                match (old.next(), new.next()) {
+                    (Some(old), Some(new)) => diff.changed.push(Changed { old, new }),
+                    (Some(old), None) => diff.removed.push(old),
+                    (None, Some(new)) => diff.added.push(new),
+                    (None, None) => break,
+                }
+            }
+        }
+
+        diff
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut text = String::from("# Lintcheck diff\n\n");
+        let _: fmt::Result = writeln!(text, "- old: `{}` (`{}`)", self.old.version, self.old.path);
+        let _: fmt::Result = writeln!(text, "- new: `{}` (`{}`)", self.new.version, self.new.path);
+
+        for (title, warnings) in [("Added", &self.added), ("Removed", &self.removed)] {
+            let _: fmt::Result = writeln!(text, "\n## {title} ({})", warnings.len());
+            for warning in warnings {
+                write_heading(&mut text, warning);
+                write_snippet(&mut text, warning);
+            }
+        }
+
+        let _: fmt::Result = writeln!(text, "\n## Changed ({})", self.changed.len());
+        for Changed { old, new } in &self.changed {
+            write_heading(&mut text, old);
+            text.push_str("\nOld:\n");
+            write_snippet(&mut text, old);
+            text.push_str("\nNew:\n");
+            write_snippet(&mut text, new);
+        }
+
+        text
+    }
+}
+
+fn write_heading(text: &mut String, warning: &ClippyWarning) {
+    let _: fmt::Result = writeln!(
+        text,
+        "\n### `{}` at `{}:{}:{}`",
+        warning.lint_type, warning.file, warning.line, warning.column
+    );
+}
+
+fn write_snippet(text: &mut String, warning: &ClippyWarning) {
+    let _: fmt::Result = writeln!(text, "\n```text\n{}\n```", warning.rendered.trim_end());
+}
+
+/// Checks `crates` with both `clippy-driver`s and writes the differences between their warnings to
+/// `config.diff_results_path` as markdown and JSON
+pub(crate) fn run(config: &LintcheckConfig, crates: &[Crate], lint_filter: &[String], old: &Path, new: &Path) {
+    let old = fs::canonicalize(old).unwrap_or_else(|_| panic!("{} not found", old.display()));
+    let new = fs::canonicalize(new).unwrap_or_else(|_| panic!("{} not found", new.display()));
+
+    let shared_target_dir = clippy_project_root().join("target/lintcheck/shared_target_dir");
+    let (old_warnings, new_warnings): (Vec<_>, Vec<_>) = crates
+        .par_iter()
+        .map(|krate| {
+            println!("Linting {} {}", krate.name, krate.version);
+            // the drivers use separate target dirs, so that the warnings of the other one aren't
+            // replayed from the cache
+            let target_dir = shared_target_dir.join(format!("diff_{}", rayon::current_thread_index().unwrap_or(0)));
+            (
+                krate.run_clippy_driver(&old, &target_dir.join("old"), lint_filter),
+                krate.run_clippy_driver(&new, &target_dir.join("new"), lint_filter),
+            )
+        })
+        .unzip();
+    let old_warnings: Vec<ClippyWarning> = old_warnings.into_iter().flatten().collect();
+    let new_warnings: Vec<ClippyWarning> = new_warnings.into_iter().flatten().collect();
+
+    let diff = Diff::new(Driver::new(&old), Driver::new(&new), &old_warnings, &new_warnings);
+
+    let markdown_path = config.diff_results_path.with_extension("md");
+    let json_path = config.diff_results_path.with_extension("json");
+    println!(
+        "Writing the diff to {} and {}",
+        markdown_path.display(),
+        json_path.display()
+    );
+    fs::create_dir_all(markdown_path.parent().unwrap()).unwrap();
+    fs::write(markdown_path, diff.to_markdown()).unwrap();
+    fs::write(json_path, serde_json::to_string_pretty(&diff).unwrap()).unwrap();
+
+    println!(
+        "\nDiff: {} added, {} removed, {} changed",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len()
+    );
+}
@@ -0,0 +1,297 @@
+//! When Clippy crashes with an internal compiler error (ICE) on a crate, a bundle to reproduce it
+//! is written to `lintcheck-logs/ices/<crate>-<version>`. It contains:
+//!
+//! - `crate/`: the sources of the crate
+//! - `stderr.txt`: the output of cargo, including the panic message and backtrace
+//! - `command.txt`: the `clippy-driver` command lines run by cargo and the `CLIPPY_ARGS`
+//! - `clippy.toml`: the configuration file that was used, if any
+//! - `repro.rs`: if a file of the crate also crashes Clippy on its own, that file reduced to the
+//!   top-level items that are needed for the crash
+//! - `README.md`: how to reproduce the crash
+
+use crate::{copy_dir, Crate};
+
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The lines rustc prints when it crashes
+const ICE_MARKERS: [&str; 2] = [
+    "error: the compiler unexpectedly panicked",
+    "error: internal compiler error",
+];
+
+/// The number of files of the crate that are tried as a single file repro
+const MAX_CANDIDATES: usize = 5;
+
+/// Whether the stderr of a cargo or rustc run contains an ICE
+pub(crate) fn is_ice(stderr: &str) -> bool {
+    stderr
+        .lines()
+        .any(|line| ICE_MARKERS.iter().any(|marker| line.starts_with(marker)))
+}
+
+/// Writes a reproduction bundle for the ICE of `krate`
+pub(crate) fn write_bundle(krate: &Crate, clippy_driver_path: &Path, clippy_args: &[&str], stderr: &str) {
+    let bundle = PathBuf::from(format!("lintcheck-logs/ices/{}-{}", krate.name, krate.version));
+    let _: std::io::Result<()> = fs::remove_dir_all(&bundle);
+    fs::create_dir_all(&bundle).unwrap();
+
+    let crate_path = fs::canonicalize(&krate.path).unwrap();
+    copy_dir(&crate_path, &bundle.join("crate"));
+    fs::write(bundle.join("stderr.txt"), stderr).unwrap();
+
+    // `cargo -v` prints the commands it runs, with the environment variables it sets
+    let mut command = String::new();
+    for running in stderr
+        .lines()
+        .filter_map(|line| line.trim_start().strip_prefix("Running `"))
+        .filter(|running| running.contains("clippy-driver"))
+    {
+        let _: fmt::Result = writeln!(command, "{}\n", running.trim_end_matches('`'));
+    }
+    let _: fmt::Result = writeln!(command, "CLIPPY_ARGS={}", clippy_args.join("__CLIPPY_HACKERY__"));
+    fs::write(bundle.join("command.txt"), command).unwrap();
+
+    let conf = find_conf(&crate_path);
+    if let Some(conf) = &conf {
+        fs::copy(conf, bundle.join("clippy.toml")).unwrap();
+    }
+
+    let reducer = Reducer {
+        clippy_driver_path,
+        clippy_args,
+        edition: edition(&crate_path),
+        conf_dir: conf.as_deref().and_then(Path::parent),
+        panic_location: panic_location(stderr),
+        dir: &bundle,
+    };
+    let repro = candidates(&crate_path, stderr)
+        .into_iter()
+        .take(MAX_CANDIDATES)
+        .find_map(|file| reducer.reduce(&fs::read_to_string(file).ok()?));
+    if let Some(repro) = &repro {
+        fs::write(bundle.join("repro.rs"), repro).unwrap();
+    } else {
+        let _: std::io::Result<()> = fs::remove_file(bundle.join("repro.rs"));
+    }
+
+    fs::write(
+        bundle.join("README.md"),
+        readme(krate, &reducer, stderr, repro.is_some()),
+    )
+    .unwrap();
+
+    println!(
+        "ICE in {} {}, wrote a reproduction bundle to {}",
+        krate.name,
+        krate.version,
+        bundle.display()
+    );
+}
+
+/// The location in the panic message, e.g. `clippy_lints/src/foo.rs:12:34`, to check that a
+/// reduced file crashes in the same way
+fn panic_location(stderr: &str) -> Option<&str> {
+    stderr.lines().find_map(|line| {
+        line.strip_prefix("thread 'rustc' panicked at ")
+            .map(|location| location.trim_end_matches(':'))
+    })
+}
+
+/// The configuration file used for the crate, the same lookup as the one of `clippy-driver`
+fn find_conf(crate_path: &Path) -> Option<PathBuf> {
+    crate_path.ancestors().find_map(|dir| {
+        [".clippy.toml", "clippy.toml"]
+            .into_iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+    })
+}
+
+fn edition(crate_path: &Path) -> String {
+    fs::read_to_string(crate_path.join("Cargo.toml"))
+        .ok()
+        .and_then(|manifest| manifest.parse::<toml::Value>().ok())
+        .and_then(|manifest| Some(manifest.get("package")?.get("edition")?.as_str()?.to_owned()))
+        .unwrap_or_else(|| String::from("2015"))
+}
+
+/// The files to try as a single file repro: the ones the diagnostics point at, then the crate
+/// roots
+fn candidates(crate_path: &Path, stderr: &str) -> Vec<PathBuf> {
+    let mut candidates: Vec<PathBuf> = stderr
+        .lines()
+        .filter_map(|line| line.trim_start().strip_prefix("--> "))
+        .filter_map(|location| location.split(':').next())
+        .map(|file| crate_path.join(file))
+        .chain([crate_path.join("src/lib.rs"), crate_path.join("src/main.rs")])
+        .filter(|file| file.is_file())
+        .collect();
+    let mut seen = Vec::new();
+    candidates.retain(|file| {
+        let new = !seen.contains(file);
+        seen.push(file.clone());
+        new
+    });
+    candidates
+}
+
+struct Reducer<'a> {
+    clippy_driver_path: &'a Path,
+    clippy_args: &'a [&'a str],
+    edition: String,
+    conf_dir: Option<&'a Path>,
+    panic_location: Option<&'a str>,
+    /// The directory the files are checked in
+    dir: &'a Path,
+}
+
+impl Reducer<'_> {
+    /// The command to check `file` with `clippy-driver`
+    fn command(&self, file: &str) -> Command {
+        let mut command = Command::new(self.clippy_driver_path);
+        command
+            .args(["--edition", &self.edition, "--crate-type", "lib", "--emit=metadata"])
+            .args(["-o", "repro.rmeta", file])
+            .args(self.clippy_args)
+            .current_dir(self.dir)
+            // don't write a `rustc-ice-*.txt` file for every crash while reducing
+            .env("RUSTC_ICE", "0");
+        if let Some(conf_dir) = self.conf_dir {
+            command.env("CLIPPY_CONF_DIR", conf_dir);
+        }
+        command
+    }
+
+    /// Whether `source` crashes Clippy in the same way as the crate
+    fn crashes(&self, source: &str) -> bool {
+        fs::write(self.dir.join("repro.rs"), source).unwrap();
+        let Ok(output) = self.command("repro.rs").output() else {
+            return false;
+        };
+        let _: std::io::Result<()> = fs::remove_file(self.dir.join("repro.rmeta"));
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        is_ice(&stderr) && (self.panic_location.is_none() || panic_location(&stderr) == self.panic_location)
+    }
+
+    /// Removes the top-level items of `source` that are not needed for the crash, in chunks that
+    /// get smaller each round. Returns `None` if `source` doesn't crash on its own.
+    fn reduce(&self, source: &str) -> Option<String> {
+        if !self.crashes(source) {
+            return None;
+        }
+
+        let mut items = split_items(source);
+        let mut chunk_size = items.len() / 2;
+        while chunk_size > 0 {
+            let mut start = 0;
+            while start < items.len() {
+                let end = (start + chunk_size).min(items.len());
+                let candidate: String = items[..start].iter().chain(&items[end..]).map(String::as_str).collect();
+                if self.crashes(&candidate) {
+                    items.drain(start..end);
+                } else {
+                    start = end;
+                }
+            }
+            chunk_size /= 2;
+        }
+
+        Some(items.concat())
+    }
+}
+
+/// Splits `source` into its top-level items, each with the attributes, comments and whitespace
+/// before it. Strings and comments are skipped roughly, this only needs to be good enough for the
+/// usual code.
+fn split_items(source: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut chars = source.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '/' if chars.next_if(|&(_, c)| c == '/').is_some() => while chars.next_if(|&(_, c)| c != '\n').is_some() {},
+            '/' if chars.next_if(|&(_, c)| c == '*').is_some() => {
+                let mut prev = ' ';
+                for (_, c) in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            },
+            '"' => {
+                let mut escaped = false;
+                for (_, c) in chars.by_ref() {
+                    match c {
+                        '\\' if !escaped => escaped = true,
+                        '"' if !escaped => break,
+                        _ => escaped = false,
+                    }
+                }
+            },
+            '{' | '(' | '[' => depth += 1,
+            '}' | ')' | ']' => depth = depth.saturating_sub(1),
+            _ => {},
+        }
+
+        if depth == 0 && matches!(c, '}' | ';') {
+            // keep the rest of the line, e.g. a trailing comment, with the item
+            let end = source[i..].find('\n').map_or(source.len(), |n| i + n + 1);
+            while chars.next_if(|&(j, _)| j < end).is_some() {}
+            items.push(source[start..end].to_owned());
+            start = end;
+        }
+    }
+
+    if start < source.len() {
+        items.push(source[start..].to_owned());
+    }
+    items
+}
+
+fn readme(krate: &Crate, reducer: &Reducer<'_>, stderr: &str, has_repro: bool) -> String {
+    let panic: Vec<&str> = stderr
+        .lines()
+        .skip_while(|line| !line.starts_with("thread 'rustc' panicked"))
+        .take_while(|line| !line.starts_with("stack backtrace"))
+        .collect();
+
+    let mut readme = format!("# ICE in {} {}\n\n", krate.name, krate.version);
+    if !panic.is_empty() {
+        let _: fmt::Result = writeln!(readme, "```text\n{}\n```\n", panic.join("\n"));
+    }
+    let _: fmt::Result = writeln!(
+        readme,
+        "The output of cargo is in `stderr.txt`, the commands it ran in `command.txt`.\n"
+    );
+
+    let _: fmt::Result = writeln!(
+        readme,
+        "To reproduce it with the crate, run in `crate/`:\n\n```sh\ncargo clippy -- {}\n```\n",
+        reducer.clippy_args.join(" ")
+    );
+
+    if has_repro {
+        let command = reducer.command("repro.rs");
+        let args: Vec<_> = command.get_args().map(|arg| arg.to_string_lossy()).collect();
+        let conf = if reducer.conf_dir.is_some() {
+            "CLIPPY_CONF_DIR=. "
+        } else {
+            ""
+        };
+        let _: fmt::Result = writeln!(
+            readme,
+            "`repro.rs` reproduces it on its own:\n\n```sh\n{conf}clippy-driver {}\n```",
+            args.join(" ")
+        );
+    } else {
+        readme.push_str("No file of the crate reproduces it on its own.\n");
+    }
+    readme
+}
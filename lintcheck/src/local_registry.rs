@@ -0,0 +1,85 @@
+//! Crates can be read from a `cargo vendor` directory or a local registry (e.g. one made by
+//! `cargo local-registry`) instead of crates.io, so that lintcheck works without network access.
+//!
+//! Cargo is then run with `--offline`, and crates.io is replaced by the local registry so that the
+//! dependencies of the crates are read from it as well.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub(crate) enum LocalRegistry {
+    /// A `cargo vendor` directory, the crates are directories named `name` or `name-version`
+    Vendor(PathBuf),
+    /// A local registry, it has an `index` directory and the crates are `name-version.crate`
+    /// archives
+    Registry(PathBuf),
+}
+
+impl LocalRegistry {
+    pub fn new(path: &Path) -> Self {
+        let path = fs::canonicalize(path).unwrap_or_else(|e| panic!("Failed to find {}: {e}", path.display()));
+        if path.join("index").is_dir() {
+            Self::Registry(path)
+        } else {
+            Self::Vendor(path)
+        }
+    }
+
+    /// Makes the sources of `name` `version` available in `extract_dir`, returns the path to the
+    /// crate root
+    pub fn extract(&self, name: &str, version: &str, extract_dir: &Path) -> PathBuf {
+        let dest = extract_dir.join(format!("{name}-{version}"));
+        if dest.is_dir() {
+            return dest;
+        }
+
+        match self {
+            Self::Vendor(path) => {
+                // `cargo vendor` only adds the version to the directory name if there are multiple
+                // versions of a crate, or with `--versioned-dirs`
+                let src = [path.join(format!("{name}-{version}")), path.join(name)]
+                    .into_iter()
+                    .find(|dir| vendored_version(dir).as_deref() == Some(version))
+                    .unwrap_or_else(|| panic!("{name} {version} is not vendored in {}", path.display()));
+                println!("Copying {name} {version} from {}", src.display());
+                crate::copy_dir(&src, &dest);
+            },
+            Self::Registry(path) => {
+                let archive = path.join(format!("{name}-{version}.crate"));
+                assert!(
+                    archive.is_file(),
+                    "{name} {version} is not in the local registry {}",
+                    path.display()
+                );
+                println!("Extracting {name} {version} from {}", archive.display());
+                crate::extract_crate_archive(&archive, extract_dir);
+            },
+        }
+
+        dest
+    }
+
+    /// The arguments that make cargo read crates.io crates from the local registry
+    pub fn cargo_args(&self) -> Vec<String> {
+        let (kind, path) = match self {
+            Self::Vendor(path) => ("directory", path),
+            Self::Registry(path) => ("local-registry", path),
+        };
+        let path = toml::Value::String(path.display().to_string());
+
+        vec![
+            String::from("--offline"),
+            String::from("--config"),
+            String::from("source.crates-io.replace-with='lintcheck-local'"),
+            String::from("--config"),
+            format!("source.lintcheck-local.{kind}={path}"),
+        ]
+    }
+}
+
+/// The version in the `Cargo.toml` of a vendored crate
+fn vendored_version(dir: &Path) -> Option<String> {
+    let manifest: toml::Value = fs::read_to_string(dir.join("Cargo.toml")).ok()?.parse().ok()?;
+    Some(manifest.get("package")?.get("version")?.as_str()?.to_owned())
+}
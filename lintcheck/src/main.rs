@@ -8,11 +8,18 @@
 #![allow(clippy::collapsible_else_if)]
 
 mod config;
+mod diff;
 mod driver;
+mod ice;
+mod local_registry;
+mod profile;
 mod recursive;
+mod verify_fixes;
 
 use crate::config::LintcheckConfig;
+use crate::local_registry::LocalRegistry;
 use crate::recursive::LintcheckServer;
+use clippy_summary::Summary;
 
 use std::collections::{HashMap, HashSet};
 use std::env::consts::EXE_SUFFIX;
@@ -24,7 +31,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use std::{env, fs, thread};
 
-use cargo_metadata::diagnostic::{Diagnostic, DiagnosticLevel};
+use cargo_metadata::diagnostic::{Applicability, Diagnostic, DiagnosticLevel};
 use cargo_metadata::Message;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -55,6 +62,7 @@ struct TomlCrate {
     git_url: Option<String>,
     git_hash: Option<String>,
     path: Option<String>,
+    registry: Option<String>,
     options: Option<Vec<String>>,
 }
 
@@ -78,6 +86,13 @@ enum CrateSource {
         path: PathBuf,
         options: Option<Vec<String>>,
     },
+    /// A crates.io crate read from a `cargo vendor` directory or a local registry
+    Registry {
+        name: String,
+        version: String,
+        registry: PathBuf,
+        options: Option<Vec<String>>,
+    },
 }
 
 /// Represents the actual source code of a crate that we ran "cargo clippy" on
@@ -88,10 +103,12 @@ struct Crate {
     // path to the extracted sources that clippy can check
     path: PathBuf,
     options: Option<Vec<String>>,
+    // the local registry cargo reads the crates.io dependencies from
+    registry: Option<LocalRegistry>,
 }
 
 /// A single warning that clippy issued while checking a `Crate`
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 struct ClippyWarning {
     crate_name: String,
     file: String,
@@ -100,6 +117,12 @@ struct ClippyWarning {
     lint_type: String,
     message: String,
     is_ice: bool,
+    /// The replacements suggested by the warning
+    suggestions: Vec<String>,
+    /// Whether a suggestion is `MachineApplicable`, so that `cargo clippy --fix` applies it
+    machine_applicable: bool,
+    /// The warning as printed by rustc, with the source snippet
+    rendered: String,
 }
 
 #[allow(unused)]
@@ -113,6 +136,17 @@ impl ClippyWarning {
         }
 
         let span = diag.spans.into_iter().find(|span| span.is_primary)?;
+        let suggestions = diag
+            .children
+            .iter()
+            .flat_map(|child| &child.spans)
+            .filter_map(|span| span.suggested_replacement.clone())
+            .collect();
+        let machine_applicable = diag
+            .children
+            .iter()
+            .flat_map(|child| &child.spans)
+            .any(|span| span.suggestion_applicability == Some(Applicability::MachineApplicable));
 
         let file = if let Ok(stripped) = Path::new(&span.file_name).strip_prefix(env!("CARGO_HOME")) {
             format!("$CARGO_HOME/{}", stripped.display())
@@ -131,6 +165,9 @@ impl ClippyWarning {
             lint_type,
             message: diag.message,
             is_ice: diag.level == DiagnosticLevel::Ice,
+            suggestions,
+            machine_applicable,
+            rendered: diag.rendered.unwrap_or_default(),
         })
     }
 
@@ -194,11 +231,7 @@ impl CrateSource {
                     // copy the crate into the file
                     std::io::copy(&mut krate_req, &mut krate_dest).unwrap();
 
-                    // unzip the tarball
-                    let ungz_tar = flate2::read::GzDecoder::new(std::fs::File::open(&krate_file_path).unwrap());
-                    // extract the tar archive
-                    let mut archive = tar::Archive::new(ungz_tar);
-                    archive.unpack(&extract_dir).expect("Failed to extract!");
+                    extract_crate_archive(&krate_file_path, &extract_dir);
                 }
                 // crate is extracted, return a new Krate object which contains the path to the extracted
                 // sources that clippy can check
@@ -207,6 +240,25 @@ impl CrateSource {
                     name: name.clone(),
                     path: extract_dir.join(format!("{name}-{version}/")),
                     options: options.clone(),
+                    registry: None,
+                }
+            },
+            CrateSource::Registry {
+                name,
+                version,
+                registry,
+                options,
+            } => {
+                let extract_dir = PathBuf::from(LINTCHECK_SOURCES);
+                create_dirs(&PathBuf::from(LINTCHECK_DOWNLOADS), &extract_dir);
+
+                let registry = LocalRegistry::new(registry);
+                Crate {
+                    version: version.clone(),
+                    name: name.clone(),
+                    path: registry.extract(name, version, &extract_dir),
+                    options: options.clone(),
+                    registry: Some(registry),
                 }
             },
             CrateSource::Git {
@@ -253,18 +305,10 @@ impl CrateSource {
                     name: name.clone(),
                     path: repo_path,
                     options: options.clone(),
+                    registry: None,
                 }
             },
             CrateSource::Path { name, path, options } => {
-                fn is_cache_dir(entry: &DirEntry) -> bool {
-                    std::fs::read(entry.path().join("CACHEDIR.TAG"))
-                        .map(|x| x.starts_with(b"Signature: 8a477f597d28d172789f06886806bc55"))
-                        .unwrap_or(false)
-                }
-
-                // copy path into the dest_crate_root but skip directories that contain a CACHEDIR.TAG file.
-                // The target/ directory contains a CACHEDIR.TAG file so it is the most commonly skipped directory
-                // as a result of this filter.
                 let dest_crate_root = PathBuf::from(LINTCHECK_SOURCES).join(name);
                 if dest_crate_root.exists() {
                     println!("Deleting existing directory at {dest_crate_root:?}");
@@ -272,36 +316,66 @@ impl CrateSource {
                 }
 
                 println!("Copying {path:?} to {dest_crate_root:?}");
-
-                for entry in WalkDir::new(path).into_iter().filter_entry(|e| !is_cache_dir(e)) {
-                    let entry = entry.unwrap();
-                    let entry_path = entry.path();
-                    let relative_entry_path = entry_path.strip_prefix(path).unwrap();
-                    let dest_path = dest_crate_root.join(relative_entry_path);
-                    let metadata = entry_path.symlink_metadata().unwrap();
-
-                    if metadata.is_dir() {
-                        std::fs::create_dir(dest_path).unwrap();
-                    } else if metadata.is_file() {
-                        std::fs::copy(entry_path, dest_path).unwrap();
-                    }
-                }
+                copy_dir(path, &dest_crate_root);
 
                 Crate {
                     version: String::from("local"),
                     name: name.clone(),
                     path: dest_crate_root,
                     options: options.clone(),
+                    registry: None,
                 }
             },
         }
     }
 }
 
+/// Extracts a `.crate` archive, which contains a `name-version` directory, into `extract_dir`
+fn extract_crate_archive(archive_path: &Path, extract_dir: &Path) {
+    // unzip the tarball
+    let ungz_tar = flate2::read::GzDecoder::new(std::fs::File::open(archive_path).unwrap());
+    // extract the tar archive
+    let mut archive = tar::Archive::new(ungz_tar);
+    archive.unpack(extract_dir).expect("Failed to extract!");
+}
+
+/// Copies the directory `src` to `dest`, skipping the directories that contain a CACHEDIR.TAG file
+fn copy_dir(src: &Path, dest: &Path) {
+    fn is_cache_dir(entry: &DirEntry) -> bool {
+        std::fs::read(entry.path().join("CACHEDIR.TAG"))
+            .map(|x| x.starts_with(b"Signature: 8a477f597d28d172789f06886806bc55"))
+            .unwrap_or(false)
+    }
+
+    // The target/ directory contains a CACHEDIR.TAG file so it is the most commonly skipped directory
+    // as a result of this filter.
+    for entry in WalkDir::new(src).into_iter().filter_entry(|e| !is_cache_dir(e)) {
+        let entry = entry.unwrap();
+        let entry_path = entry.path();
+        let relative_entry_path = entry_path.strip_prefix(src).unwrap();
+        let dest_path = dest.join(relative_entry_path);
+        let metadata = entry_path.symlink_metadata().unwrap();
+
+        if metadata.is_dir() {
+            std::fs::create_dir(dest_path).unwrap();
+        } else if metadata.is_file() {
+            std::fs::copy(entry_path, dest_path).unwrap();
+        }
+    }
+}
+
 impl Crate {
+    /// The arguments passed to cargo to read the crates.io crates from the local registry, if any
+    fn cargo_args(&self) -> Vec<String> {
+        self.registry
+            .as_ref()
+            .map(LocalRegistry::cargo_args)
+            .unwrap_or_default()
+    }
+
     /// Run `cargo clippy` on the `Crate` and collect and return all the lint warnings that clippy
     /// issued
-    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
     fn run_clippy_lints(
         &self,
         cargo_clippy_path: &Path,
@@ -340,21 +414,13 @@ impl Crate {
             vec!["--", "--message-format=json", "--"]
         };
 
-        let mut clippy_args = Vec::<&str>::new();
-        if let Some(options) = &self.options {
-            for opt in options {
-                clippy_args.push(opt);
-            }
-        } else {
-            clippy_args.extend(["-Wclippy::pedantic", "-Wclippy::cargo"]);
-        }
-
-        if lint_filter.is_empty() {
-            clippy_args.push("--cap-lints=warn");
-        } else {
-            clippy_args.push("--cap-lints=allow");
-            clippy_args.extend(lint_filter.iter().map(std::string::String::as_str));
-        }
+        let clippy_args = self.clippy_args(lint_filter);
+        let cargo_args = self.cargo_args();
+        // the cargo arguments go before the `--` that separates them from the clippy arguments
+        cargo_clippy_args.splice(
+            cargo_clippy_args.len() - 1..cargo_clippy_args.len() - 1,
+            cargo_args.iter().map(String::as_str),
+        );
 
         if let Some(server) = server {
             let target = shared_target_dir.join("recursive");
@@ -370,6 +436,7 @@ impl Crate {
             let status = Command::new("cargo")
                 .arg("check")
                 .arg("--quiet")
+                .args(&cargo_args)
                 .current_dir(&self.path)
                 .env("CLIPPY_ARGS", clippy_args.join("__CLIPPY_HACKERY__"))
                 .env("CARGO_TARGET_DIR", target)
@@ -386,21 +453,30 @@ impl Crate {
             return Vec::new();
         }
 
-        cargo_clippy_args.extend(clippy_args);
+        let cargo_args_end = cargo_clippy_args.len() - 1;
+        cargo_clippy_args.extend(&clippy_args);
 
-        let all_output = Command::new(&cargo_clippy_path)
-            // use the looping index to create individual target dirs
-            .env("CARGO_TARGET_DIR", shared_target_dir.join(format!("_{thread_index:?}")))
-            .args(&cargo_clippy_args)
-            .current_dir(&self.path)
-            .output()
-            .unwrap_or_else(|error| {
-                panic!(
-                    "Encountered error:\n{error:?}\ncargo_clippy_path: {}\ncrate path:{}\n",
-                    &cargo_clippy_path.display(),
-                    &self.path.display()
-                );
-            });
+        let run = |cargo_clippy_args: &[&str]| {
+            let mut cmd = Command::new(&cargo_clippy_path);
+            if config.profile {
+                cmd.env("CLIPPY_PASS_TIMINGS", profile::timings_dir(self))
+                    .env("CARGO_TARGET_DIR", profile::target_dir(thread_index));
+            } else {
+                // use the looping index to create individual target dirs
+                cmd.env("CARGO_TARGET_DIR", shared_target_dir.join(format!("_{thread_index:?}")));
+            }
+            cmd.args(cargo_clippy_args)
+                .current_dir(&self.path)
+                .output()
+                .unwrap_or_else(|error| {
+                    panic!(
+                        "Encountered error:\n{error:?}\ncargo_clippy_path: {}\ncrate path:{}\n",
+                        &cargo_clippy_path.display(),
+                        &self.path.display()
+                    );
+                })
+        };
+        let all_output = run(&cargo_clippy_args);
         let stdout = String::from_utf8_lossy(&all_output.stdout);
         let stderr = String::from_utf8_lossy(&all_output.stderr);
         let status = &all_output.status;
@@ -412,6 +488,15 @@ impl Crate {
             );
         }
 
+        if ice::is_ice(&stderr) {
+            // the reproduction bundle needs the `clippy-driver` commands, which cargo only prints
+            // with `-v`. The crate that crashed is checked again, the others are fresh by now.
+            cargo_clippy_args.insert(cargo_args_end, "-v");
+            let verbose_output = run(&cargo_clippy_args);
+            let verbose_stderr = String::from_utf8_lossy(&verbose_output.stderr);
+            ice::write_bundle(self, clippy_driver_path, &clippy_args, &verbose_stderr);
+        }
+
         if config.fix {
             if let Some(stderr) = stderr
                 .lines()
@@ -437,6 +522,62 @@ impl Crate {
 
         warnings
     }
+
+    /// Run `cargo check` on the `Crate` with the given `clippy-driver` as the compiler wrapper,
+    /// like `cargo clippy` does, and return all the lint warnings that clippy issued
+    fn run_clippy_driver(
+        &self,
+        clippy_driver_path: &Path,
+        target_dir: &Path,
+        lint_filter: &[String],
+    ) -> Vec<ClippyWarning> {
+        let output = Command::new("cargo")
+            .args(["check", "--quiet", "--message-format=json"])
+            .args(self.cargo_args())
+            .current_dir(&self.path)
+            .env("CLIPPY_ARGS", self.clippy_args(lint_filter).join("__CLIPPY_HACKERY__"))
+            .env("CARGO_TARGET_DIR", target_dir)
+            .env("RUSTC_WORKSPACE_WRAPPER", clippy_driver_path)
+            .output()
+            .expect("failed to run cargo");
+
+        if !output.status.success() {
+            eprintln!(
+                "\nWARNING: bad exit status after checking {} {} with {}\n",
+                self.name,
+                self.version,
+                clippy_driver_path.display()
+            );
+        }
+
+        Message::parse_stream(output.stdout.as_slice())
+            .filter_map(|msg| match msg {
+                Ok(Message::CompilerMessage(message)) => ClippyWarning::new(message.message, &self.name, &self.version),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The arguments passed to `clippy-driver` when checking the `Crate`
+    fn clippy_args<'a>(&'a self, lint_filter: &'a [String]) -> Vec<&'a str> {
+        let mut clippy_args = Vec::<&str>::new();
+        if let Some(options) = &self.options {
+            for opt in options {
+                clippy_args.push(opt);
+            }
+        } else {
+            clippy_args.extend(["-Wclippy::pedantic", "-Wclippy::cargo"]);
+        }
+
+        if lint_filter.is_empty() {
+            clippy_args.push("--cap-lints=warn");
+        } else {
+            clippy_args.push("--cap-lints=allow");
+            clippy_args.extend(lint_filter.iter().map(std::string::String::as_str));
+        }
+
+        clippy_args
+    }
 }
 
 /// Builds clippy inside the repo to make sure we have a clippy executable we can use.
@@ -451,8 +592,8 @@ fn build_clippy() {
     }
 }
 
-/// Read a `lintcheck_crates.toml` file
-fn read_crates(toml_path: &Path) -> (Vec<CrateSource>, RecursiveOptions) {
+/// Read a `lintcheck_crates.toml` file, the crates.io crates are read from `registry` if it's set
+fn read_crates(toml_path: &Path, registry: Option<&Path>) -> (Vec<CrateSource>, RecursiveOptions) {
     let toml_content: String =
         std::fs::read_to_string(toml_path).unwrap_or_else(|_| panic!("Failed to read {}", toml_path.display()));
     let crate_list: SourceList =
@@ -473,11 +614,20 @@ fn read_crates(toml_path: &Path) -> (Vec<CrateSource>, RecursiveOptions) {
         } else if let Some(ref versions) = tk.versions {
             // if we have multiple versions, save each one
             for ver in versions {
-                crate_sources.push(CrateSource::CratesIo {
-                    name: tk.name.clone(),
-                    version: ver.to_string(),
-                    options: tk.options.clone(),
-                });
+                if let Some(registry) = tk.registry.as_deref().map(Path::new).or(registry) {
+                    crate_sources.push(CrateSource::Registry {
+                        name: tk.name.clone(),
+                        version: ver.to_string(),
+                        registry: registry.to_path_buf(),
+                        options: tk.options.clone(),
+                    });
+                } else {
+                    crate_sources.push(CrateSource::CratesIo {
+                        name: tk.name.clone(),
+                        version: ver.to_string(),
+                        options: tk.options.clone(),
+                    });
+                }
             }
         } else if tk.git_url.is_some() && tk.git_hash.is_some() {
             // otherwise, we should have a git source
@@ -491,6 +641,11 @@ fn read_crates(toml_path: &Path) -> (Vec<CrateSource>, RecursiveOptions) {
             panic!("Invalid crate source: {tk:?}");
         }
 
+        assert!(
+            tk.registry.is_none() || tk.versions.is_some(),
+            "Error: TomlCrate with a 'registry' needs 'versions': {tk:?}"
+        );
+
         // if we have a version as well as a git data OR only one git data, something is funky
         if tk.versions.is_some() && (tk.git_url.is_some() || tk.git_hash.is_some())
             || tk.git_hash.is_some() != tk.git_url.is_some()
@@ -515,30 +670,38 @@ fn read_crates(toml_path: &Path) -> (Vec<CrateSource>, RecursiveOptions) {
 }
 
 /// Generate a short list of occurring lints-types and their count
-fn gather_stats(clippy_warnings: &[ClippyWarning]) -> (String, HashMap<&String, usize>) {
-    // count lint type occurrences
-    let mut counter: HashMap<&String, usize> = HashMap::new();
-    clippy_warnings
-        .iter()
-        .for_each(|wrn| *counter.entry(&wrn.lint_type).or_insert(0) += 1);
-
-    // collect into a tupled list for sorting
-    let mut stats: Vec<(&&String, &usize)> = counter.iter().collect();
-    // sort by "000{count} {clippy::lintname}"
-    // to not have a lint with 200 and 2 warnings take the same spot
-    stats.sort_by_key(|(lint, count)| format!("{count:0>4}, {lint}"));
+fn gather_stats(clippy_warnings: &[ClippyWarning], groups: &HashMap<String, String>) -> Summary {
+    let mut summary = Summary::default();
+    for warning in clippy_warnings.iter().filter(|warning| !warning.is_ice) {
+        let group = groups.get(&warning.lint_type).map_or("unknown", String::as_str);
+        summary.add(
+            &warning.lint_type,
+            group,
+            &warning.crate_name,
+            &warning.file,
+            warning.machine_applicable,
+        );
+    }
+    summary
+}
 
-    let mut header = String::from("| lint                                               | count |\n");
-    header.push_str("| -------------------------------------------------- | ----- |\n");
-    let stats_string = stats
-        .iter()
-        .map(|(lint, count)| format!("| {lint:<50} |  {count:>4} |\n"))
-        .fold(header, |mut table, line| {
-            table.push_str(&line);
-            table
-        });
+/// The groups of the Clippy lints by name, e.g. `clippy::needless_borrow`
+fn lint_groups(cargo_clippy_path: &Path) -> HashMap<String, String> {
+    #[derive(Deserialize)]
+    struct Lint {
+        name: String,
+        group: String,
+    }
 
-    (stats_string, counter)
+    let output = Command::new(cargo_clippy_path)
+        .args(["--list-lints", "--format", "json"])
+        .output()
+        .expect("failed to run cargo-clippy");
+    let lints: Vec<Lint> = serde_json::from_slice(&output.stdout).expect("failed to parse the list of lints");
+    lints
+        .into_iter()
+        .map(|lint| (format!("clippy::{}", lint.name), lint.group))
+        .collect()
 }
 
 #[allow(clippy::too_many_lines)]
@@ -579,7 +742,7 @@ fn main() {
     // download and extract the crates, then run clippy on them and collect clippy's warnings
     // flatten into one big list of warnings
 
-    let (crates, recursive_options) = read_crates(&config.sources_toml_path);
+    let (crates, recursive_options) = read_crates(&config.sources_toml_path, config.registry.as_deref());
     let old_stats = read_stats_from_file(&config.lintcheck_results_path);
 
     let counter = AtomicUsize::new(1);
@@ -600,7 +763,8 @@ fn main() {
                 let name = match krate {
                     CrateSource::CratesIo { name, .. }
                     | CrateSource::Git { name, .. }
-                    | CrateSource::Path { name, .. } => name,
+                    | CrateSource::Path { name, .. }
+                    | CrateSource::Registry { name, .. } => name,
                 };
 
                 name == only_one_crate
@@ -608,7 +772,14 @@ fn main() {
                 true
             }
         })
-        .map(|krate| krate.download_and_extract())
+        .map(|krate| {
+            let mut krate = krate.download_and_extract();
+            // the dependencies of git and path crates are read from the local registry as well
+            if krate.registry.is_none() {
+                krate.registry = config.registry.as_deref().map(LocalRegistry::new);
+            }
+            krate
+        })
         .collect();
 
     if crates.is_empty() {
@@ -629,6 +800,21 @@ fn main() {
         .build_global()
         .unwrap();
 
+    if let Some(old_driver) = &config.diff {
+        let new_driver = config.diff_new.as_deref().unwrap_or(&clippy_driver_path);
+        diff::run(&config, &crates, &lint_filter, old_driver, new_driver);
+        return;
+    }
+
+    if config.verify_fixes {
+        verify_fixes::run(&config, &crates, &clippy_driver_path, &lint_filter);
+        return;
+    }
+
+    if config.profile {
+        profile::clean();
+    }
+
     let server = config.recursive.then(|| {
         let _: io::Result<()> = fs::remove_dir_all("target/lintcheck/shared_target_dir/recursive");
 
@@ -660,7 +846,7 @@ fn main() {
     }
 
     // generate some stats
-    let (stats_formatted, new_stats) = gather_stats(&clippy_warnings);
+    let summary = gather_stats(&clippy_warnings, &lint_groups(&cargo_clippy_path));
 
     // grab crashes/ICEs, save the crate name and the ice message
     let ices: Vec<(&String, &String)> = clippy_warnings
@@ -675,7 +861,7 @@ fn main() {
         .collect();
     all_msgs.sort();
     all_msgs.push("\n\n### Stats:\n\n".into());
-    all_msgs.push(stats_formatted);
+    all_msgs.push(summary.to_table());
 
     // save the text into lintcheck-logs/logs.txt
     let mut text = clippy_ver; // clippy version number on top
@@ -693,8 +879,14 @@ fn main() {
     println!("Writing logs to {}", config.lintcheck_results_path.display());
     fs::create_dir_all(config.lintcheck_results_path.parent().unwrap()).unwrap();
     fs::write(&config.lintcheck_results_path, text).unwrap();
+    println!("Writing the summary to {}", config.summary_results_path.display());
+    fs::write(&config.summary_results_path, summary.to_json()).unwrap();
+
+    print_stats(old_stats, summary.lints, &config.lint_filter);
 
-    print_stats(old_stats, new_stats, &config.lint_filter);
+    if config.profile {
+        profile::report(&config, &crates);
+    }
 }
 
 /// read the previous stats from the lintcheck-log file
@@ -728,10 +920,10 @@ fn read_stats_from_file(file_path: &Path) -> HashMap<String, usize> {
 }
 
 /// print how lint counts changed between runs
-fn print_stats(old_stats: HashMap<String, usize>, new_stats: HashMap<&String, usize>, lint_filter: &[String]) {
+fn print_stats(old_stats: HashMap<String, usize>, new_stats: HashMap<String, usize>, lint_filter: &[String]) {
     let same_in_both_hashmaps = old_stats
         .iter()
-        .filter(|(old_key, old_val)| new_stats.get::<&String>(old_key) == Some(old_val))
+        .filter(|(old_key, old_val)| new_stats.get(*old_key) == Some(old_val))
         .map(|(k, v)| (k.to_string(), *v))
         .collect::<Vec<(String, usize)>>();
 
@@ -766,7 +958,7 @@ fn print_stats(old_stats: HashMap<String, usize>, new_stats: HashMap<&String, us
     // list all gone counts (key is in old status but not in new stats)
     old_stats_deduped
         .iter()
-        .filter(|(old_key, _)| new_stats_deduped.get::<&String>(old_key).is_none())
+        .filter(|(old_key, _)| new_stats_deduped.get(*old_key).is_none())
         .filter(|(old_key, _)| lint_filter.is_empty() || lint_filter.contains(old_key))
         .for_each(|(old_key, old_value)| {
             println!("{old_key} {old_value} => 0");
@@ -0,0 +1,135 @@
+//! In `--profile` mode `clippy-driver` records the time spent in each lint pass and writes it to a
+//! JSON file per checked crate in the directory set in `CLIPPY_PASS_TIMINGS`. The timings of all
+//! the crates are combined into a report of the slowest passes.
+
+use crate::config::LintcheckConfig;
+use crate::{clippy_project_root, Crate};
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Write as _};
+use std::path::PathBuf;
+use std::{fs, io};
+
+use serde::{Deserialize, Serialize};
+
+const PROFILE_DIR: &str = "target/lintcheck/profile";
+
+/// The number of passes printed by [`report`], all of them are in the JSON report
+const PRINTED_PASSES: usize = 20;
+
+/// The time spent in a lint pass, as written by `clippy-driver`
+#[derive(Deserialize)]
+struct PassTiming {
+    name: String,
+    secs: f64,
+}
+
+/// The time spent in a lint pass in total and per crate
+#[derive(Serialize)]
+struct PassReport {
+    name: String,
+    secs: f64,
+    crates: BTreeMap<String, f64>,
+}
+
+#[derive(Serialize)]
+struct Report {
+    secs: f64,
+    passes: Vec<PassReport>,
+}
+
+/// The target dir used for `thread_index`, separate from the one of the usual runs so that it can
+/// be removed by [`clean`] to make sure every crate is checked again
+pub(crate) fn target_dir(thread_index: usize) -> PathBuf {
+    clippy_project_root()
+        .join(PROFILE_DIR)
+        .join(format!("target_{thread_index}"))
+}
+
+/// The directory `clippy-driver` writes the timings of `krate` to
+pub(crate) fn timings_dir(krate: &Crate) -> PathBuf {
+    clippy_project_root()
+        .join(PROFILE_DIR)
+        .join("timings")
+        .join(format!("{}-{}", krate.name, krate.version))
+}
+
+/// Removes the timings and target dirs of the previous run
+pub(crate) fn clean() {
+    let _: io::Result<()> = fs::remove_dir_all(clippy_project_root().join(PROFILE_DIR));
+}
+
+/// Reads the timings of `crates`, prints the slowest passes and writes all of them to
+/// `config.profile_results_path`
+pub(crate) fn report(config: &LintcheckConfig, crates: &[Crate]) {
+    let mut passes: HashMap<String, PassReport> = HashMap::new();
+    for krate in crates {
+        let crate_name = format!("{} {}", krate.name, krate.version);
+        // a crate that failed to compile may not have any timings
+        let Ok(entries) = fs::read_dir(timings_dir(krate)) else {
+            continue;
+        };
+
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let content = fs::read_to_string(&path).unwrap();
+            let timings: Vec<PassTiming> =
+                serde_json::from_str(&content).unwrap_or_else(|e| panic!("Failed to parse {}: {e}", path.display()));
+
+            for timing in timings {
+                let pass = passes.entry(timing.name.clone()).or_insert_with(|| PassReport {
+                    name: timing.name,
+                    secs: 0.0,
+                    crates: BTreeMap::new(),
+                });
+                pass.secs += timing.secs;
+                *pass.crates.entry(crate_name.clone()).or_default() += timing.secs;
+            }
+        }
+    }
+
+    let mut passes: Vec<PassReport> = passes.into_values().collect();
+    passes.sort_by(|a, b| b.secs.total_cmp(&a.secs).then_with(|| a.name.cmp(&b.name)));
+    let report = Report {
+        secs: passes.iter().map(|pass| pass.secs).sum(),
+        passes,
+    };
+
+    println!("\nSlowest lint passes:\n\n{}", format_table(&report));
+
+    println!("Writing lint pass timings to {}", config.profile_results_path.display());
+    fs::create_dir_all(config.profile_results_path.parent().unwrap()).unwrap();
+    fs::write(
+        &config.profile_results_path,
+        serde_json::to_string_pretty(&report).unwrap(),
+    )
+    .unwrap();
+}
+
+fn format_table(report: &Report) -> String {
+    let mut table = String::from(
+        "| pass                           |      time | share | slowest crate                            |\n",
+    );
+    table.push_str(
+        "| ------------------------------ | --------- | ----- | ---------------------------------------- |\n",
+    );
+    for pass in report.passes.iter().take(PRINTED_PASSES) {
+        let share = if report.secs > 0.0 {
+            pass.secs / report.secs * 100.0
+        } else {
+            0.0
+        };
+        let slowest_crate = pass
+            .crates
+            .iter()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(name, secs)| format!("{name} ({secs:.3}s)"))
+            .unwrap_or_default();
+        let _: fmt::Result = writeln!(
+            table,
+            "| {:<30} | {:>8.3}s | {share:>4.1}% | {slowest_crate:<40} |",
+            pass.name, pass.secs
+        );
+    }
+    table
+}
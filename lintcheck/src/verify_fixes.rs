@@ -0,0 +1,335 @@
+//! In `--verify-fixes` mode the `MachineApplicable` suggestions of each lint are applied to a copy
+//! of the crates on their own with `cargo fix`, then the crates are checked to still compile and to
+//! still pass their tests. The lints whose suggestions break the code are reported, so that their
+//! applicability can be lowered.
+//!
+//! The lints are run with `--force-warn`, so the suggestions are also applied where the crate
+//! allows the lint.
+
+use crate::config::LintcheckConfig;
+use crate::{clippy_project_root, copy_dir, Crate};
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+use std::path::Path;
+use std::process::{Command, Output};
+use std::{fs, io};
+
+use rayon::prelude::*;
+use serde::Serialize;
+
+const VERIFY_FIXES_DIR: &str = "target/lintcheck/verify_fixes";
+
+/// The number of lines of the output of a failed build or test run kept in the report
+const MAX_OUTPUT_LINES: usize = 50;
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Outcome {
+    /// The crate compiles and its tests pass after the fixes
+    Ok,
+    /// There was nothing to fix, e.g. all the suggestions were in macros
+    NoFixes,
+    DoesNotCompile,
+    TestsFail,
+}
+
+/// The result of applying the suggestions of a lint to a crate
+#[derive(Serialize)]
+struct Verification {
+    lint: String,
+    fixes: usize,
+    outcome: Outcome,
+    /// The output of the failed build or test run
+    output: String,
+}
+
+#[derive(Serialize)]
+struct CrateReport {
+    name: String,
+    /// Whether the crate compiles without fixes, the suggestions are only verified if it does
+    compiles: bool,
+    /// Whether the tests of the crate pass without fixes, they are only run after the fixes if
+    /// they do
+    tests_pass: bool,
+    verifications: Vec<Verification>,
+}
+
+/// The verifications of a lint over all the crates
+#[derive(Default)]
+struct LintSummary {
+    crates: usize,
+    fixes: usize,
+    does_not_compile: usize,
+    tests_fail: usize,
+}
+
+/// Verifies the suggestions of the lints that `crates` trigger and writes the report to
+/// `config.verify_fixes_results_path` as markdown and JSON
+pub(crate) fn run(config: &LintcheckConfig, crates: &[Crate], clippy_driver_path: &Path, lint_filter: &[String]) {
+    let _: io::Result<()> = fs::remove_dir_all(clippy_project_root().join(VERIFY_FIXES_DIR));
+
+    // `--filter` lints are stored as `clippy::lint-name`, the warnings as `clippy::lint_name`
+    let only_lints: Vec<String> = config.lint_filter.iter().map(|lint| lint.replace('-', "_")).collect();
+    let reports: Vec<CrateReport> = crates
+        .par_iter()
+        .map(|krate| verify_crate(krate, clippy_driver_path, lint_filter, &only_lints))
+        .collect();
+
+    let table = format_table(&reports);
+    println!("\n{table}");
+
+    let markdown_path = config.verify_fixes_results_path.with_extension("md");
+    let json_path = config.verify_fixes_results_path.with_extension("json");
+    println!(
+        "Writing the fix verification report to {} and {}",
+        markdown_path.display(),
+        json_path.display()
+    );
+    fs::create_dir_all(markdown_path.parent().unwrap()).unwrap();
+    fs::write(markdown_path, to_markdown(&table, &reports)).unwrap();
+    fs::write(json_path, serde_json::to_string_pretty(&reports).unwrap()).unwrap();
+}
+
+fn verify_crate(
+    krate: &Crate,
+    clippy_driver_path: &Path,
+    lint_filter: &[String],
+    only_lints: &[String],
+) -> CrateReport {
+    let crate_name = format!("{} {}", krate.name, krate.version);
+    println!("Verifying the fixes for {crate_name}");
+
+    let dir = clippy_project_root().join(VERIFY_FIXES_DIR);
+    // the target dir is shared by all the runs on a crate, so that its dependencies are only built
+    // once
+    let target_dir = dir.join(format!("target_{}", rayon::current_thread_index().unwrap_or(0)));
+    let work_dir = dir.join("sources").join(format!("{}-{}", krate.name, krate.version));
+
+    let mut lints: Vec<String> = krate
+        .run_clippy_driver(clippy_driver_path, &target_dir, lint_filter)
+        .into_iter()
+        .filter(|warning| warning.machine_applicable)
+        .map(|warning| warning.lint_type)
+        .filter(|lint| lint.starts_with("clippy::") && (only_lints.is_empty() || only_lints.contains(lint)))
+        .collect();
+    lints.sort_unstable();
+    lints.dedup();
+
+    let mut report = CrateReport {
+        name: crate_name,
+        compiles: false,
+        tests_pass: false,
+        verifications: Vec::new(),
+    };
+
+    reset(krate, &work_dir);
+    report.compiles = cargo(krate, &work_dir, &target_dir, &["check", "--all-targets"]).is_ok();
+    if !report.compiles {
+        eprintln!(
+            "\nWARNING: {} doesn't compile without fixes, skipping it\n",
+            report.name
+        );
+        return report;
+    }
+    report.tests_pass = cargo(krate, &work_dir, &target_dir, &["test"]).is_ok();
+
+    for lint in lints {
+        println!("Verifying the fixes of {lint} for {}", report.name);
+        reset(krate, &work_dir);
+        report.verifications.push(verify_lint(
+            krate,
+            &work_dir,
+            &target_dir,
+            clippy_driver_path,
+            lint,
+            report.tests_pass,
+        ));
+    }
+
+    report
+}
+
+/// Applies the suggestions of `lint` to the sources in `work_dir`, then builds and tests them
+fn verify_lint(
+    krate: &Crate,
+    work_dir: &Path,
+    target_dir: &Path,
+    clippy_driver_path: &Path,
+    lint: String,
+    run_tests: bool,
+) -> Verification {
+    // `cargo clippy --fix` is `cargo fix` with `clippy-driver` as the workspace wrapper, the other
+    // lints are allowed so that only the suggestions of `lint` are applied
+    let clippy_args = ["-Awarnings", &format!("--force-warn={lint}"), "--cap-lints=warn"];
+    let fix = Command::new("cargo")
+        .args([
+            "fix",
+            "--allow-no-vcs",
+            "--allow-dirty",
+            "--broken-code",
+            "--all-targets",
+        ])
+        .args(krate.cargo_args())
+        .current_dir(work_dir)
+        .env("CLIPPY_ARGS", clippy_args.join("__CLIPPY_HACKERY__"))
+        .env("CARGO_TARGET_DIR", target_dir)
+        .env("RUSTC_WORKSPACE_WRAPPER", clippy_driver_path)
+        .output()
+        .expect("failed to run cargo");
+    let stderr = String::from_utf8_lossy(&fix.stderr);
+
+    let fixes = count_fixes(&stderr);
+    // `cargo fix` doesn't report the fixes that broke the code, it only warns about them
+    let broken = stderr.contains("failed to automatically apply fixes suggested by rustc");
+
+    let (outcome, output) = if !fix.status.success() {
+        (Outcome::DoesNotCompile, truncate(&stderr))
+    } else if fixes == 0 && !broken {
+        (Outcome::NoFixes, String::new())
+    } else if let Err(output) = cargo(krate, work_dir, target_dir, &["check", "--all-targets"]) {
+        (Outcome::DoesNotCompile, output)
+    } else if !run_tests {
+        (Outcome::Ok, String::new())
+    } else if let Err(output) = cargo(krate, work_dir, target_dir, &["test"]) {
+        (Outcome::TestsFail, output)
+    } else {
+        (Outcome::Ok, String::new())
+    };
+
+    Verification {
+        lint,
+        fixes,
+        outcome,
+        output,
+    }
+}
+
+/// Replaces the sources in `work_dir` by a fresh copy of the ones of `krate`
+fn reset(krate: &Crate, work_dir: &Path) {
+    let _: io::Result<()> = fs::remove_dir_all(work_dir);
+    fs::create_dir_all(work_dir.parent().unwrap()).unwrap();
+    copy_dir(&krate.path, work_dir);
+}
+
+/// Runs `cargo <args>` on the sources in `work_dir`, returns its output if it fails
+fn cargo(krate: &Crate, work_dir: &Path, target_dir: &Path, args: &[&str]) -> Result<(), String> {
+    let Output { status, stdout, stderr } = Command::new("cargo")
+        .args(args)
+        .arg("--quiet")
+        .args(krate.cargo_args())
+        .current_dir(work_dir)
+        .env("CARGO_TARGET_DIR", target_dir)
+        .output()
+        .expect("failed to run cargo");
+
+    if status.success() {
+        Ok(())
+    } else {
+        // the failed tests are printed to stdout, the compiler errors to stderr
+        let output = String::from_utf8_lossy(&stdout) + String::from_utf8_lossy(&stderr);
+        Err(truncate(&output))
+    }
+}
+
+/// The number of fixes in the `Fixed src/lib.rs (2 fixes)` lines `cargo fix` prints
+fn count_fixes(stderr: &str) -> usize {
+    stderr
+        .lines()
+        .filter_map(|line| line.trim_start().strip_prefix("Fixed "))
+        .filter_map(|line| line.rsplit_once(" (")?.1.split(' ').next()?.parse::<usize>().ok())
+        .sum()
+}
+
+fn truncate(output: &str) -> String {
+    let lines: Vec<&str> = output.trim().lines().collect();
+    if lines.len() > MAX_OUTPUT_LINES {
+        format!(
+            "{}\n[{} more lines]",
+            lines[..MAX_OUTPUT_LINES].join("\n"),
+            lines.len() - MAX_OUTPUT_LINES
+        )
+    } else {
+        lines.join("\n")
+    }
+}
+
+/// A table of the lints whose suggestions were applied, the ones that broke a crate first
+fn format_table(reports: &[CrateReport]) -> String {
+    let mut lints: BTreeMap<&str, LintSummary> = BTreeMap::new();
+    for verification in reports.iter().flat_map(|report| &report.verifications) {
+        if verification.outcome == Outcome::NoFixes {
+            continue;
+        }
+        let summary = lints.entry(&verification.lint).or_default();
+        summary.crates += 1;
+        summary.fixes += verification.fixes;
+        summary.does_not_compile += usize::from(verification.outcome == Outcome::DoesNotCompile);
+        summary.tests_fail += usize::from(verification.outcome == Outcome::TestsFail);
+    }
+    let mut lints: Vec<(&str, LintSummary)> = lints.into_iter().collect();
+    lints.sort_by_key(|(_, summary)| summary.does_not_compile + summary.tests_fail == 0);
+
+    let mut table = String::from(
+        "| lint                                               | crates | fixes | doesn't compile | tests fail |\n",
+    );
+    table.push_str(
+        "| -------------------------------------------------- | ------ | ----- | --------------- | ---------- |\n",
+    );
+    for (lint, summary) in lints {
+        let _: fmt::Result = writeln!(
+            table,
+            "| {lint:<50} | {:>6} | {:>5} | {:>15} | {:>10} |",
+            summary.crates, summary.fixes, summary.does_not_compile, summary.tests_fail
+        );
+    }
+    table
+}
+
+fn to_markdown(table: &str, reports: &[CrateReport]) -> String {
+    let mut text = String::from("# Lintcheck fix verification\n\n");
+    text.push_str(table);
+
+    text.push_str("\n## Failures\n");
+    for report in reports {
+        for verification in &report.verifications {
+            let failure = match verification.outcome {
+                Outcome::DoesNotCompile => "doesn't compile",
+                Outcome::TestsFail => "tests fail",
+                Outcome::Ok | Outcome::NoFixes => continue,
+            };
+            let _: fmt::Result = writeln!(
+                text,
+                "\n### `{}` in {}: {failure}\n\n```text\n{}\n```",
+                verification.lint, report.name, verification.output
+            );
+        }
+    }
+
+    let not_compiling: Vec<&str> = reports
+        .iter()
+        .filter(|report| !report.compiles)
+        .map(|report| report.name.as_str())
+        .collect();
+    let not_tested: Vec<&str> = reports
+        .iter()
+        .filter(|report| report.compiles && !report.tests_pass)
+        .map(|report| report.name.as_str())
+        .collect();
+    if !not_compiling.is_empty() {
+        let _: fmt::Result = writeln!(
+            text,
+            "\nSkipped, they don't compile without fixes: {}",
+            not_compiling.join(", ")
+        );
+    }
+    if !not_tested.is_empty() {
+        let _: fmt::Result = writeln!(
+            text,
+            "\nNot tested, their tests fail without fixes: {}",
+            not_tested.join(", ")
+        );
+    }
+
+    text
+}
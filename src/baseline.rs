@@ -0,0 +1,209 @@
+//! `--write-baseline` records the Clippy diagnostics of a project in a file, `--baseline` then
+//! only prints the diagnostics that are not in that file, so that new lints can be enabled without
+//! fixing all their existing warnings first.
+
+use crate::diagnostics::Diagnostic;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+const BASELINE_VERSION: u32 = 1;
+
+pub enum Mode {
+    Write(PathBuf),
+    Check(PathBuf),
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    version: u32,
+    entries: Vec<Entry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    file: String,
+    lint: String,
+    message: String,
+    /// See [`Key::fingerprint`]
+    fingerprint: String,
+    count: usize,
+}
+
+/// Identifies a diagnostic without its line number, so that code can be added or removed above it
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Key {
+    file: String,
+    lint: String,
+    /// A hash of the lint, the message and the trimmed source lines of the primary span
+    fingerprint: String,
+}
+
+impl Key {
+    fn new(diag: &Diagnostic) -> Option<Self> {
+        let lint = diag.clippy_lint()?;
+        let span = diag.primary_span();
+        let snippet = span.map_or_else(String::new, |span| {
+            span.text
+                .iter()
+                .map(|line| line.text.trim())
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+
+        Some(Self {
+            file: span.map_or_else(String::new, |span| span.file_name.replace('\\', "/")),
+            lint: lint.to_string(),
+            fingerprint: format!("{:016x}", fnv1a([lint, &diag.message, &snippet])),
+        })
+    }
+}
+
+/// The 64 bit FNV-1a hash of `parts`, used because it is stable across Rust versions and platforms
+fn fnv1a<const N: usize>(parts: [&str; N]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.bytes().chain([0]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+fn read(path: &PathBuf) -> Result<BTreeMap<Key, Entry>, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("failed to read baseline `{}`: {e}", path.display()))?;
+    let baseline: BaselineFile =
+        serde_json::from_str(&content).map_err(|e| format!("failed to parse baseline `{}`: {e}", path.display()))?;
+    if baseline.version != BASELINE_VERSION {
+        return Err(format!(
+            "unsupported baseline version {} in `{}`, recreate it with `--write-baseline`",
+            baseline.version,
+            path.display()
+        ));
+    }
+
+    Ok(baseline
+        .entries
+        .into_iter()
+        .map(|entry| {
+            let key = Key {
+                file: entry.file.clone(),
+                lint: entry.lint.clone(),
+                fingerprint: entry.fingerprint.clone(),
+            };
+            (key, entry)
+        })
+        .collect())
+}
+
+fn write(path: &PathBuf, entries: BTreeMap<Key, Entry>) -> Result<(), String> {
+    let baseline = BaselineFile {
+        version: BASELINE_VERSION,
+        entries: entries.into_values().collect(),
+    };
+    let json = serde_json::to_string_pretty(&baseline).unwrap();
+    fs::write(path, json + "\n").map_err(|e| format!("failed to write baseline `{}`: {e}", path.display()))
+}
+
+pub struct Baseline {
+    mode: Mode,
+    entries: BTreeMap<Key, Entry>,
+    suppressed: usize,
+}
+
+impl Baseline {
+    pub fn new(mode: Mode) -> Result<Self, String> {
+        let entries = match &mode {
+            Mode::Check(path) => read(path)?,
+            Mode::Write(_) => BTreeMap::new(),
+        };
+        Ok(Self {
+            mode,
+            entries,
+            suppressed: 0,
+        })
+    }
+
+    /// Returns whether `diag` is in the baseline and shouldn't be printed, each entry of the
+    /// baseline only suppresses as many diagnostics as it recorded. With [`Mode::Write`] the
+    /// diagnostic is recorded instead.
+    pub fn suppresses(&mut self, diag: &Diagnostic) -> bool {
+        let Some(key) = Key::new(diag) else {
+            return false;
+        };
+
+        match self.mode {
+            Mode::Write(_) => {
+                self.entries
+                    .entry(key.clone())
+                    .or_insert_with(|| Entry {
+                        file: key.file,
+                        lint: key.lint,
+                        message: diag.message.clone(),
+                        fingerprint: key.fingerprint,
+                        count: 0,
+                    })
+                    .count += 1;
+                false
+            },
+            Mode::Check(_) => {
+                if let Some(entry) = self.entries.get_mut(&key).filter(|entry| entry.count > 0) {
+                    entry.count -= 1;
+                    self.suppressed += 1;
+                    true
+                } else {
+                    false
+                }
+            },
+        }
+    }
+
+    /// Writes the baseline file with [`Mode::Write`], otherwise prints how many diagnostics were
+    /// suppressed and the entries of the baseline that no longer occur.
+    ///
+    /// `complete` is `false` if cargo failed before Clippy could lint everything, in that case the
+    /// baseline file is not replaced.
+    pub fn finish(self, complete: bool) -> Result<(), String> {
+        match self.mode {
+            Mode::Write(path) if !complete => {
+                eprintln!(
+                    "warning: the baseline `{}` was not written, the build failed before Clippy could lint everything",
+                    path.display()
+                );
+            },
+            Mode::Write(path) => {
+                let count: usize = self.entries.values().map(|entry| entry.count).sum();
+                write(&path, self.entries)?;
+                eprintln!("Wrote {count} diagnostics to the baseline `{}`", path.display());
+            },
+            Mode::Check(path) => {
+                if self.suppressed > 0 {
+                    eprintln!(
+                        "{} diagnostics are suppressed by the baseline `{}`",
+                        self.suppressed,
+                        path.display()
+                    );
+                }
+
+                let fixed: Vec<_> = self.entries.values().filter(|entry| entry.count > 0).collect();
+                if !fixed.is_empty() {
+                    let count: usize = fixed.iter().map(|entry| entry.count).sum();
+                    eprintln!(
+                        "{count} diagnostics of the baseline no longer occur, update it with `--write-baseline`:"
+                    );
+                    for entry in fixed {
+                        let times = if entry.count > 1 {
+                            format!(" ({} times)", entry.count)
+                        } else {
+                            String::new()
+                        };
+                        eprintln!("    {}: {}: {}{times}", entry.file, entry.lint, entry.message);
+                    }
+                }
+            },
+        }
+        Ok(())
+    }
+}
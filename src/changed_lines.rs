@@ -0,0 +1,158 @@
+//! `--changed-since <rev>` only prints the lint diagnostics whose primary span touches a line that
+//! changed since the git revision `rev`, e.g. the base of a pull request, so that a review only
+//! shows the warnings of the code it changes.
+//!
+//! The changed lines are the ones `git diff <rev>` adds to the working tree, the files that are
+//! not tracked by git count as changed entirely. Compilation errors are always printed.
+
+use crate::diagnostics::{self, Diagnostic};
+use std::collections::HashMap;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The lines that were changed in a file
+enum Changes {
+    /// The file is not tracked by git
+    Untracked,
+    Lines(Vec<Range<usize>>),
+}
+
+pub struct ChangedLines {
+    rev: String,
+    /// The changed files by their canonical path
+    files: HashMap<PathBuf, Changes>,
+    /// The root of the workspace, the file names of the diagnostics are relative to it
+    root: PathBuf,
+    filtered: usize,
+}
+
+impl ChangedLines {
+    pub fn new(rev: String) -> Result<Self, String> {
+        let toplevel = PathBuf::from(git(Path::new("."), &["rev-parse", "--show-toplevel"])?.trim());
+        let toplevel = fs::canonicalize(&toplevel).unwrap_or(toplevel);
+
+        // renamed files are diffed as added files, so that all their lines count as changed
+        let diff = git(
+            &toplevel,
+            &[
+                "-c",
+                "core.quotePath=false",
+                "diff",
+                "--unified=0",
+                "--no-color",
+                "--no-ext-diff",
+                "--no-renames",
+                "--src-prefix=a/",
+                "--dst-prefix=b/",
+                &rev,
+                "--",
+            ],
+        )
+        .map_err(|e| format!("failed to diff against `{rev}`: {e}"))?;
+        let mut files: HashMap<PathBuf, Changes> = parse_diff(&diff)
+            .into_iter()
+            .map(|(file, lines)| (toplevel.join(file), Changes::Lines(lines)))
+            .collect();
+
+        let untracked = git(
+            &toplevel,
+            &[
+                "-c",
+                "core.quotePath=false",
+                "ls-files",
+                "--others",
+                "--exclude-standard",
+            ],
+        )?;
+        files.extend(untracked.lines().map(|file| (toplevel.join(file), Changes::Untracked)));
+
+        let root = diagnostics::workspace_root();
+        Ok(Self {
+            rev,
+            files,
+            root: fs::canonicalize(&root).unwrap_or(root),
+            filtered: 0,
+        })
+    }
+
+    /// Returns whether `diag` is a lint whose primary span is outside of the changed lines, and
+    /// shouldn't be printed
+    pub fn filters(&mut self, diag: &Diagnostic) -> bool {
+        let Some(span) = diag.primary_span().filter(|_| diag.is_lint()) else {
+            return false;
+        };
+
+        let changed = match self.files.get(&self.root.join(&span.file_name)) {
+            None => false,
+            Some(Changes::Untracked) => true,
+            Some(Changes::Lines(lines)) => lines
+                .iter()
+                .any(|lines| span.line_start < lines.end && lines.start <= span.line_end),
+        };
+        if !changed {
+            self.filtered += 1;
+        }
+        !changed
+    }
+
+    /// Prints how many diagnostics were filtered
+    pub fn finish(self) {
+        if self.filtered > 0 {
+            eprintln!(
+                "{} diagnostics outside of the lines changed since `{}` were filtered",
+                self.filtered, self.rev
+            );
+        }
+    }
+}
+
+/// Runs git in `dir`, returns its output or its error message
+pub fn git(dir: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| format!("failed to run git: {e}"))?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).map_err(|e| format!("git printed invalid UTF-8: {e}"))
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// The lines added by a `git diff --unified=0`, by file relative to the root of the repository
+fn parse_diff(diff: &str) -> HashMap<&str, Vec<Range<usize>>> {
+    let mut files: HashMap<&str, Vec<Range<usize>>> = HashMap::new();
+    // `None` for deleted files
+    let mut file = None;
+    // an added line that starts with `++ ` is not a header
+    let mut in_header = false;
+
+    for line in diff.lines() {
+        if line.starts_with("diff --git ") {
+            in_header = true;
+        } else if let Some(path) = line.strip_prefix("+++ ").filter(|_| in_header) {
+            // git ends the line with a tab if the path contains a space
+            file = path.trim_end_matches('\t').strip_prefix("b/");
+        } else if let Some(hunk) = line.strip_prefix("@@ ") {
+            in_header = false;
+            // `@@ -old_start,old_count +new_start,new_count @@`, the counts are omitted if they are 1
+            let Some(file) = file else { continue };
+            let Some(new) = hunk.split(' ').find_map(|range| range.strip_prefix('+')) else {
+                continue;
+            };
+            let (start, count) = new.split_once(',').unwrap_or((new, "1"));
+            let (Ok(start), Ok(count)) = (start.parse::<usize>(), count.parse::<usize>()) else {
+                continue;
+            };
+            // a count of 0 is a hunk that only removes lines
+            if count > 0 {
+                files.entry(file).or_default().push(start..start + count);
+            }
+        }
+    }
+
+    files
+}
@@ -0,0 +1,328 @@
+//! Runs cargo with JSON diagnostics so that `cargo clippy` can post-process them before printing
+
+use crate::baseline::{self, Baseline};
+use crate::changed_lines::ChangedLines;
+use crate::msrv_report::MsrvReport;
+use crate::sarif::Sarif;
+use clippy_summary::Summary;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead, BufReader, IsTerminal, Write};
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus, Stdio};
+
+#[derive(Deserialize)]
+#[serde(tag = "reason", rename_all = "kebab-case")]
+enum CargoMessage {
+    CompilerMessage {
+        message: Diagnostic,
+        target: Target,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+struct Target {
+    name: String,
+}
+
+/// A diagnostic in rustc's JSON format
+#[derive(Deserialize)]
+pub struct Diagnostic {
+    pub message: String,
+    pub code: Option<DiagnosticCode>,
+    pub level: String,
+    pub spans: Vec<DiagnosticSpan>,
+    pub children: Vec<Diagnostic>,
+    pub rendered: Option<String>,
+    /// The name of the crate the diagnostic was emitted for, set by [`run`]
+    #[serde(skip)]
+    pub crate_name: String,
+}
+
+#[derive(Deserialize)]
+pub struct DiagnosticCode {
+    pub code: String,
+}
+
+#[derive(Deserialize)]
+pub struct DiagnosticSpan {
+    pub file_name: String,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub column_start: usize,
+    pub column_end: usize,
+    pub is_primary: bool,
+    pub text: Vec<DiagnosticSpanLine>,
+    pub suggested_replacement: Option<String>,
+    pub suggestion_applicability: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct DiagnosticSpanLine {
+    pub text: String,
+}
+
+impl Diagnostic {
+    /// The name of the Clippy lint that emitted the diagnostic, e.g. `clippy::needless_borrow`
+    pub fn clippy_lint(&self) -> Option<&str> {
+        self.code
+            .as_ref()
+            .map(|code| code.code.as_str())
+            .filter(|code| code.starts_with("clippy::"))
+    }
+
+    /// Whether the diagnostic was emitted by a lint of Clippy or rustc, instead of being a
+    /// compilation error like `E0308`
+    pub fn is_lint(&self) -> bool {
+        self.code.as_ref().is_some_and(|code| {
+            let code = &code.code;
+            !(code.starts_with('E') && code[1..].bytes().all(|b| b.is_ascii_digit()))
+        })
+    }
+
+    /// Whether a suggestion of the diagnostic is `MachineApplicable`, so that `--fix` applies it
+    pub fn is_fixable(&self) -> bool {
+        self.children
+            .iter()
+            .flat_map(|child| &child.spans)
+            .any(|span| span.suggestion_applicability.as_deref() == Some("MachineApplicable"))
+    }
+
+    pub fn primary_span(&self) -> Option<&DiagnosticSpan> {
+        self.spans.iter().find(|span| span.is_primary)
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.level == "error"
+    }
+
+    /// Whether this is one of the `N warnings emitted`/`aborting due to N previous errors`
+    /// messages, which are wrong once diagnostics are filtered
+    pub fn is_summary(&self) -> bool {
+        self.code.is_none()
+            && self.spans.is_empty()
+            && (self.message.ends_with("emitted") || self.message.starts_with("aborting due to"))
+    }
+
+    /// Prints the diagnostic like rustc does
+    pub fn print(&self) {
+        if let Some(rendered) = &self.rendered {
+            eprint!("{rendered}");
+        }
+    }
+}
+
+/// Runs `cmd`, a cargo command, with JSON diagnostics and calls `handle` for every diagnostic. The
+/// other output of cargo is passed through.
+pub fn run(mut cmd: Command, mut handle: impl FnMut(Diagnostic)) -> io::Result<ExitStatus> {
+    let format = if io::stderr().is_terminal() {
+        "--message-format=json-diagnostic-rendered-ansi"
+    } else {
+        "--message-format=json"
+    };
+    let mut child = cmd.arg(format).stdout(Stdio::piped()).spawn()?;
+
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    for line in stdout.lines() {
+        let line = line?;
+        match serde_json::from_str::<CargoMessage>(&line) {
+            Ok(CargoMessage::CompilerMessage { mut message, target }) => {
+                message.crate_name = target.name;
+                handle(message);
+            },
+            Ok(CargoMessage::Other) => {},
+            Err(_) => writeln!(io::stdout(), "{line}")?,
+        }
+    }
+
+    child.wait()
+}
+
+/// The root of the workspace, the file names of the diagnostics are relative to it
+pub fn workspace_root() -> PathBuf {
+    Command::new("cargo")
+        .args(["locate-project", "--workspace", "--message-format", "plain"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| {
+            let manifest = PathBuf::from(String::from_utf8(output.stdout).ok()?.trim());
+            Some(manifest.parent()?.to_path_buf())
+        })
+        .unwrap_or_default()
+}
+
+/// What `cargo clippy` does with the diagnostics besides printing them
+#[derive(Default)]
+pub struct Options {
+    pub baseline: Option<baseline::Mode>,
+    /// The file to write a SARIF log of the Clippy diagnostics to
+    pub sarif: Option<PathBuf>,
+    /// Only print the lints that are suppressed by the MSRV, see [`MsrvReport`]
+    pub msrv_report: bool,
+    /// Only print the lints on the lines changed since this git revision, see [`ChangedLines`]
+    pub changed_since: Option<String>,
+    /// Print a [`Summary`] of the Clippy diagnostics at the end
+    pub summary: Option<SummaryFormat>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SummaryFormat {
+    Table,
+    Json,
+}
+
+impl Options {
+    /// Whether the diagnostics have to be processed by `cargo clippy` instead of just printed by
+    /// cargo
+    pub fn is_empty(&self) -> bool {
+        self.baseline.is_none()
+            && self.sarif.is_none()
+            && !self.msrv_report
+            && self.changed_since.is_none()
+            && self.summary.is_none()
+    }
+
+    /// Parses `arg` if it is one of the flags of the options, the value of the flag is read from
+    /// `args`. Returns whether it was.
+    pub fn parse_flag(&mut self, arg: &str, args: &mut impl Iterator<Item = String>) -> Result<bool, String> {
+        match arg {
+            "--baseline" | "--write-baseline" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| format!("`{arg}` requires the path of the baseline file"))?;
+                let mode = if arg == "--baseline" {
+                    baseline::Mode::Check(path.into())
+                } else {
+                    baseline::Mode::Write(path.into())
+                };
+                if self.baseline.replace(mode).is_some() {
+                    return Err("only one of `--baseline` and `--write-baseline` can be used".into());
+                }
+            },
+            "--sarif" => {
+                let path = args.next().ok_or("`--sarif` requires the path of the SARIF file")?;
+                self.sarif = Some(path.into());
+            },
+            "--msrv-report" => self.msrv_report = true,
+            "--changed-since" => {
+                let rev = args.next().ok_or("`--changed-since` requires a git revision")?;
+                self.changed_since = Some(rev);
+            },
+            "--summary" | "--summary=table" => self.summary = Some(SummaryFormat::Table),
+            "--summary=json" => self.summary = Some(SummaryFormat::Json),
+            _ => {
+                if let Some(format) = arg.strip_prefix("--summary=") {
+                    return Err(format!("unknown summary format `{format}`, expected `table` or `json`"));
+                }
+                return Ok(false);
+            },
+        }
+        Ok(true)
+    }
+}
+
+fn report(error: &str) -> i32 {
+    eprintln!("error: {error}");
+    1
+}
+
+/// Runs `cmd` and processes its diagnostics according to `options`
+pub fn process(mut cmd: Command, options: Options) -> Result<(), i32> {
+    let mut baseline = options
+        .baseline
+        .map(Baseline::new)
+        .transpose()
+        .map_err(|e| report(&e))?;
+    let mut changed_lines = options
+        .changed_since
+        .map(ChangedLines::new)
+        .transpose()
+        .map_err(|e| report(&e))?;
+    let mut sarif = options.sarif.map(Sarif::new);
+    let mut summary = options.summary.map(|format| {
+        let groups: HashMap<String, &str> = clippy_lints::lint_metadata()
+            .into_iter()
+            .map(|lint| (format!("clippy::{}", lint.name), lint.group))
+            .collect();
+        (format, groups, Summary::default())
+    });
+    let mut msrv_report = options.msrv_report.then(|| {
+        cmd.env("CLIPPY_MSRV_REPORT", "1");
+        MsrvReport::new()
+    });
+
+    // the same diagnostic is emitted for every target that contains the code, e.g. the lib and its
+    // unit tests, only the first one is counted
+    let mut seen = HashSet::new();
+    let mut errors = 0;
+    let mut printed_errors = 0;
+    let status = run(cmd, |diag| {
+        if diag.is_summary() || !seen.insert(diag.rendered.clone()) {
+            return;
+        }
+        if diag.is_error() {
+            errors += 1;
+        }
+
+        if baseline.as_mut().is_some_and(|baseline| baseline.suppresses(&diag)) {
+            return;
+        }
+        if changed_lines.as_mut().is_some_and(|changed| changed.filters(&diag)) {
+            return;
+        }
+        if let Some(sarif) = &mut sarif {
+            sarif.add(&diag);
+        }
+        if let (Some((_, groups, summary)), Some(lint)) = (&mut summary, diag.clippy_lint()) {
+            let file = diag.primary_span().map_or("", |span| &span.file_name);
+            let group = groups.get(lint).copied().unwrap_or("unknown");
+            summary.add(lint, group, &diag.crate_name, file, diag.is_fixable());
+        }
+        // only errors that are not Clippy lints, e.g. compilation errors, are printed with the report
+        if let Some(msrv_report) = &mut msrv_report {
+            if msrv_report.add(&diag) || !diag.is_error() || diag.clippy_lint().is_some() {
+                return;
+            }
+        }
+
+        if diag.is_error() {
+            printed_errors += 1;
+        }
+        diag.print();
+    })
+    .expect("could not run cargo");
+
+    // if cargo failed without an error diagnostic, Clippy didn't lint everything
+    let complete = status.success() || errors > 0;
+    if let Some(baseline) = baseline {
+        baseline.finish(complete).map_err(|e| report(&e))?;
+    }
+    if let Some(changed_lines) = changed_lines {
+        changed_lines.finish();
+    }
+    if let Some(sarif) = sarif {
+        sarif.write().map_err(|e| report(&e))?;
+    }
+    if let Some(msrv_report) = msrv_report.filter(|_| complete) {
+        msrv_report.print();
+    }
+    if let Some((format, _, summary)) = summary.filter(|_| complete) {
+        match format {
+            SummaryFormat::Table => println!("{}", summary.to_table()),
+            SummaryFormat::Json => println!("{}", summary.to_json()),
+        }
+    }
+
+    // errors that are suppressed by the baseline or filtered by `--changed-since` still fail the
+    // compilation, only fail if there are other errors or if cargo failed for another reason
+    if status.success() || (errors > 0 && printed_errors == 0) {
+        Ok(())
+    } else {
+        Err(status.code().unwrap_or(-1))
+    }
+}
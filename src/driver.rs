@@ -21,10 +21,12 @@ use rustc_session::parse::ParseSess;
 use rustc_session::EarlyErrorHandler;
 use rustc_span::symbol::Symbol;
 
-use std::env;
+use clippy_lints::pass_timings::{self, PassCounts, PassTiming};
 use std::ops::Deref;
 use std::path::Path;
-use std::process::exit;
+use std::process::{self, exit};
+use std::time::Instant;
+use std::{env, fs};
 
 use anstream::println;
 
@@ -100,6 +102,32 @@ fn track_files(parse_sess: &mut ParseSess) {
     }
 }
 
+/// Writes the time spent in each lint pass to a JSON file in `dir`, the directory set in
+/// `CLIPPY_PASS_TIMINGS` by `cargo lintcheck --profile`
+fn write_pass_timings(dir: &Path, timings: &[PassTiming]) {
+    let crate_name = env::var("CARGO_CRATE_NAME").unwrap_or_else(|_| String::from("unknown"));
+    let path = dir.join(format!("{crate_name}-{}.json", process::id()));
+    let json = serde_json::to_string(timings).unwrap();
+    if let Err(e) = fs::create_dir_all(dir).and_then(|()| fs::write(&path, json)) {
+        eprintln!("error writing the lint pass timings to {}: {e}", path.display());
+    }
+}
+
+/// Prints the time spent in each lint pass and in reading the configuration, for
+/// `--time-lint-passes`
+fn print_pass_timings(timings: &[PassTiming]) {
+    let total: f64 = timings.iter().map(|timing| timing.secs).sum();
+    match env::var("CARGO_CRATE_NAME") {
+        Ok(crate_name) => eprintln!("time spent in Clippy's lint passes for `{crate_name}`:"),
+        Err(_) => eprintln!("time spent in Clippy's lint passes:"),
+    }
+    for timing in timings {
+        let share = if total > 0.0 { timing.secs / total * 100.0 } else { 0.0 };
+        eprintln!("{:>12.3}ms {share:>6.1}%  {}", timing.secs * 1000.0, timing.name);
+    }
+    eprintln!("{:>12.3}ms {:>6.1}%  total", total * 1000.0, 100.0);
+}
+
 struct DefaultCallbacks;
 impl rustc_driver::Callbacks for DefaultCallbacks {}
 
@@ -120,15 +148,36 @@ impl rustc_driver::Callbacks for RustcCallbacks {
 
 struct ClippyCallbacks {
     clippy_args_var: Option<String>,
+    /// Whether the lint passes are timed, for `--time-lint-passes` or `CLIPPY_PASS_TIMINGS`
+    time_lint_passes: bool,
 }
 
 impl rustc_driver::Callbacks for ClippyCallbacks {
     // JUSTIFICATION: necessary in clippy driver to set `mir_opt_level`
     #[allow(rustc::bad_opt_access)]
     fn config(&mut self, config: &mut interface::Config) {
-        let conf_path = clippy_config::lookup_conf_file();
+        // The configuration files are only parsed here, `Conf::read` reports their errors once the
+        // session is created
+        let conf_files = clippy_config::ConfFiles::read(clippy_config::lookup_conf_file());
+
+        // The levels from the `[lints]` table are added before the ones from the command line, so
+        // that `-A`/`-W`/`-D`/`-F` flags take precedence, and lint groups are added before lints so
+        // that the level of a single lint overrides the one of its groups. Unknown lints are
+        // reported against `clippy.toml` in `check_configured_lints`
+        let mut conf_lint_levels: Vec<_> = conf_files
+            .lint_levels()
+            .into_iter()
+            .filter_map(|(name, level)| match clippy_lints::renamed_lint(&name) {
+                Some(new_name) => Some((new_name.to_string(), level)),
+                None => clippy_lints::is_lint_or_group(&name).then_some((name, level)),
+            })
+            .collect();
+        conf_lint_levels.sort_by_key(|(name, _)| !clippy_lints::is_lint_group(name));
+        config.opts.lint_opts.splice(0..0, conf_lint_levels);
+
         let previous = config.register_lints.take();
         let clippy_args_var = self.clippy_args_var.take();
+        let time_lint_passes = self.time_lint_passes;
         config.parse_sess_created = Some(Box::new(move |parse_sess| {
             track_clippy_args(parse_sess, &clippy_args_var);
             track_files(parse_sess);
@@ -139,6 +188,16 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
                 Symbol::intern("CLIPPY_CONF_DIR"),
                 env::var("CLIPPY_CONF_DIR").ok().map(|dir| Symbol::intern(&dir)),
             ));
+            // Trigger a rebuild when switching to or from `cargo clippy --msrv-report`
+            parse_sess.env_depinfo.get_mut().insert((
+                Symbol::intern("CLIPPY_MSRV_REPORT"),
+                env::var("CLIPPY_MSRV_REPORT").ok().map(|value| Symbol::intern(&value)),
+            ));
+            // Trigger a rebuild when the lint pass timings are requested
+            parse_sess.env_depinfo.get_mut().insert((
+                Symbol::intern("CLIPPY_PASS_TIMINGS"),
+                env::var("CLIPPY_PASS_TIMINGS").ok().map(|value| Symbol::intern(&value)),
+            ));
         }));
         config.register_lints = Some(Box::new(move |sess, lint_store| {
             // technically we're ~guaranteed that this is none but might as well call anything that
@@ -147,10 +206,18 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
                 (previous)(sess, lint_store);
             }
 
-            let conf = clippy_config::Conf::read(sess, &conf_path);
+            let start = Instant::now();
+            let conf = clippy_config::Conf::read(sess, &conf_files);
+            let conf_read = start.elapsed();
+            let pass_counts = PassCounts::new(lint_store);
             clippy_lints::register_lints(lint_store, conf);
             clippy_lints::register_pre_expansion_lints(lint_store, conf);
+            if time_lint_passes {
+                pass_timings::record("Conf::read", conf_read);
+                pass_timings::instrument(lint_store, &pass_counts);
+            }
             clippy_lints::register_renamed(lint_store);
+            clippy_lints::check_configured_lints(sess, lint_store, conf);
         }));
 
         // FIXME: #4825; This is required, because Clippy lints that are based on MIR have to be
@@ -233,6 +300,13 @@ pub fn main() {
             exit(0);
         }
 
+        // `clippy-driver --time-lint-passes`, `cargo clippy` passes it in `CLIPPY_ARGS` instead
+        let mut time_lint_passes = false;
+        if let Some(pos) = orig_args.iter().position(|arg| arg == "--time-lint-passes") {
+            orig_args.remove(pos);
+            time_lint_passes = true;
+        }
+
         let mut args: Vec<String> = orig_args.clone();
         pass_sysroot_env_if_given(&mut args, sys_root_env);
 
@@ -248,6 +322,10 @@ pub fn main() {
                     no_deps = true;
                     None
                 },
+                "--time-lint-passes" => {
+                    time_lint_passes = true;
+                    None
+                },
                 _ => Some(s.to_string()),
             })
             .chain(vec!["--cfg".into(), r#"feature="cargo-clippy""#.into()])
@@ -265,9 +343,26 @@ pub fn main() {
         let clippy_enabled = !cap_lints_allow && (!no_deps || in_primary_package);
         if clippy_enabled {
             args.extend(clippy_args);
-            rustc_driver::RunCompiler::new(&args, &mut ClippyCallbacks { clippy_args_var })
+            let timings_dir = env::var_os("CLIPPY_PASS_TIMINGS");
+            let mut callbacks = ClippyCallbacks {
+                clippy_args_var,
+                time_lint_passes: time_lint_passes || timings_dir.is_some(),
+            };
+            let result = rustc_driver::RunCompiler::new(&args, &mut callbacks)
                 .set_using_internal_features(using_internal_features)
-                .run()
+                .run();
+
+            let timings = pass_timings::take();
+            // empty e.g. when cargo runs `clippy-driver rustc -vV`
+            if !timings.is_empty() {
+                if time_lint_passes {
+                    print_pass_timings(&timings);
+                }
+                if let Some(dir) = timings_dir {
+                    write_pass_timings(Path::new(&dir), &timings);
+                }
+            }
+            result
         } else {
             rustc_driver::RunCompiler::new(&args, &mut RustcCallbacks { clippy_args_var })
                 .set_using_internal_features(using_internal_features)
@@ -289,6 +384,7 @@ Run <cyan>clippy-driver</> with the same arguments you use for <cyan>rustc</>
     <cyan,bold>-h</>, <cyan,bold>--help</>               Print this message
     <cyan,bold>-V</>, <cyan,bold>--version</>            Print version info and exit
     <cyan,bold>--rustc</>                  Pass all arguments to <cyan>rustc</>
+    <cyan,bold>--time-lint-passes</>       Print the time spent in each lint pass and in reading the configuration
 
 <green,bold>Allowing / Denying lints</>
 You can use tool lints to allow or deny lints from your code, e.g.:
@@ -0,0 +1,589 @@
+//! `cargo clippy --fix` with `--interactive` or `--only` applies the suggestions itself instead of
+//! running `cargo fix`. The crates are checked with JSON diagnostics and the replacements of the
+//! selected suggestions are applied to the files, then they are checked again for the suggestions
+//! that only apply after the first ones, like `cargo fix` does. If the suggestions break the code,
+//! they are reverted.
+//!
+//! `--interactive` shows the suggestions one by one as a diff and only applies the ones that are
+//! accepted. Besides the `MachineApplicable` suggestions that `--fix` applies, the `MaybeIncorrect`
+//! ones are offered as well, they are never accepted by accepting all the suggestions of a lint.
+//!
+//! `--only` applies the suggestions of the given lints, the diagnostics of the others are still
+//! printed.
+//!
+//! Only the suggestions of Clippy lints are applied, the ones of rustc lints and errors are left to
+//! `cargo fix`.
+//!
+//! Like `cargo fix`, the files are only changed if the package is in a VCS repository without
+//! uncommitted changes, unless `--allow-no-vcs`, `--allow-dirty` or `--allow-staged` are passed.
+
+use crate::changed_lines::git;
+use crate::diagnostics::{self, Diagnostic};
+use anstream::{eprintln, print, println};
+use color_print::cformat;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+/// A replacement of a suggestion, the byte offsets are the ones in the file before any suggestion
+/// is applied
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Replacement {
+    file: PathBuf,
+    byte_start: usize,
+    byte_end: usize,
+    text: String,
+}
+
+struct Suggestion {
+    /// The lint of the diagnostic, e.g. `clippy::needless_return`
+    lint: String,
+    message: String,
+    /// The message of the suggestion itself, e.g. remove `return`
+    help: String,
+    /// Where the diagnostic points at, as `file:line:column`
+    location: String,
+    maybe_incorrect: bool,
+    replacements: Vec<Replacement>,
+}
+
+impl Suggestion {
+    /// Converts `child`, a child of `diag` with replacements, to a suggestion if `diag` is emitted
+    /// by a Clippy lint and all the replacements are `MachineApplicable` or `MaybeIncorrect`
+    fn new(diag: &Diagnostic, child: &Diagnostic, root: &Path) -> Option<Self> {
+        let lint = &diag.code.as_ref()?.code;
+        if !lint.starts_with("clippy::") {
+            return None;
+        }
+        let span = diag.primary_span()?;
+
+        let mut maybe_incorrect = false;
+        let mut replacements = Vec::new();
+        for span in &child.spans {
+            let Some(text) = &span.suggested_replacement else {
+                continue;
+            };
+            match span.suggestion_applicability.as_deref() {
+                Some("MachineApplicable") => {},
+                Some("MaybeIncorrect") => maybe_incorrect = true,
+                _ => return None,
+            }
+            replacements.push(Replacement {
+                file: root.join(&span.file_name),
+                byte_start: span.byte_start,
+                byte_end: span.byte_end,
+                text: text.clone(),
+            });
+        }
+        if replacements.is_empty() {
+            return None;
+        }
+        replacements.sort_by(|a, b| (&a.file, a.byte_start).cmp(&(&b.file, b.byte_start)));
+
+        Some(Self {
+            lint: lint.clone(),
+            message: diag.message.clone(),
+            help: child.message.clone(),
+            location: format!("{}:{}:{}", span.file_name, span.line_start, span.column_start),
+            maybe_incorrect,
+            replacements,
+        })
+    }
+}
+
+/// What to do with the remaining suggestions of a lint
+#[derive(Clone, Copy)]
+enum Decision {
+    AcceptAll,
+    SkipAll,
+}
+
+enum Answer {
+    Accept,
+    Skip,
+    Remember(Decision),
+    Quit,
+}
+
+/// How `cargo clippy --fix` selects the suggestions to apply
+#[derive(Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Options {
+    /// Review the suggestions one by one
+    pub interactive: bool,
+    /// Only apply the suggestions of these lints, e.g. `clippy::needless_borrow`
+    pub only: Option<HashSet<String>>,
+    /// `--allow-dirty`, change the files even if they have uncommitted changes
+    pub allow_dirty: bool,
+    /// `--allow-staged`, change the files even if they have staged changes
+    pub allow_staged: bool,
+    /// `--allow-no-vcs`, change the files even if the package isn't in a VCS repository
+    pub allow_no_vcs: bool,
+}
+
+impl Options {
+    /// Whether `cargo fix` can be used, as `cargo clippy` doesn't have to select the suggestions
+    pub fn is_empty(&self) -> bool {
+        !self.interactive && self.only.is_none()
+    }
+}
+
+/// Resolves the lints and lint groups of `--only`, e.g. `clippy::style,clippy::needless_return`, to
+/// the lints that make up the groups
+pub fn parse_only(list: &str) -> Result<HashSet<String>, String> {
+    let lints = clippy_lints::lint_metadata();
+    let mut only = HashSet::new();
+    for name in list.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+        let name = name.to_ascii_lowercase().replace('-', "_");
+        let Some(clippy_name) = name.strip_prefix("clippy::") else {
+            return Err(format!(
+                "`{name}` is not a Clippy lint, only the suggestions of Clippy lints can be applied"
+            ));
+        };
+
+        let len = only.len();
+        only.extend(
+            lints
+                .iter()
+                .filter(|lint| {
+                    lint.name == clippy_name
+                        || lint.group == clippy_name
+                        || (clippy_name == "all" && DEFAULT_GROUPS.contains(&lint.group))
+                })
+                .map(|lint| format!("clippy::{}", lint.name)),
+        );
+        if only.len() == len && !only.contains(&name) {
+            return Err(format!("unknown lint or lint group `{name}`"));
+        }
+    }
+
+    if only.is_empty() {
+        return Err("`--only` requires a list of lints".into());
+    }
+    Ok(only)
+}
+
+/// The groups that make up `clippy::all`
+const DEFAULT_GROUPS: [&str; 5] = ["correctness", "suspicious", "style", "complexity", "perf"];
+
+/// The maximal number of times the crates are checked and fixed
+const MAX_PASSES: usize = 4;
+
+/// The diagnostics of a check of the crates
+struct Check {
+    status: ExitStatus,
+    diagnostics: Vec<Diagnostic>,
+    suggestions: Vec<Suggestion>,
+    /// The number of errors, e.g. compilation errors or the lints that are denied
+    errors: usize,
+}
+
+impl Check {
+    /// Runs `cmd` and collects the suggestions that `options` select
+    fn run(cmd: Command, root: &Path, options: &Options) -> Self {
+        // the same diagnostic is emitted for every target that contains the code, e.g. the lib and
+        // its unit tests
+        let mut seen = HashSet::new();
+        let mut diagnostics = Vec::new();
+        let mut suggestions = Vec::new();
+        let mut errors = 0;
+        let status = diagnostics::run(cmd, |diag| {
+            if diag.is_summary() || !seen.insert(diag.rendered.clone()) {
+                return;
+            }
+
+            errors += usize::from(diag.is_error());
+            suggestions.extend(
+                diag.children
+                    .iter()
+                    .filter_map(|child| Suggestion::new(&diag, child, root))
+                    .filter(|suggestion| {
+                        (options.interactive || !suggestion.maybe_incorrect)
+                            && options
+                                .only
+                                .as_ref()
+                                .map_or(true, |only| only.contains(&suggestion.lint))
+                    }),
+            );
+            diagnostics.push(diag);
+        })
+        .expect("could not run cargo");
+
+        // the suggestions of diagnostics that differ only in their notes, e.g. the ones emitted
+        // for the lib and for its tests with a different `cfg(test)`, are the same
+        let mut seen = HashSet::new();
+        suggestions.retain(|suggestion| seen.insert(suggestion.replacements.clone()));
+        suggestions.sort_by(|a, b| {
+            let (a, b) = (&a.replacements[0], &b.replacements[0]);
+            (&a.file, a.byte_start).cmp(&(&b.file, b.byte_start))
+        });
+
+        Self {
+            status,
+            diagnostics,
+            suggestions,
+            errors,
+        }
+    }
+
+    /// Prints the diagnostics and returns the exit code of cargo
+    fn finish(&self) -> Result<(), i32> {
+        for diag in &self.diagnostics {
+            diag.print();
+        }
+        if self.status.success() {
+            Ok(())
+        } else {
+            Err(self.status.code().unwrap_or(-1))
+        }
+    }
+}
+
+/// Checks the crates with the commands returned by `cmd`, then applies the suggestions selected by
+/// `options` until there are none left
+pub fn process(mut cmd: impl FnMut() -> Command, options: &Options) -> Result<(), i32> {
+    let root = diagnostics::workspace_root();
+    let report = |e: String| {
+        eprintln!("error: {e}");
+        1
+    };
+    check_version_control(options).map_err(report)?;
+
+    let mut fixes: BTreeMap<PathBuf, usize> = BTreeMap::new();
+    // the sources before the last fixes and the number of fixes by file, to revert them if they
+    // break the code
+    let mut last_pass: Option<(HashMap<PathBuf, String>, BTreeMap<PathBuf, usize>)> = None;
+    let mut done = false;
+    let mut pass = 0;
+    let result = loop {
+        pass += 1;
+        let mut check = Check::run(cmd(), &root, options);
+
+        if check.errors > 0 {
+            let Some((sources, last_fixes)) = last_pass.take() else {
+                for diag in &check.diagnostics {
+                    diag.print();
+                }
+                return Err(report(
+                    "the suggestions can only be applied once the code compiles without errors".into(),
+                ));
+            };
+            for diag in check.diagnostics.iter().filter(|diag| diag.is_error()) {
+                diag.print();
+            }
+            eprintln!("warning: the last suggestions broke the code, they were reverted");
+            for (file, source) in &sources {
+                fs::write(file, source).map_err(|e| report(format!("couldn't write `{}`: {e}", file.display())))?;
+            }
+            for (file, count) in last_fixes {
+                *fixes.get_mut(&file).unwrap() -= count;
+            }
+            done = true;
+            continue;
+        }
+
+        let sources = read_sources(&mut check.suggestions, &root);
+        if done || pass > MAX_PASSES || check.suggestions.is_empty() {
+            break check.finish();
+        }
+
+        let accepted = if options.interactive {
+            // the suggestions are only reviewed once, the next check is only for the remaining
+            // diagnostics
+            done = true;
+            review(&check.suggestions, &sources, &mut io::stdin().lock()).map_err(|e| report(e.to_string()))?
+        } else {
+            let mut accepted: Vec<&Suggestion> = Vec::new();
+            for suggestion in &check.suggestions {
+                if !overlaps(suggestion, &accepted) {
+                    accepted.push(suggestion);
+                }
+            }
+            accepted
+        };
+        if accepted.is_empty() {
+            break check.finish();
+        }
+
+        let last_fixes = apply(&accepted, &sources).map_err(report)?;
+        for (file, count) in &last_fixes {
+            *fixes.entry(file.clone()).or_default() += count;
+        }
+        let sources = sources
+            .into_iter()
+            .filter(|(file, _)| last_fixes.contains_key(file))
+            .collect();
+        last_pass = Some((sources, last_fixes));
+    };
+
+    fixes.retain(|_, count| *count > 0);
+    if fixes.is_empty() {
+        println!("No suggestions were applied");
+    }
+    for (file, count) in fixes {
+        println!(
+            "{}",
+            cformat!(
+                "<green,bold>Fixed</> {} ({count} {})",
+                file.strip_prefix(&root).unwrap_or(&file).display(),
+                if count == 1 { "fix" } else { "fixes" }
+            )
+        );
+    }
+    result
+}
+
+/// Checks that the files can be changed without losing uncommitted work, like `cargo fix` does
+fn check_version_control(options: &Options) -> Result<(), String> {
+    if options.allow_no_vcs {
+        return Ok(());
+    }
+
+    let dir = Path::new(".");
+    // the packages in an ignored directory of a git repository are not versioned
+    let in_git = git(dir, &["rev-parse", "--show-toplevel"]).is_ok() && git(dir, &["check-ignore", "-q", "."]).is_err();
+    let in_hg = || {
+        Command::new("hg")
+            .arg("root")
+            .output()
+            .is_ok_and(|output| output.status.success())
+    };
+    if !in_git && !in_hg() {
+        return Err(
+            "no VCS found for this package and `cargo clippy --fix` can potentially perform destructive \
+             changes; if you'd like to suppress this error pass `--allow-no-vcs`"
+                .into(),
+        );
+    }
+    if !in_git || (options.allow_dirty && options.allow_staged) {
+        return Ok(());
+    }
+
+    let mut files = String::new();
+    for line in git(dir, &["status", "--porcelain", "--untracked-files=all"])?.lines() {
+        let (Some(status), Some(path)) = (line.get(..2), line.get(3..)) else {
+            continue;
+        };
+        // the first character is the status in the index, the second the one in the working tree
+        if status.as_bytes()[1] != b' ' {
+            if !options.allow_dirty {
+                files.push_str(&format!("  * {path} (dirty)\n"));
+            }
+        } else if !options.allow_staged {
+            files.push_str(&format!("  * {path} (staged)\n"));
+        }
+    }
+
+    if files.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "the working directory of this package has uncommitted changes, and `cargo clippy --fix` can \
+             potentially perform destructive changes; if you'd like to suppress this error pass `--allow-dirty`, \
+             `--allow-staged`, or commit the changes to these files:\n\n{files}"
+        ))
+    }
+}
+
+/// Reads the files the suggestions change, the suggestions for files outside of the workspace, e.g.
+/// in the standard library, and the ones whose offsets don't match the file are removed
+fn read_sources(suggestions: &mut Vec<Suggestion>, root: &Path) -> HashMap<PathBuf, String> {
+    let mut sources = HashMap::new();
+    suggestions.retain(|suggestion| {
+        suggestion.replacements.iter().all(|replacement| {
+            if !replacement.file.starts_with(root) {
+                return false;
+            }
+            if !sources.contains_key(&replacement.file) {
+                let Ok(source) = fs::read_to_string(&replacement.file) else {
+                    return false;
+                };
+                sources.insert(replacement.file.clone(), source);
+            }
+            let source = &sources[&replacement.file];
+            replacement.byte_start <= replacement.byte_end
+                && source.is_char_boundary(replacement.byte_start)
+                && source.is_char_boundary(replacement.byte_end)
+        })
+    });
+    sources
+}
+
+/// Shows the suggestions one by one and asks whether to apply them, returns the accepted ones
+fn review<'a>(
+    suggestions: &'a [Suggestion],
+    sources: &HashMap<PathBuf, String>,
+    input: &mut impl BufRead,
+) -> io::Result<Vec<&'a Suggestion>> {
+    let mut decisions: HashMap<&str, Decision> = HashMap::new();
+    let mut accepted: Vec<&Suggestion> = Vec::new();
+
+    for (i, suggestion) in suggestions.iter().enumerate() {
+        if overlaps(suggestion, &accepted) {
+            continue;
+        }
+
+        let answer = match decisions.get(suggestion.lint.as_str()) {
+            Some(Decision::AcceptAll) if !suggestion.maybe_incorrect => Answer::Accept,
+            Some(Decision::SkipAll) => Answer::Skip,
+            _ => {
+                show(suggestion, sources, i + 1, suggestions.len());
+                ask(&suggestion.lint, input)?
+            },
+        };
+
+        match answer {
+            Answer::Accept => {},
+            Answer::Skip => continue,
+            Answer::Remember(decision) => {
+                decisions.insert(&suggestion.lint, decision);
+                if let Decision::SkipAll = decision {
+                    continue;
+                }
+            },
+            Answer::Quit => break,
+        }
+        accepted.push(suggestion);
+    }
+
+    Ok(accepted)
+}
+
+/// Whether `suggestion` overlaps with one of the `accepted` ones. The byte offsets are the ones of
+/// the files before the suggestions are applied, so only one of them can be applied.
+fn overlaps(suggestion: &Suggestion, accepted: &[&Suggestion]) -> bool {
+    suggestion.replacements.iter().any(|replacement| {
+        accepted
+            .iter()
+            .flat_map(|accepted| &accepted.replacements)
+            .any(|other| {
+                other.file == replacement.file
+                    && (replacement.byte_start < other.byte_end && other.byte_start < replacement.byte_end
+                        || replacement.byte_start == other.byte_start)
+            })
+    })
+}
+
+/// Prints the lint, the message and the diff of `suggestion`
+fn show(suggestion: &Suggestion, sources: &HashMap<PathBuf, String>, index: usize, total: usize) {
+    println!();
+    println!(
+        "{}",
+        cformat!(
+            "<bold>[{index}/{total}]</> <yellow,bold>{}</>: <bold>{}</>",
+            suggestion.lint,
+            suggestion.message
+        )
+    );
+    println!("{}", cformat!("  <blue,bold>--></> {}", suggestion.location));
+    if suggestion.maybe_incorrect {
+        println!("{}", cformat!("  <red,bold>maybe incorrect</>: {}", suggestion.help));
+    } else {
+        println!("  {}", suggestion.help);
+    }
+
+    let mut start = 0;
+    while start < suggestion.replacements.len() {
+        // the replacements are sorted by file
+        let file = &suggestion.replacements[start].file;
+        let end = start
+            + suggestion.replacements[start..]
+                .iter()
+                .take_while(|replacement| &replacement.file == file)
+                .count();
+        print_diff(&sources[file], &suggestion.replacements[start..end]);
+        start = end;
+    }
+}
+
+/// Prints the lines that `replacements`, which are in the same file, change before and after they
+/// are applied
+fn print_diff(source: &str, replacements: &[Replacement]) {
+    let first = replacements[0].byte_start;
+    let last = replacements.iter().map(|r| r.byte_end).max().unwrap();
+    let start = source[..first].rfind('\n').map_or(0, |i| i + 1);
+    let end = source[last..].find('\n').map_or(source.len(), |i| last + i);
+
+    let mut new = String::new();
+    let mut pos = start;
+    for replacement in replacements {
+        new.push_str(&source[pos..replacement.byte_start]);
+        new.push_str(&replacement.text);
+        pos = replacement.byte_end;
+    }
+    new.push_str(&source[pos..end]);
+
+    println!();
+    for line in source[start..end].lines() {
+        println!("{}", cformat!("<red>- {}</>", line));
+    }
+    for line in new.lines() {
+        println!("{}", cformat!("<green>+ {}</>", line));
+    }
+    println!();
+}
+
+fn ask(lint: &str, input: &mut impl BufRead) -> io::Result<Answer> {
+    loop {
+        print!(
+            "{}",
+            cformat!(
+                "Apply this suggestion? <bold>[y]</>es, <bold>[n]</>o, <bold>[a]</>ll `{lint}` suggestions, \
+                 <bold>[s]</>kip all `{lint}` suggestions, <bold>[q]</>uit: "
+            )
+        );
+        io::stdout().flush()?;
+
+        let mut answer = String::new();
+        // the input ended, e.g. it was piped from a file
+        if input.read_line(&mut answer)? == 0 {
+            println!();
+            return Ok(Answer::Quit);
+        }
+        match answer.trim() {
+            "y" | "yes" => return Ok(Answer::Accept),
+            "n" | "no" => return Ok(Answer::Skip),
+            "a" | "all" => return Ok(Answer::Remember(Decision::AcceptAll)),
+            "s" | "skip" => return Ok(Answer::Remember(Decision::SkipAll)),
+            "q" | "quit" => return Ok(Answer::Quit),
+            _ => {},
+        }
+    }
+}
+
+/// Applies the accepted suggestions to the files, returns the number of suggestions applied to
+/// each file
+fn apply(accepted: &[&Suggestion], sources: &HashMap<PathBuf, String>) -> Result<BTreeMap<PathBuf, usize>, String> {
+    let mut fixes: BTreeMap<PathBuf, usize> = BTreeMap::new();
+    let mut replacements: BTreeMap<&Path, Vec<&Replacement>> = BTreeMap::new();
+    for suggestion in accepted {
+        let mut counted = HashSet::new();
+        for replacement in &suggestion.replacements {
+            if counted.insert(&replacement.file) {
+                *fixes.entry(replacement.file.clone()).or_default() += 1;
+            }
+            replacements.entry(&replacement.file).or_default().push(replacement);
+        }
+    }
+
+    for (file, mut replacements) in replacements {
+        let mut source = fs::read_to_string(file).map_err(|e| format!("couldn't read `{}`: {e}", file.display()))?;
+        if source != sources[file] {
+            return Err(format!(
+                "`{}` changed while the suggestions were reviewed",
+                file.display()
+            ));
+        }
+
+        // the later replacements are applied first so that the offsets of the others stay valid
+        replacements.sort_by_key(|replacement| Reverse(replacement.byte_start));
+        for replacement in replacements {
+            source.replace_range(replacement.byte_start..replacement.byte_end, &replacement.text);
+        }
+        fs::write(file, source).map_err(|e| format!("couldn't write `{}`: {e}", file.display()))?;
+    }
+    Ok(fixes)
+}
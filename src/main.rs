@@ -6,7 +6,14 @@ use std::env;
 use std::path::PathBuf;
 use std::process::{self, Command};
 
-use anstream::println;
+use anstream::{eprintln, println};
+
+mod baseline;
+mod changed_lines;
+mod diagnostics;
+mod fix;
+mod msrv_report;
+mod sarif;
 
 #[allow(clippy::ignored_unit_patterns)]
 fn show_help() {
@@ -34,47 +41,130 @@ pub fn main() {
     if let Some(pos) = env::args().position(|a| a == "--explain") {
         if let Some(mut lint) = env::args().nth(pos + 1) {
             lint.make_ascii_lowercase();
-            process::exit(clippy_lints::explain(
-                &lint.strip_prefix("clippy::").unwrap_or(&lint).replace('-', "_"),
-            ));
+            let lint = lint.strip_prefix("clippy::").unwrap_or(&lint).replace('-', "_");
+            process::exit(match output_format() {
+                Ok(Format::Text) => clippy_lints::explain(&lint),
+                Ok(Format::Json) => explain_json(&lint),
+                Err(code) => code,
+            });
         } else {
             show_help();
         }
         return;
     }
 
+    if env::args().any(|a| a == "--list-lints") {
+        process::exit(match output_format() {
+            Ok(format) => list_lints(format),
+            Err(code) => code,
+        });
+    }
+
     if let Err(code) = process(env::args().skip(2)) {
         process::exit(code);
     }
 }
 
+#[derive(Clone, Copy)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// Reads the `--format` flag of `--explain` and `--list-lints`
+fn output_format() -> Result<Format, i32> {
+    let mut args = env::args();
+    let mut format = None;
+    while let Some(arg) = args.next() {
+        if arg == "--format" {
+            format = args.next();
+        } else if let Some(value) = arg.strip_prefix("--format=") {
+            format = Some(value.to_string());
+        }
+    }
+
+    match format.as_deref() {
+        None | Some("text") => Ok(Format::Text),
+        Some("json") => Ok(Format::Json),
+        Some(format) => {
+            eprintln!("error: unknown format `{format}`, expected `text` or `json`");
+            Err(1)
+        },
+    }
+}
+
+fn explain_json(name: &str) -> i32 {
+    if let Some(lint) = clippy_lints::lint_metadata().into_iter().find(|lint| lint.name == name) {
+        println!("{}", serde_json::to_string_pretty(&lint).unwrap());
+        0
+    } else {
+        eprintln!("unknown lint: {name}");
+        1
+    }
+}
+
+fn list_lints(format: Format) -> i32 {
+    let lints = clippy_lints::lint_metadata();
+    match format {
+        Format::Text => {
+            for lint in lints {
+                println!("{:<45} {:<12} {}", lint.name, lint.group, lint.default_level);
+            }
+        },
+        Format::Json => println!("{}", serde_json::to_string_pretty(&lints).unwrap()),
+    }
+    0
+}
+
 struct ClippyCmd {
     cargo_subcommand: &'static str,
     args: Vec<String>,
     clippy_args: Vec<String>,
+    diagnostics: diagnostics::Options,
+    /// How `--fix` selects the suggestions to apply, see [`fix`]
+    fix: fix::Options,
 }
 
 impl ClippyCmd {
-    fn new<I>(mut old_args: I) -> Self
+    fn new<I>(mut old_args: I) -> Result<Self, String>
     where
         I: Iterator<Item = String>,
     {
         let mut cargo_subcommand = "check";
         let mut args = vec![];
         let mut clippy_args: Vec<String> = vec![];
+        let mut diagnostics = diagnostics::Options::default();
+        let mut fix = fix::Options::default();
 
-        for arg in old_args.by_ref() {
+        while let Some(arg) = old_args.next() {
             match arg.as_str() {
                 "--fix" => {
                     cargo_subcommand = "fix";
                     continue;
                 },
-                "--no-deps" => {
-                    clippy_args.push("--no-deps".into());
+                "--interactive" => {
+                    fix.interactive = true;
+                    continue;
+                },
+                "--only" => {
+                    let lints = old_args.next().ok_or("`--only` requires a list of lints")?;
+                    fix.only = Some(fix::parse_only(&lints)?);
+                    continue;
+                },
+                "--no-deps" | "--time-lint-passes" => {
+                    clippy_args.push(arg);
                     continue;
                 },
                 "--" => break,
-                _ => {},
+                _ => {
+                    if let Some(lints) = arg.strip_prefix("--only=") {
+                        fix.only = Some(fix::parse_only(lints)?);
+                        continue;
+                    }
+                    if diagnostics.parse_flag(&arg, &mut old_args)? {
+                        continue;
+                    }
+                },
             }
 
             args.push(arg);
@@ -85,11 +175,42 @@ impl ClippyCmd {
             clippy_args.push("--no-deps".into());
         }
 
-        Self {
+        if !diagnostics.is_empty() {
+            const FLAGS: &str =
+                "`--baseline`, `--write-baseline`, `--sarif`, `--msrv-report`, `--changed-since` or `--summary`";
+            if cargo_subcommand == "fix" {
+                return Err(format!("`--fix` can't be combined with {FLAGS}"));
+            }
+            if args.iter().any(|arg| arg.starts_with("--message-format")) {
+                return Err(format!("`--message-format` can't be combined with {FLAGS}"));
+            }
+        }
+        if diagnostics.msrv_report {
+            if diagnostics.baseline.is_some() || diagnostics.sarif.is_some() {
+                return Err(
+                    "`--msrv-report` can't be combined with `--baseline`, `--write-baseline` or `--sarif`".into(),
+                );
+            }
+            // the lints that are no longer suppressed must not fail the compilation, so that the
+            // crates that depend on the current one are linted as well
+            clippy_args.push("--cap-lints=warn".into());
+        }
+
+        if !fix.is_empty() {
+            if cargo_subcommand != "fix" {
+                return Err("`--interactive` and `--only` require `--fix`".into());
+            }
+            cargo_subcommand = "check";
+            check_args_for_fix(&mut args, &mut fix);
+        }
+
+        Ok(Self {
             cargo_subcommand,
             args,
             clippy_args,
-        }
+            diagnostics,
+            fix,
+        })
     }
 
     fn path() -> PathBuf {
@@ -104,7 +225,7 @@ impl ClippyCmd {
         path
     }
 
-    fn into_std_cmd(self) -> Command {
+    fn to_std_cmd(&self) -> Command {
         let mut cmd = Command::new("cargo");
         let clippy_args: String = self
             .clippy_args
@@ -124,13 +245,68 @@ impl ClippyCmd {
     }
 }
 
+/// The suggestions of `--interactive` and `--only` are applied by `cargo clippy` instead of `cargo
+/// fix`, the crates are checked with `cargo check`. The flags of `cargo fix` that allow changing
+/// files with uncommitted changes are moved to `fix`, `--broken-code` is not used.
+fn check_args_for_fix(args: &mut Vec<String>, fix: &mut fix::Options) {
+    args.retain(|arg| match arg.as_str() {
+        "--allow-dirty" => {
+            fix.allow_dirty = true;
+            false
+        },
+        "--allow-staged" => {
+            fix.allow_staged = true;
+            false
+        },
+        "--allow-no-vcs" => {
+            fix.allow_no_vcs = true;
+            false
+        },
+        "--broken-code" => false,
+        _ => true,
+    });
+    if !args.iter().any(|arg| is_target_selection(arg)) {
+        args.push("--all-targets".into());
+    }
+}
+
+/// Whether `arg` selects the targets to check, like `--lib` or `--bin=name`
+fn is_target_selection(arg: &str) -> bool {
+    let flag = arg.split('=').next().unwrap_or(arg);
+    matches!(
+        flag,
+        "--lib"
+            | "--bin"
+            | "--bins"
+            | "--example"
+            | "--examples"
+            | "--test"
+            | "--tests"
+            | "--bench"
+            | "--benches"
+            | "--all-targets"
+    )
+}
+
 fn process<I>(old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
 {
-    let cmd = ClippyCmd::new(old_args);
+    let mut cmd = ClippyCmd::new(old_args).map_err(|e| {
+        eprintln!("error: {e}");
+        1
+    })?;
 
-    let mut cmd = cmd.into_std_cmd();
+    if !cmd.fix.is_empty() {
+        return fix::process(|| cmd.to_std_cmd(), &cmd.fix);
+    }
+
+    if !cmd.diagnostics.is_empty() {
+        let options = std::mem::take(&mut cmd.diagnostics);
+        return diagnostics::process(cmd.to_std_cmd(), options);
+    }
+
+    let mut cmd = cmd.to_std_cmd();
 
     let exit_status = cmd
         .spawn()
@@ -156,9 +332,28 @@ pub fn help_message() -> &'static str {
 <green,bold>Common options:</>
     <cyan,bold>--no-deps</>                Run Clippy only on the given crate, without linting the dependencies
     <cyan,bold>--fix</>                    Automatically apply lint suggestions. This flag implies <cyan>--no-deps</> and <cyan>--all-targets</>
+    <cyan,bold>--interactive</>            With <cyan>--fix</>, show each suggestion as a diff and ask whether to apply it,
+                             including the ones that may be incorrect
+    <cyan,bold>--only [LINTS]</>           With <cyan>--fix</>, only apply the suggestions of these comma separated lints or
+                             lint groups, e.g. <cyan>clippy::needless_borrow,clippy::style</>
+    <cyan,bold>--write-baseline [FILE]</>  Record the current Clippy diagnostics in a baseline file
+    <cyan,bold>--baseline [FILE]</>        Only print the diagnostics that are not in the baseline file, and list the ones
+                             of the baseline that no longer occur
+    <cyan,bold>--sarif [FILE]</>           Write the Clippy diagnostics to a SARIF file
+    <cyan,bold>--changed-since [REV]</>    Only print the lints on the lines that changed since the git revision, e.g.
+                             the base branch of a pull request
+    <cyan,bold>--summary[=table|json]</>   Print the number of Clippy diagnostics of each lint, group, crate and file,
+                             and how many can be fixed automatically
+    <cyan,bold>--msrv-report</>            List the lints that are suppressed because the MSRV is lower than the Rust
+                             version they require, instead of printing the diagnostics
+    <cyan,bold>--time-lint-passes</>       Print the time spent in each lint pass and in reading the configuration for
+                             every crate that is checked
     <cyan,bold>-h</>, <cyan,bold>--help</>               Print this message
     <cyan,bold>-V</>, <cyan,bold>--version</>            Print version info and exit
     <cyan,bold>--explain [LINT]</>         Print the documentation for a given lint
+    <cyan,bold>--list-lints</>             Print the name, group and default level of all lints
+    <cyan,bold>--format [text|json]</>     Output format of <cyan>--explain</> and <cyan>--list-lints</>, <cyan>json</> prints all
+                             the metadata of the lints
 
 See all options with <cyan,bold>cargo check --help</>.
 
@@ -180,11 +375,12 @@ You can use tool lints to allow or deny lints from your code, e.g.:
 #[cfg(test)]
 mod tests {
     use super::ClippyCmd;
+    use crate::diagnostics::SummaryFormat;
 
     #[test]
     fn fix() {
         let args = "cargo clippy --fix".split_whitespace().map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert_eq!("fix", cmd.cargo_subcommand);
         assert!(!cmd.args.iter().any(|arg| arg.ends_with("unstable-options")));
     }
@@ -192,23 +388,95 @@ mod tests {
     #[test]
     fn fix_implies_no_deps() {
         let args = "cargo clippy --fix".split_whitespace().map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert!(cmd.clippy_args.iter().any(|arg| arg == "--no-deps"));
     }
 
+    #[test]
+    fn time_lint_passes() {
+        let args = "cargo clippy --time-lint-passes --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.clippy_args, ["--time-lint-passes"]);
+        assert!(cmd.args.iter().all(|arg| arg != "--time-lint-passes"));
+    }
+
+    #[test]
+    fn interactive() {
+        let args = "cargo clippy --fix --interactive --allow-dirty"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert!(cmd.fix.interactive);
+        assert_eq!("check", cmd.cargo_subcommand);
+        assert!(cmd.args.iter().any(|arg| arg == "--all-targets"));
+        assert!(!cmd.args.iter().any(|arg| arg == "--allow-dirty"));
+        assert!(cmd.fix.allow_dirty);
+        assert!(!cmd.fix.allow_staged);
+
+        let args = "cargo clippy --interactive".split_whitespace().map(ToString::to_string);
+        assert!(ClippyCmd::new(args).is_err());
+    }
+
+    #[test]
+    fn only() {
+        let args = "cargo clippy --fix --only clippy::needless_return,clippy::style"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        let only = cmd.fix.only.unwrap();
+        assert!(only.contains("clippy::needless_return"));
+        assert!(only.contains("clippy::len_zero"));
+        assert!(!only.contains("clippy::clone_on_ref_ptr"));
+        assert_eq!("check", cmd.cargo_subcommand);
+
+        let args = "cargo clippy --fix --only clippy::unknown_lint"
+            .split_whitespace()
+            .map(ToString::to_string);
+        assert!(ClippyCmd::new(args).is_err());
+        let args = "cargo clippy --fix --only unused_mut"
+            .split_whitespace()
+            .map(ToString::to_string);
+        assert!(ClippyCmd::new(args).is_err());
+        let args = "cargo clippy --only clippy::style"
+            .split_whitespace()
+            .map(ToString::to_string);
+        assert!(ClippyCmd::new(args).is_err());
+    }
+
+    #[test]
+    fn summary() {
+        let args = "cargo clippy --summary=json --all-targets"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args).unwrap();
+        assert_eq!(cmd.diagnostics.summary, Some(SummaryFormat::Json));
+        assert!(!cmd.args.iter().any(|arg| arg.starts_with("--summary")));
+
+        let args = "cargo clippy --summary=yaml"
+            .split_whitespace()
+            .map(ToString::to_string);
+        assert!(ClippyCmd::new(args).is_err());
+        let args = "cargo clippy --fix --summary"
+            .split_whitespace()
+            .map(ToString::to_string);
+        assert!(ClippyCmd::new(args).is_err());
+    }
+
     #[test]
     fn no_deps_not_duplicated_with_fix() {
         let args = "cargo clippy --fix -- --no-deps"
             .split_whitespace()
             .map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert_eq!(cmd.clippy_args.iter().filter(|arg| *arg == "--no-deps").count(), 1);
     }
 
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
-        let cmd = ClippyCmd::new(args);
+        let cmd = ClippyCmd::new(args).unwrap();
         assert_eq!("check", cmd.cargo_subcommand);
     }
 }
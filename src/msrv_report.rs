@@ -0,0 +1,92 @@
+//! `--msrv-report` lists the lints that are suppressed because the MSRV is lower than the Rust
+//! version their suggestions require.
+//!
+//! Clippy runs with `CLIPPY_MSRV_REPORT` set, then the lints gated on the MSRV are not suppressed
+//! and their diagnostics have a note with the MSRV and the version they require.
+
+use crate::diagnostics::Diagnostic;
+use clippy_config::msrvs::MSRV_REPORT_NOTE;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A Rust version as `(major, minor, patch)`, so that it's ordered numerically
+type Version = (u64, u64, u64);
+
+pub struct MsrvReport {
+    /// The names of the lints that are gated on the MSRV, e.g. `clippy::manual_let_else`
+    gated: BTreeSet<String>,
+    /// The number of suppressed diagnostics by required version and lint
+    suppressed: BTreeMap<(Version, String), usize>,
+}
+
+impl MsrvReport {
+    pub fn new() -> Self {
+        let gated = clippy_lints::lint_metadata()
+            .into_iter()
+            .filter(|lint| lint.msrv_gated)
+            .map(|lint| format!("clippy::{}", lint.name))
+            .collect();
+        Self {
+            gated,
+            suppressed: BTreeMap::new(),
+        }
+    }
+
+    /// Records `diag` if it would be suppressed by the MSRV, returns whether it was
+    pub fn add(&mut self, diag: &Diagnostic) -> bool {
+        let Some(lint) = diag.clippy_lint().filter(|lint| self.gated.contains(*lint)) else {
+            return false;
+        };
+        let Some(required) = diag
+            .children
+            .iter()
+            .find_map(|child| child.message.strip_prefix(MSRV_REPORT_NOTE))
+            .and_then(|note| note.rsplit('`').nth(1))
+            .and_then(parse_version)
+        else {
+            return false;
+        };
+
+        *self.suppressed.entry((required, lint.to_string())).or_default() += 1;
+        true
+    }
+
+    pub fn print(&self) {
+        let Some(((highest, _), _)) = self.suppressed.last_key_value() else {
+            println!("No lints are suppressed by the MSRV");
+            return;
+        };
+
+        println!("Lints suppressed by the MSRV, by the Rust version that enables them:");
+        for ((required, lint), count) in &self.suppressed {
+            println!("    {}  {lint} ({})", display(*required), plural(*count, "diagnostic"));
+        }
+
+        let lints: BTreeSet<_> = self.suppressed.keys().map(|(_, lint)| lint).collect();
+        println!(
+            "Raising the MSRV to {} enables {} with {}",
+            display(*highest),
+            plural(lints.len(), "lint"),
+            plural(self.suppressed.values().sum(), "diagnostic")
+        );
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{count} {noun}")
+    } else {
+        format!("{count} {noun}s")
+    }
+}
+
+fn parse_version(version: &str) -> Option<Version> {
+    let mut parts = version.split('.').map(str::parse);
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor)), patch, None) => Some((major, minor, patch.unwrap_or(Ok(0)).ok()?)),
+        _ => None,
+    }
+}
+
+fn display((major, minor, patch): Version) -> String {
+    format!("{major}.{minor}.{patch}")
+}
@@ -0,0 +1,196 @@
+//! `--sarif` writes the Clippy diagnostics as a [SARIF 2.1.0] log, the format used by code scanning
+//! tools
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use crate::diagnostics::{self, Diagnostic, DiagnosticSpan};
+use clippy_lints::LintMetadata;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct Sarif {
+    path: PathBuf,
+    /// The metadata of all lints by their full name, e.g. `clippy::needless_borrow`
+    lints: BTreeMap<String, LintMetadata>,
+    /// The lint and the SARIF result of every diagnostic
+    results: Vec<(String, Value)>,
+}
+
+impl Sarif {
+    pub fn new(path: PathBuf) -> Self {
+        let lints = clippy_lints::lint_metadata()
+            .into_iter()
+            .map(|lint| (format!("clippy::{}", lint.name), lint))
+            .collect();
+        Self {
+            path,
+            lints,
+            results: Vec::new(),
+        }
+    }
+
+    /// Adds `diag` to the log if it is emitted by a Clippy lint
+    pub fn add(&mut self, diag: &Diagnostic) {
+        let Some(lint) = diag.clippy_lint().and_then(|lint| self.lints.get(lint)) else {
+            return;
+        };
+
+        let mut result = json!({
+            "ruleId": format!("clippy::{}", lint.name),
+            "level": level(lint.group),
+            "message": { "text": diag.message },
+            "locations": diag.primary_span().map_or_else(Vec::new, |span| vec![json!({
+                "physicalLocation": {
+                    "artifactLocation": artifact_location(&span.file_name),
+                    "region": region(span),
+                },
+            })]),
+        });
+
+        let fixes: Vec<Value> = diag.children.iter().filter_map(fix).collect();
+        if !fixes.is_empty() {
+            result["fixes"] = fixes.into();
+        }
+
+        self.results.push((format!("clippy::{}", lint.name), result));
+    }
+
+    /// Writes the log, the rules only contain the lints that emitted a diagnostic
+    pub fn write(self) -> Result<(), String> {
+        let mut rule_indices = BTreeMap::new();
+        for (lint, _) in &self.results {
+            rule_indices.insert(lint.as_str(), 0);
+        }
+        for (index, rule_index) in rule_indices.values_mut().enumerate() {
+            *rule_index = index;
+        }
+
+        let rules: Vec<Value> = rule_indices.keys().map(|lint| rule(&self.lints[*lint])).collect();
+        let results: Vec<Value> = self
+            .results
+            .iter()
+            .map(|(lint, result)| {
+                let mut result = result.clone();
+                result["ruleIndex"] = rule_indices[lint.as_str()].into();
+                result
+            })
+            .collect();
+
+        let log = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "clippy",
+                        "informationUri": "https://github.com/rust-lang/rust-clippy",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    },
+                },
+                // the relative file names of the diagnostics are relative to the workspace root
+                "originalUriBaseIds": {
+                    "%SRCROOT%": { "uri": file_uri(&format!("{}/", diagnostics::workspace_root().display())) },
+                },
+                "columnKind": "unicodeCodePoints",
+                "results": results,
+            }],
+        });
+
+        let json = serde_json::to_string_pretty(&log).unwrap();
+        fs::write(&self.path, json + "\n")
+            .map_err(|e| format!("failed to write SARIF file `{}`: {e}", self.path.display()))
+    }
+}
+
+/// The SARIF level of the diagnostics of a lint group, based on the default lint level of the
+/// groups
+fn level(group: &str) -> &'static str {
+    match group {
+        "correctness" => "error",
+        "suspicious" | "style" | "complexity" | "perf" => "warning",
+        _ => "note",
+    }
+}
+
+fn rule(lint: &LintMetadata) -> Value {
+    json!({
+        "id": format!("clippy::{}", lint.name),
+        "name": lint.name,
+        "shortDescription": { "text": lint.description },
+        "fullDescription": { "text": lint.docs },
+        "help": { "text": lint.docs, "markdown": lint.docs },
+        "helpUri": docs_url(&lint.name),
+        "defaultConfiguration": { "level": level(lint.group) },
+        "properties": { "tags": [lint.group] },
+    })
+}
+
+/// The link to the documentation of a lint, the same as the one in the `for further information
+/// visit` help message
+fn docs_url(lint: &str) -> String {
+    let version = option_env!("RUST_RELEASE_NUM").map_or("master".to_string(), |n| {
+        // extract just major + minor version and ignore patch versions
+        format!("rust-{}", n.rsplit_once('.').unwrap().1)
+    });
+    format!("https://rust-lang.github.io/rust-clippy/{version}/index.html#{lint}")
+}
+
+/// The `file://` URI of an absolute path
+fn file_uri(path: &str) -> String {
+    format!("file:///{}", path.replace('\\', "/").trim_start_matches('/'))
+}
+
+fn artifact_location(file_name: &str) -> Value {
+    if Path::new(file_name).is_absolute() {
+        json!({ "uri": file_uri(file_name) })
+    } else {
+        json!({ "uri": file_name.replace('\\', "/"), "uriBaseId": "%SRCROOT%" })
+    }
+}
+
+fn region(span: &DiagnosticSpan) -> Value {
+    json!({
+        "startLine": span.line_start,
+        "startColumn": span.column_start,
+        "endLine": span.line_end,
+        "endColumn": span.column_end,
+    })
+}
+
+/// Converts a suggestion, a child diagnostic with replacements, to a SARIF fix
+fn fix(suggestion: &Diagnostic) -> Option<Value> {
+    let mut changes: BTreeMap<&str, Vec<Value>> = BTreeMap::new();
+    let mut applicability = None;
+    for span in &suggestion.spans {
+        if let Some(replacement) = &span.suggested_replacement {
+            changes.entry(&span.file_name).or_default().push(json!({
+                "deletedRegion": region(span),
+                "insertedContent": { "text": replacement },
+            }));
+            applicability = applicability.or(span.suggestion_applicability.as_deref());
+        }
+    }
+
+    if changes.is_empty() {
+        return None;
+    }
+
+    let artifact_changes: Vec<Value> = changes
+        .into_iter()
+        .map(|(file_name, replacements)| {
+            json!({
+                "artifactLocation": artifact_location(file_name),
+                "replacements": replacements,
+            })
+        })
+        .collect();
+
+    Some(json!({
+        "description": { "text": suggestion.message },
+        "artifactChanges": artifact_changes,
+        "properties": { "applicability": applicability },
+    }))
+}
@@ -0,0 +1,64 @@
+#![feature(lazy_cell)]
+
+use std::fs;
+use std::path::Path;
+use std::process::ExitStatus;
+use test_utils::{cargo_clippy, setup_crate, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+const MAIN: &str = "
+fn main() {
+    let x: Vec<u8> = Vec::new();
+    let _ = x.len() == 0;
+    let _ = x.len() == 0;
+    let _ = x.iter().nth(0);
+}
+";
+
+fn run(dir: &Path, args: &[&str]) -> (ExitStatus, String) {
+    let output = cargo_clippy(dir).args(args).output().unwrap();
+    (output.status, String::from_utf8_lossy(&output.stderr).into_owned())
+}
+
+#[test]
+fn baseline() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+
+    let dir = setup_crate("baseline_test", "", MAIN);
+    let (status, stderr) = run(&dir, &["--write-baseline", "baseline.json"]);
+    assert!(status.success(), "{stderr}");
+    assert!(stderr.contains("Wrote 3 diagnostics to the baseline `baseline.json`"));
+
+    // shifting the lines and adding new code only reports the new diagnostic
+    let main = format!("// a comment\n{MAIN}\nfn f(v: &[u8]) -> bool {{\n    v.len() == 0\n}}\n");
+    fs::write(dir.join("src/main.rs"), main).unwrap();
+    let (status, stderr) = run(&dir, &["--baseline", "baseline.json"]);
+    assert!(status.success(), "{stderr}");
+    assert_eq!(stderr.matches("length comparison to zero").count(), 1);
+    assert!(stderr.contains("src/main.rs:11:5"));
+    assert!(!stderr.contains("called `.iter().nth()`"));
+    assert!(stderr.contains("3 diagnostics are suppressed by the baseline `baseline.json`"));
+    assert!(!stderr.contains("no longer occur"));
+
+    // fixed diagnostics are listed
+    let main = MAIN.replacen("let _ = x.len() == 0;\n", "", 1);
+    fs::write(dir.join("src/main.rs"), main).unwrap();
+    let (status, stderr) = run(&dir, &["--baseline", "baseline.json"]);
+    assert!(status.success(), "{stderr}");
+    assert!(stderr.contains("2 diagnostics are suppressed by the baseline `baseline.json`"));
+    assert!(stderr.contains("1 diagnostics of the baseline no longer occur"));
+    assert!(stderr.contains("src/main.rs: clippy::len_zero: length comparison to zero"));
+
+    // the baseline isn't replaced if cargo fails before Clippy runs
+    let baseline = fs::read_to_string(dir.join("baseline.json")).unwrap();
+    let mut manifest = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+    manifest.push_str("\n[dependencies]\nmissing = { path = \"missing\" }\n");
+    fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    let (status, stderr) = run(&dir, &["--write-baseline", "baseline.json"]);
+    assert!(!status.success(), "{stderr}");
+    assert!(stderr.contains("warning: the baseline `baseline.json` was not written"));
+    assert_eq!(fs::read_to_string(dir.join("baseline.json")).unwrap(), baseline);
+}
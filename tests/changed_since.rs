@@ -0,0 +1,60 @@
+#![feature(lazy_cell)]
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use test_utils::{cargo_clippy, setup_crate, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+const MAIN: &str = "
+fn main() {
+    let x: Vec<u8> = Vec::new();
+    let _a = x.len() == 0;
+    let _b = x.len() == 0;
+    let _ = x == x;
+}
+";
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+#[test]
+fn changed_since() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+
+    let dir = setup_crate("changed_since_test", "", MAIN);
+    fs::write(dir.join(".gitignore"), "/target\n").unwrap();
+    git(&dir, &["init", "--quiet"]);
+    git(&dir, &["add", "."]);
+    git(&dir, &["commit", "--quiet", "--message", "initial"]);
+
+    // only the second comparison is changed, an untracked module counts as changed entirely
+    let main = format!("mod new;\n{}", MAIN.replace("_b", "_changed"));
+    fs::write(dir.join("src/main.rs"), main).unwrap();
+    fs::write(
+        dir.join("src/new.rs"),
+        "pub fn f(v: &[u8]) -> bool {\n    v.len() == 0\n}\n",
+    )
+    .unwrap();
+
+    let output = cargo_clippy(&dir).args(["--changed-since", "HEAD"]).output().unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    // `clippy::eq_op` is denied by default, but it's not on a changed line
+    assert!(output.status.success(), "{stderr}");
+    assert_eq!(stderr.matches("length comparison to zero").count(), 2);
+    assert!(stderr.contains("src/main.rs:6:20"));
+    assert!(stderr.contains("src/new.rs:2:5"));
+    assert!(!stderr.contains("equal expressions"));
+    assert!(stderr.contains("2 diagnostics outside of the lines changed since `HEAD` were filtered"));
+}
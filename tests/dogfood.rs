@@ -29,6 +29,7 @@ fn dogfood_clippy() {
         "clippy_lints",
         "clippy_utils",
         "clippy_config",
+        "clippy_summary",
         "lintcheck",
         "rustc_tools_util",
     ] {
@@ -0,0 +1,127 @@
+#![feature(lazy_cell)]
+
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use test_utils::{cargo_clippy, setup_crate, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+const MAIN: &str = "
+fn f(x: u32) -> u32 {
+    return x + 1;
+}
+
+fn g(x: u32) -> u32 {
+    return x + 2;
+}
+
+fn main() {
+    let x: Vec<u8> = Vec::new();
+    let _ = x.len() == 0;
+    let _ = f(1) + g(2);
+}
+";
+
+#[test]
+fn interactive_fix() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+
+    let dir = setup_crate("interactive_fix_test", "", MAIN);
+    let mut child = cargo_clippy(&dir)
+        .args(["--fix", "--interactive", "--allow-no-vcs"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // accept all the `needless_return` suggestions, skip the `len_zero` one
+    child.stdin.take().unwrap().write_all(b"a\nn\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    // the second `needless_return` suggestion is accepted without asking
+    assert_eq!(stdout.matches("Apply this suggestion?").count(), 2);
+    assert!(stdout.contains("clippy::needless_return: unneeded `return` statement"));
+    assert!(stdout.contains("-     return x + 1;\n+     x + 1\n"));
+    assert!(stdout.contains("Fixed src/main.rs (2 fixes)"));
+
+    let main = fs::read_to_string(dir.join("src/main.rs")).unwrap();
+    assert!(!main.contains("return"));
+    assert!(main.contains("x.len() == 0"));
+}
+
+#[test]
+fn fix_only() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+
+    let dir = setup_crate("fix_only_test", "", MAIN);
+    let output = cargo_clippy(&dir)
+        .args(["--fix", "--only", "clippy::len_zero", "--allow-no-vcs"])
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    // the diagnostics of the other lints are still printed
+    assert_eq!(stderr.matches("unneeded `return` statement").count(), 2);
+    assert!(!stderr.contains("length comparison to zero"));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Fixed src/main.rs (1 fix)"));
+
+    let main = fs::read_to_string(dir.join("src/main.rs")).unwrap();
+    assert_eq!(main.matches("return").count(), 2);
+    assert!(main.contains("x.is_empty()"));
+}
+
+#[test]
+fn fix_only_refuses_dirty_files() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+
+    let dir = setup_crate("fix_dirty_test", "", MAIN);
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .current_dir(&dir)
+            .args(["-c", "user.name=clippy", "-c", "user.email=clippy@example.com"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {args:?} failed");
+    };
+    git(&["init", "--quiet"]);
+    fs::write(dir.join(".gitignore"), "target\n").unwrap();
+    git(&["add", "."]);
+    git(&["commit", "--quiet", "-m", "initial"]);
+    let dirty = format!("{MAIN}\n// uncommitted\n");
+    fs::write(dir.join("src/main.rs"), &dirty).unwrap();
+
+    let fix = |allow: &[&str]| {
+        cargo_clippy(&dir)
+            .args(["--fix", "--only", "clippy::len_zero"])
+            .args(allow)
+            .output()
+            .unwrap()
+    };
+
+    let output = fix(&[]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "{stderr}");
+    assert!(stderr.contains("uncommitted changes"));
+    assert!(stderr.contains("  * src/main.rs (dirty)"));
+    assert_eq!(fs::read_to_string(dir.join("src/main.rs")).unwrap(), dirty);
+
+    let output = fix(&["--allow-dirty"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(
+        fs::read_to_string(dir.join("src/main.rs"))
+            .unwrap()
+            .contains("x.is_empty()")
+    );
+}
@@ -0,0 +1,101 @@
+#![feature(lazy_cell)]
+
+use std::process::Command;
+use test_utils::{CARGO_CLIPPY_PATH, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+fn cargo_clippy_json(args: &[&str]) -> serde_json::Value {
+    let output = Command::new(&*CARGO_CLIPPY_PATH)
+        .arg("clippy")
+        .args(args)
+        .args(["--format", "json"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn list_lints_json() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+
+    let lints = cargo_clippy_json(&["--list-lints"]);
+    let lints = lints.as_array().unwrap();
+    assert!(lints.len() > 500);
+
+    let manual_let_else = lints.iter().find(|lint| lint["name"] == "manual_let_else").unwrap();
+    assert_eq!(manual_let_else["group"], "pedantic");
+    assert_eq!(manual_let_else["default_level"], "allow");
+    assert_eq!(manual_let_else["version"], "1.67.0");
+    assert_eq!(manual_let_else["msrv_gated"], true);
+    assert_eq!(manual_let_else["applicability"], "HasPlaceholders");
+    assert_eq!(
+        manual_let_else["config_keys"],
+        serde_json::json!(["msrv", "matches-for-let-else"])
+    );
+}
+
+#[test]
+fn collected_metadata_is_up_to_date() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+
+    let lints = cargo_clippy_json(&["--list-lints"]);
+    let lint_names: Vec<&str> = lints
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|lint| lint["name"].as_str().unwrap())
+        .collect();
+
+    let collected = std::fs::read_to_string("clippy_lints/src/collected_metadata.rs").unwrap();
+    let collected_names: Vec<&str> = collected
+        .lines()
+        .filter_map(|line| line.strip_prefix("    (\""))
+        .map(|line| line.split('"').next().unwrap())
+        .collect();
+
+    let mut sorted_names = lint_names.clone();
+    sorted_names.sort_unstable();
+    assert!(
+        collected_names == sorted_names,
+        "clippy_lints/src/collected_metadata.rs is out of date, run `cargo collect-metadata` to update it\n\
+        missing lints: {:?}\n\
+        removed lints: {:?}",
+        sorted_names
+            .iter()
+            .filter(|name| !collected_names.contains(name))
+            .collect::<Vec<_>>(),
+        collected_names
+            .iter()
+            .filter(|name| !sorted_names.contains(name))
+            .collect::<Vec<_>>(),
+    );
+}
+
+#[test]
+fn explain_json() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+
+    let lint = cargo_clippy_json(&["--explain", "clippy::Box-Collection"]);
+    assert_eq!(lint["name"], "box_collection");
+    assert_eq!(lint["group"], "perf");
+    assert_eq!(lint["default_level"], "warn");
+    assert_eq!(lint["msrv_gated"], false);
+    assert_eq!(lint["config_keys"], serde_json::json!(["avoid-breaking-exported-api"]));
+    assert!(lint["docs"].as_str().unwrap().starts_with("### What it does"));
+
+    let output = Command::new(&*CARGO_CLIPPY_PATH)
+        .args(["clippy", "--explain", "no_such_lint", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+}
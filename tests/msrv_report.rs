@@ -0,0 +1,56 @@
+#![feature(lazy_cell)]
+
+use test_utils::{cargo_clippy, setup_crate, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+const MAIN: &str = "
+use std::collections::BinaryHeap;
+use std::path::MAIN_SEPARATOR;
+
+fn main() {
+    let _: &str = &MAIN_SEPARATOR.to_string();
+    let _: &str = &MAIN_SEPARATOR.to_string();
+    let mut heap = BinaryHeap::from([1, 2, 3]);
+    heap = heap.into_iter().filter(|x| x % 2 == 0).collect();
+    let _ = heap;
+    let x = 5;
+    let _ = x >= 1 && x < 10;
+    let _ = std::path::Path::new(\"README.md\").ends_with(\".md\");
+    f();
+}
+
+#[clippy::msrv = \"1.70\"]
+fn f() {
+    let _: &str = &MAIN_SEPARATOR.to_string();
+}
+";
+
+#[test]
+fn msrv_report() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+
+    let dir = setup_crate("msrv_report_test", "rust-version = \"1.56\"\n", MAIN);
+    let output = cargo_clippy(&dir)
+        .args(["--msrv-report", "--", "-Wclippy::path_ends_with_ext"])
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+
+    // the diagnostics themselves are not printed, `manual_range_contains` isn't suppressed, the MSRV
+    // only picks the suggestion of `path_ends_with_ext` and the function with `#[clippy::msrv]`
+    // isn't counted
+    assert!(!stderr.contains("warning"));
+    assert_eq!(
+        stdout,
+        "Lints suppressed by the MSRV, by the Rust version that enables them:\n    \
+         1.68.0  clippy::manual_main_separator_str (2 diagnostics)\n    \
+         1.70.0  clippy::manual_retain (1 diagnostic)\n\
+         Raising the MSRV to 1.70.0 enables 2 lints with 3 diagnostics\n"
+    );
+}
@@ -0,0 +1,65 @@
+#![feature(lazy_cell)]
+
+use std::fs;
+use test_utils::{cargo_clippy, setup_crate, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+const MAIN: &str = "
+fn main() {
+    let x: Vec<u8> = Vec::new();
+    let _ = x.len() == 0;
+    let _ = x == x;
+}
+";
+
+#[test]
+fn sarif() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+
+    let dir = setup_crate("sarif_test", "", MAIN);
+    let output = cargo_clippy(&dir).args(["--sarif", "clippy.sarif"]).output().unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    // `clippy::eq_op` is denied by default
+    assert!(!output.status.success(), "{stderr}");
+    assert!(stderr.contains("length comparison to zero"));
+
+    let sarif: serde_json::Value = serde_json::from_slice(&fs::read(dir.join("clippy.sarif")).unwrap()).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    let run = &sarif["runs"][0];
+    let root = run["originalUriBaseIds"]["%SRCROOT%"]["uri"].as_str().unwrap();
+    assert!(root.starts_with("file:///") && root.ends_with("/target/sarif_test/"));
+
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    let rule_ids: Vec<_> = rules.iter().map(|rule| rule["id"].as_str().unwrap()).collect();
+    assert_eq!(rule_ids, ["clippy::eq_op", "clippy::len_zero"]);
+    assert_eq!(rules[0]["defaultConfiguration"]["level"], "error");
+    assert_eq!(rules[1]["defaultConfiguration"]["level"], "warning");
+    assert_eq!(rules[1]["properties"]["tags"][0], "style");
+    assert_eq!(
+        rules[1]["shortDescription"]["text"],
+        "checking `.len() == 0` or `.len() > 0` (or similar) when `.is_empty()` could be used instead"
+    );
+    assert!(rules[1]["helpUri"].as_str().unwrap().ends_with("index.html#len_zero"));
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    let len_zero = results
+        .iter()
+        .find(|result| result["ruleId"] == "clippy::len_zero")
+        .unwrap();
+    assert_eq!(len_zero["ruleIndex"], 1);
+    assert_eq!(len_zero["level"], "warning");
+    assert_eq!(len_zero["message"]["text"], "length comparison to zero");
+    let location = &len_zero["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "src/main.rs");
+    assert_eq!(location["region"]["startLine"], 4);
+    assert_eq!(location["region"]["startColumn"], 13);
+
+    let replacement = &len_zero["fixes"][0]["artifactChanges"][0]["replacements"][0];
+    assert_eq!(replacement["insertedContent"]["text"], "x.is_empty()");
+    assert_eq!(replacement["deletedRegion"]["endColumn"], 25);
+}
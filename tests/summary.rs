@@ -0,0 +1,64 @@
+#![feature(lazy_cell)]
+
+use std::fs;
+use test_utils::{cargo_clippy, setup_crate, IS_RUSTC_TEST_SUITE};
+
+mod test_utils;
+
+const LIB: &str = "
+pub fn f(v: &[u8]) -> bool {
+    v.len() == 0
+}
+
+pub fn g(x: u32) -> u32 {
+    return x;
+}
+";
+
+const MAIN: &str = "
+fn main() {
+    let x: Vec<u8> = Vec::new();
+    let _ = x.len() == 0;
+    let _ = x.iter().nth(0);
+}
+";
+
+#[test]
+fn summary() {
+    if IS_RUSTC_TEST_SUITE {
+        return;
+    }
+
+    let dir = setup_crate("summary_test", "\n[workspace]\nmembers = [\"a\"]\n", MAIN);
+    fs::create_dir_all(dir.join("a/src")).unwrap();
+    fs::write(
+        dir.join("a/Cargo.toml"),
+        "[package]\nname = \"a\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+    )
+    .unwrap();
+    fs::write(dir.join("a/src/lib.rs"), LIB).unwrap();
+
+    let output = cargo_clippy(&dir)
+        .args(["--workspace", "--summary=json"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let summary: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(summary["total"], 4);
+    // the suggestion of `clippy::iter_nth` is not `MachineApplicable`
+    assert_eq!(summary["fixable"], 3);
+    assert_eq!(summary["not_fixable"], 1);
+    assert_eq!(summary["lints"][0]["name"], "clippy::len_zero");
+    assert_eq!(summary["lints"][0]["count"], 2);
+    assert_eq!(summary["groups"][0]["name"], "style");
+    assert_eq!(summary["groups"][0]["count"], 3);
+    assert_eq!(summary["groups"][1]["name"], "perf");
+    let crates = &summary["crates"];
+    assert_eq!(
+        (&crates[0]["name"], &crates[1]["name"]),
+        (&"a".into(), &"summary_test".into())
+    );
+    assert_eq!(summary["files"][0]["name"], "a/src/lib.rs");
+}
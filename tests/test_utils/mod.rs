@@ -1,6 +1,8 @@
 #![allow(dead_code)] // see https://github.com/rust-lang/rust/issues/46379
 
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::LazyLock;
 
 pub static CARGO_CLIPPY_PATH: LazyLock<PathBuf> = LazyLock::new(|| {
@@ -11,3 +13,32 @@ pub static CARGO_CLIPPY_PATH: LazyLock<PathBuf> = LazyLock::new(|| {
 });
 
 pub const IS_RUSTC_TEST_SUITE: bool = option_env!("RUSTC_TEST_SUITE").is_some();
+
+/// Creates the binary crate `target/<name>` with `main` as its `src/main.rs`, removing the one of a
+/// previous run, and returns its directory.
+///
+/// `manifest` is appended to the `[package]` table of its `Cargo.toml`. Unless it adds a
+/// `[workspace]` table, an empty one is added so that the crate isn't part of Clippy's workspace.
+pub fn setup_crate(name: &str, manifest: &str, main: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target").join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    let mut cargo_toml = format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n{manifest}");
+    if !manifest.contains("[workspace]") {
+        cargo_toml.push_str("\n[workspace]\n");
+    }
+    fs::write(dir.join("Cargo.toml"), cargo_toml).unwrap();
+    fs::write(dir.join("src/main.rs"), main).unwrap();
+    dir
+}
+
+/// A `cargo clippy` command for the crate in `dir` that builds into `dir/target`.
+pub fn cargo_clippy(dir: &Path) -> Command {
+    let mut command = Command::new(&*CARGO_CLIPPY_PATH);
+    command
+        .current_dir(dir)
+        .env("CARGO_INCREMENTAL", "0")
+        .env("CARGO_TARGET_DIR", dir.join("target"))
+        .arg("clippy");
+    command
+}
@@ -0,0 +1,53 @@
+#![warn(clippy::async_blocking_call)]
+#![allow(clippy::unused_async, clippy::redundant_closure)]
+
+mod db {
+    pub fn query() {}
+
+    pub async fn query_async() {}
+}
+
+mod sync {
+    pub mod nested {
+        pub fn wait() {}
+    }
+}
+
+mod pool {
+    pub fn run<T>(f: impl FnOnce() -> T) -> T {
+        f()
+    }
+}
+
+pub struct Cache;
+
+impl Cache {
+    pub fn load() -> Self {
+        Self
+    }
+}
+
+async fn blocking() {
+    db::query();
+    //~^ ERROR: blocking call to `db::query` in async code
+    db::query_async().await;
+    let _ = Cache::load();
+    //~^ ERROR: blocking call to `Cache::load` in async code
+    sync::nested::wait();
+    //~^ ERROR: blocking call to `sync::nested::wait` in async code
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    //~^ ERROR: blocking call to `std::thread::sleep` in async code
+}
+
+async fn offloaded() {
+    pool::run(|| db::query());
+    pool::run(|| {
+        let _ = Cache::load();
+    });
+    let _ = [1].iter().map(|_| db::query()).count();
+    //~^ ERROR: blocking call to `db::query` in async code
+}
+
+fn main() {
+    db::query();
+}
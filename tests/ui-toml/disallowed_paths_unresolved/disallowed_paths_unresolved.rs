@@ -0,0 +1,4 @@
+//@check-pass
+#![warn(clippy::disallowed_methods, clippy::disallowed_types, clippy::disallowed_macros)]
+
+fn main() {}
@@ -0,0 +1,3 @@
+//@error-in-other-file: does not refer to an existing item
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![warn(clippy::disallowed_names, clippy::too_many_arguments)]
+
+// `too-many-arguments-threshold` is inherited from the parent directory
+fn too_many(_: u8, _: u8, _: u8) {}
+//~^ ERROR: this function has too many arguments (3/2)
+
+fn main() {
+    // `disallowed-names` is overridden by the child configuration
+    let geese = ["honk", "honk"];
+    //~^ ERROR: use of a disallowed/placeholder name `geese`
+    let ducks = ["quack", "quack"];
+}
@@ -0,0 +1,16 @@
+#![allow(unused)]
+#![warn(clippy::large_regex)]
+
+extern crate regex;
+
+use regex::Regex;
+
+fn main() {
+    let large = Regex::new(r"[a-z]{101}");
+    //~^ ERROR: this regex has an estimated compiled size of 101
+    let large = Regex::new(r"\w+");
+    //~^ ERROR: this regex has an estimated compiled size of
+
+    let small = Regex::new(r"[a-z]{100}");
+    let small = Regex::new(r"(?-u:\w)+");
+}
@@ -0,0 +1,16 @@
+//@no-rustfix
+
+/// Mentions `doc_markdown` in a doc comment: foo_bar
+fn returns() -> u32 {
+    return 1;
+}
+
+fn main() {
+    let x = 1u8;
+    // `cast_lossless` is part of `clippy::pedantic`
+    let _ = x as u32;
+    //~^ ERROR: casting `u8` to `u32` may become silently lossy
+    let _ = returns();
+    let foo = 1;
+    //~^ ERROR: use of a disallowed/placeholder name `foo`
+}
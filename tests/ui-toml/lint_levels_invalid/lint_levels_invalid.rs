@@ -0,0 +1,5 @@
+//@error-in-other-file: unknown or removed lint `clippy::no_such_lint`
+//@error-in-other-file: invalid lint level
+//@error-in-other-file: `dead_code` is not a Clippy lint
+
+fn main() {}
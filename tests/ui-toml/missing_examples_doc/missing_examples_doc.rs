@@ -0,0 +1,26 @@
+#![warn(clippy::missing_examples_doc)]
+
+/// No examples
+pub fn exported() {}
+//~^ ERROR: docs for function missing `# Examples` section
+
+pub(crate) fn crate_visible() {}
+//~^ ERROR: docs for function missing `# Examples` section
+
+mod private_module {
+    pub fn not_exported() {}
+    //~^ ERROR: docs for function missing `# Examples` section
+
+    mod nested {
+        pub(super) fn visible_in_parent() {}
+
+        fn private() {}
+    }
+}
+
+pub const CONST: u32 = 0;
+//~^ ERROR: docs for constant missing `# Examples` section
+
+pub struct NotChecked;
+
+fn main() {}
@@ -0,0 +1,79 @@
+#![warn(clippy::missing_panics_doc)]
+
+/// Panics in a helper
+pub fn calls_helper(x: Option<u32>) -> u32 {
+    //~^ ERROR: docs for function which may panic missing `# Panics` section
+    helper(x)
+}
+
+/// Panics two calls deep
+pub fn calls_nested(x: Option<u32>) -> u32 {
+    //~^ ERROR: docs for function which may panic missing `# Panics` section
+    nested(x) + 1
+}
+
+/// Panics three calls deep, beyond the configured depth
+pub fn too_deep(x: Option<u32>) -> u32 {
+    deeper(x)
+}
+
+/// Calls a method that panics
+pub fn calls_method(s: &S) -> u32 {
+    //~^ ERROR: docs for function which may panic missing `# Panics` section
+    s.get()
+}
+
+/// Panics in a helper
+///
+/// # Panics
+///
+/// When `x` is `None`
+pub fn documented(x: Option<u32>) -> u32 {
+    helper(x)
+}
+
+/// Calls recursive functions that don't panic
+pub fn recursive(n: u32) -> u32 {
+    if n == 0 { 0 } else { recursive(n - 1) + even(n) }
+}
+
+/// Calls trait methods, the called impl is unknown
+pub fn calls_trait<T: Trait>(t: &T) -> u32 {
+    t.value()
+}
+
+pub struct S(Option<u32>);
+
+impl S {
+    fn get(&self) -> u32 {
+        self.0.unwrap()
+    }
+}
+
+pub trait Trait {
+    fn value(&self) -> u32 {
+        panic!()
+    }
+}
+
+fn helper(x: Option<u32>) -> u32 {
+    x.unwrap()
+}
+
+fn nested(x: Option<u32>) -> u32 {
+    helper(x) * 2
+}
+
+fn deeper(x: Option<u32>) -> u32 {
+    nested(x)
+}
+
+fn even(n: u32) -> u32 {
+    if n == 0 { 1 } else { odd(n - 1) }
+}
+
+fn odd(n: u32) -> u32 {
+    if n == 0 { 0 } else { even(n - 1) }
+}
+
+fn main() {}
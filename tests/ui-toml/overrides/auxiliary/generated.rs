@@ -0,0 +1,14 @@
+pub fn too_many_arguments(_: u8, _: u8, _: u8) {}
+
+pub fn too_many_lines() {
+    println!("a");
+    println!("b");
+    println!("c");
+    println!("d");
+    println!("e");
+    println!("f");
+}
+
+pub fn nested() {
+    { { {} } }
+}
@@ -0,0 +1,29 @@
+#![warn(clippy::too_many_lines)]
+#![allow(clippy::no_effect, unused)]
+
+// `auxiliary/**` has higher thresholds and doesn't check nesting, so nothing is linted there
+#[path = "auxiliary/generated.rs"]
+mod generated;
+
+fn too_many_arguments(_: u8, _: u8, _: u8) {}
+//~^ ERROR: this function has too many arguments (3/2)
+
+fn too_many_lines() {
+    //~^ ERROR: this function has too many lines (6/5)
+    println!("a");
+    println!("b");
+    println!("c");
+    println!("d");
+    println!("e");
+    println!("f");
+}
+
+fn nested() {
+    {
+        {
+            //~^ ERROR: this block is too nested
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+//@error-in-other-file: `paths` can only be set in `[[overrides]]`
+//@error-in-other-file: missing field `paths` in `[[overrides]]`
+//@error-in-other-file: `disallowed-names` can't be set in `[[overrides]]`
+//@error-in-other-file: `inherit` can't be set in `[[overrides]]`
+
+fn main() {}
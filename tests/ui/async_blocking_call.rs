@@ -0,0 +1,73 @@
+#![warn(clippy::async_blocking_call)]
+#![allow(clippy::manual_async_fn, clippy::unused_async, clippy::unused_io_amount)]
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::sync::Mutex;
+use std::time::Duration;
+
+async fn sleep() {
+    std::thread::sleep(Duration::from_millis(10));
+    //~^ ERROR: blocking call to `std::thread::sleep` in async code
+}
+
+async fn files() -> std::io::Result<String> {
+    let config = fs::read_to_string("config.toml")?;
+    //~^ ERROR: blocking call to `std::fs::read_to_string` in async code
+    let mut file = File::open("data")?;
+    //~^ ERROR: blocking call to `std::fs::File::open` in async code
+    let mut data = String::new();
+    file.read_to_string(&mut data)?;
+    //~^ ERROR: blocking call to `std::io::Read::read_to_string` in async code
+    let options = OpenOptions::new();
+    let _ = options.open("out")?;
+    //~^ ERROR: blocking call to `std::fs::OpenOptions::open` in async code
+    Ok(config + &data)
+}
+
+async fn network() -> std::io::Result<()> {
+    let mut stream = TcpStream::connect("127.0.0.1:8080")?;
+    //~^ ERROR: blocking call to `std::net::TcpStream::connect` in async code
+    stream.write_all(b"ping")?;
+    //~^ ERROR: blocking call to `std::io::Write::write_all` in async code
+    stream.set_nodelay(true)?;
+    Ok(())
+}
+
+async fn lock(m: &Mutex<Vec<u32>>) {
+    let mut guard = m.lock().unwrap();
+    //~^ ERROR: blocking call to `std::sync::Mutex::lock` in async code
+    guard.push(1);
+    drop(guard);
+
+    // the lock is only held for the statement
+    m.lock().unwrap().push(2);
+    assert_eq!(m.lock().unwrap().len(), 2);
+}
+
+fn async_block() -> impl std::future::Future<Output = ()> {
+    async {
+        std::thread::sleep(Duration::from_millis(10));
+        //~^ ERROR: blocking call to `std::thread::sleep` in async code
+    }
+}
+
+fn not_async() {
+    std::thread::sleep(Duration::from_millis(10));
+    let _ = fs::read("data");
+}
+
+async fn in_closure(paths: &[&str]) {
+    #[allow(clippy::redundant_closure)]
+    let _: Vec<_> = paths.iter().map(|path| fs::read(path)).collect();
+    //~^ ERROR: blocking call to `std::fs::read` in async code
+
+    // the closure is run on another thread
+    let _ = std::thread::spawn(|| fs::read("data")).join();
+    let _ = std::thread::Builder::new().spawn(|| {
+        std::thread::sleep(Duration::from_millis(10));
+    });
+}
+
+fn main() {}
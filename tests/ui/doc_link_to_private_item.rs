@@ -0,0 +1,55 @@
+#![warn(clippy::doc_link_to_private_item)]
+#![allow(dead_code)]
+
+/// Uses [`Private`] and [`Public`].
+//~^ ERROR: the docs link to `Private`, which is less visible than the documented item
+pub fn links() {}
+
+/// Uses [the parser](crate::inner::Parser), [`struct@self::Private`] and [`private_fn()`].
+//~^ ERROR: the docs link to `inner::Parser`, which is less visible than the documented item
+//~| ERROR: the docs link to `Private`, which is less visible than the documented item
+//~| ERROR: the docs link to `private_fn`, which is less visible than the documented item
+pub fn more_links() {}
+
+/// Uses [`Exported`], [`Vec`], [`std::vec::Vec`], [`Self`] and [1].
+pub fn allowed_links() {}
+
+/// Uses [`Private`].
+fn private_item_links() {}
+
+/// Uses [`Reference`].
+///
+/// [`Reference`]: Private
+//~^^^ ERROR: the docs link to `Private`, which is less visible than the documented item
+pub struct ReferenceLink;
+
+struct Private;
+
+pub struct Public;
+
+fn private_fn() {}
+
+mod inner {
+    pub struct Parser;
+
+    pub struct Exported;
+
+    /// Uses [`super::Private`] and [`Parser`].
+    pub fn not_exported() {}
+
+    struct InnerPrivate;
+
+    /// Uses [`InnerPrivate`] and [`Parser`].
+    //~^ ERROR: the docs link to `inner::InnerPrivate`, which is less visible than the documented item
+    pub(crate) fn crate_visible() {}
+}
+
+pub use inner::Exported;
+
+pub mod public {
+    /// Uses [`super::Private`] and [`crate::Public`].
+    //~^ ERROR: the docs link to `Private`, which is less visible than the documented item
+    pub fn exported() {}
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+#![warn(clippy::incompatible_msrv)]
+#![feature(custom_inner_attributes)]
+#![clippy::msrv = "1.50.0"]
+
+use std::num::NonZeroU8;
+use std::thread::sleep;
+use std::time::Duration;
+
+fn foo() {
+    let _ = "a=b".split_once('=');
+    //~^ ERROR: current MSRV is `1.50.0` but this item is stable since `1.52.0`
+    let _ = Some(1).is_some_and(|x| x > 0);
+    //~^ ERROR: current MSRV is `1.50.0` but this item is stable since `1.70.0`
+    let _ = u32::ilog2;
+    //~^ ERROR: current MSRV is `1.50.0` but this item is stable since `1.67.0`
+    let _: NonZeroU8 = NonZeroU8::new(1).unwrap();
+    // stable since 1.4.0
+    sleep(Duration::new(1, 0));
+}
+
+#[clippy::msrv = "1.70.0"]
+fn newer_msrv() {
+    let _ = Some(1).is_some_and(|x| x > 0);
+}
+
+#[clippy::msrv = "1.20.0"]
+fn older_msrv() {
+    let _: NonZeroU8 = NonZeroU8::new(1).unwrap();
+    //~^ ERROR: current MSRV is `1.20.0` but this item is stable since `1.28.0`
+    //~| ERROR: current MSRV is `1.20.0` but this item is stable since `1.28.0`
+    let _ = NonZeroU8::get;
+    //~^ ERROR: current MSRV is `1.20.0` but this item is stable since `1.28.0`
+}
+
+macro_rules! local_macro {
+    () => {
+        Some(1).is_some_and(|x| x > 0)
+    };
+}
+
+fn macros() {
+    // the items used by the expansion of external macros are not linted
+    let _ = format!("{}", 1);
+    let _ = local_macro!();
+    //~^ ERROR: current MSRV is `1.50.0` but this item is stable since `1.70.0`
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+#![allow(unused, clippy::needless_borrows_for_generic_args)]
+#![warn(clippy::large_regex)]
+
+extern crate regex;
+
+use regex::bytes::Regex as BRegex;
+use regex::{Regex, RegexSet};
+
+const LARGE: &str = r"\w{20}";
+
+fn main() {
+    let large = Regex::new(r"\w{20}");
+    //~^ ERROR: this regex has an estimated compiled size of
+    let large = Regex::new(LARGE);
+    //~^ ERROR: this regex has an estimated compiled size of
+    let large = RegexSet::new(&[r"[a-z]+", r"\w{20}"]);
+    //~^ ERROR: this regex has an estimated compiled size of
+
+    let small = Regex::new(r"\w+\s\w+");
+    let small = Regex::new(r"(?-u:\w){20}");
+    let small = BRegex::new(r"(?-u)\w{20}");
+    let small = Regex::new(r"[a-z]{1000}");
+}
@@ -0,0 +1,83 @@
+#![warn(clippy::missing_examples_doc)]
+#![allow(clippy::missing_panics_doc)]
+
+/// No examples
+pub fn no_examples() {}
+//~^ ERROR: docs for function missing `# Examples` section
+//~| NOTE: `-D clippy::missing-examples-doc` implied by `-D warnings`
+
+/// With examples
+///
+/// # Examples
+///
+/// ```
+/// with_examples();
+/// ```
+pub fn with_examples() {}
+
+/// With an example
+///
+/// # Example
+///
+/// ```
+/// with_example();
+/// ```
+pub fn with_example() {}
+
+pub struct Undocumented;
+//~^ ERROR: docs for struct missing `# Examples` section
+
+/// No examples
+pub enum Enum {
+    //~^ ERROR: docs for enum missing `# Examples` section
+    A,
+}
+
+/// No examples
+pub trait Trait {
+    //~^ ERROR: docs for trait missing `# Examples` section
+    /// No examples
+    fn method(&self);
+    //~^ ERROR: docs for method missing `# Examples` section
+}
+
+impl Trait for Undocumented {
+    fn method(&self) {}
+}
+
+impl Undocumented {
+    /// No examples
+    pub fn method(&self) {}
+    //~^ ERROR: docs for method missing `# Examples` section
+
+    fn private_method(&self) {}
+}
+
+/// No examples
+#[macro_export]
+macro_rules! exported {
+    //~^ ERROR: docs for macro missing `# Examples` section
+    () => {};
+}
+
+// not checked by default
+/// No examples
+pub const CONST: u32 = 0;
+
+/// No examples
+pub mod module {}
+
+fn private() {}
+
+mod private_module {
+    pub fn not_exported() {}
+}
+
+pub(crate) fn crate_visible() {}
+
+#[doc(hidden)]
+pub mod hidden {
+    pub fn hidden() {}
+}
+
+fn main() {}
@@ -0,0 +1,73 @@
+#![allow(
+    unused,
+    clippy::trivial_regex,
+    clippy::never_loop,
+    clippy::needless_borrows_for_generic_args
+)]
+#![warn(clippy::regex_creation_in_loops)]
+
+extern crate regex;
+
+use regex::bytes::Regex as BRegex;
+use regex::{Regex, RegexSet};
+
+const PATTERN: &str = "[a-z]+";
+
+fn in_loops(haystacks: &[&str], patterns: &[&str]) {
+    for haystack in haystacks {
+        let regex = Regex::new("[a-z]+").unwrap();
+        //~^ ERROR: compiling a regex in a loop
+        let regex = Regex::new(PATTERN).unwrap();
+        //~^ ERROR: compiling a regex in a loop
+        let set = RegexSet::new(&["a+", PATTERN]).unwrap();
+        //~^ ERROR: compiling a regex in a loop
+    }
+
+    let mut i = 0;
+    while i < 10 {
+        let regex = BRegex::new("[a-z]+").unwrap();
+        //~^ ERROR: compiling a regex in a loop
+        i += 1;
+    }
+
+    loop {
+        let regex = Regex::new(PATTERN).unwrap();
+        //~^ ERROR: compiling a regex in a loop
+        break;
+    }
+
+    // the pattern depends on the loop
+    for pattern in patterns {
+        let regex = Regex::new(pattern).unwrap();
+        let set = RegexSet::new(&["a+", pattern]).unwrap();
+    }
+}
+
+fn in_closures(haystacks: &[&str]) {
+    let _ = haystacks
+        .iter()
+        .filter(|haystack| Regex::new(PATTERN).unwrap().is_match(haystack))
+        //~^ ERROR: compiling a regex in a closure that may be called multiple times
+        .count();
+
+    // called once
+    let regex = Some(PATTERN).map(|pattern| Regex::new("[a-z]+").unwrap());
+    let regex = std::sync::OnceLock::new();
+    for haystack in haystacks {
+        let _ = regex.get_or_init(|| Regex::new(PATTERN).unwrap()).is_match(haystack);
+    }
+}
+
+#[clippy::msrv = "1.69"]
+fn msrv_1_69(haystacks: &[&str]) {
+    for haystack in haystacks {
+        let regex = Regex::new(PATTERN).unwrap();
+        //~^ ERROR: compiling a regex in a loop
+    }
+}
+
+fn outside_loops() {
+    let regex = Regex::new(PATTERN).unwrap();
+}
+
+fn main() {}
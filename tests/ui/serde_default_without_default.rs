@@ -0,0 +1,44 @@
+#![warn(clippy::serde_default_without_default)]
+#![allow(dead_code)]
+
+extern crate serde;
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Generic<T, U> {
+    #[serde(default)]
+    //~^ ERROR: `#[serde(default)]` is used on a field of type `T`, which doesn't implement `Defa
+    value: T,
+    #[serde(default)]
+    //~^ ERROR: `#[serde(default)]` is used on a field of type `(T, u32)`, which doesn't implement
+    pair: (T, u32),
+    #[serde(default)]
+    values: Vec<(T, U)>,
+    #[serde(default)]
+    option: Option<U>,
+}
+
+#[derive(Deserialize)]
+struct Bounded<T: Default> {
+    #[serde(default)]
+    value: T,
+}
+
+fn default_value<T>() -> Option<T> {
+    None
+}
+
+#[derive(Deserialize)]
+struct WithPath<T> {
+    #[serde(default = "default_value")]
+    value: Option<T>,
+}
+
+#[derive(Deserialize)]
+struct Concrete {
+    #[serde(default)]
+    value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,81 @@
+#![warn(clippy::serde_duplicate_name)]
+#![allow(dead_code)]
+
+extern crate serde;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Renamed {
+    a: u32,
+    #[serde(rename = "a")]
+    b: u32,
+    //~^ ERROR: the serde name `a` of field `b` is also used by field `a`
+}
+
+#[derive(Deserialize)]
+struct Aliased {
+    #[serde(alias = "name")]
+    user_name: String,
+    name: String,
+    //~^ ERROR: the serde name `name` of field `name` is also used by field `user_name`
+    #[serde(alias = "other", alias = "user_name")]
+    other_name: String,
+    //~^ ERROR: the serde name `user_name` of field `other_name` is also used by field `user_name`
+}
+
+#[derive(Serialize, Deserialize)]
+struct OneSide {
+    #[serde(rename(serialize = "x"))]
+    a: u32,
+    #[serde(rename(deserialize = "x"))]
+    b: u32,
+    #[serde(rename(serialize = "b"), skip_deserializing)]
+    c: u32,
+    //~^ ERROR: the serde name `b` of field `c` is also used by field `b`
+}
+
+#[derive(Deserialize)]
+struct Skipped {
+    a: u32,
+    #[serde(rename = "a", skip)]
+    b: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RenameAll {
+    user_name: String,
+    #[serde(rename = "userName")]
+    name: String,
+    #[serde(alias = "id")]
+    user_id: u32,
+    #[serde(rename = "id")]
+    id_: u32,
+    //~^ ERROR: the serde name `id` of field `id_` is also used by field `user_id`
+}
+
+#[derive(Serialize, Deserialize)]
+enum Variants {
+    A,
+    #[serde(rename = "A")]
+    B,
+    //~^ ERROR: the serde name `A` of variant `B` is also used by variant `A`
+    C {
+        x: u32,
+        #[serde(alias = "x")]
+        y: u32,
+        //~^ ERROR: the serde name `x` of field `y` is also used by field `x`
+    },
+    D(u32, u32),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Untagged {
+    A(u32),
+    #[serde(rename = "A")]
+    B(String),
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+#![warn(clippy::serde_flatten_with_deny_unknown_fields)]
+#![allow(dead_code)]
+
+extern crate serde;
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Inner {
+    b: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Denied {
+    a: u32,
+    #[serde(flatten)]
+    //~^ ERROR: `#[serde(flatten)]` is not supported in a type with `#[serde(deny_unknown_fields)]
+    inner: Inner,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+enum DeniedEnum {
+    A {
+        a: u32,
+        #[serde(flatten)]
+        //~^ ERROR: `#[serde(flatten)]` is not supported in a type with `#[serde(deny_unknown_fields)]
+        inner: Inner,
+    },
+}
+
+#[derive(Deserialize)]
+struct Allowed {
+    a: u32,
+    #[serde(flatten)]
+    inner: Inner,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NotFlattened {
+    a: u32,
+    inner: Inner,
+}
+
+fn main() {}
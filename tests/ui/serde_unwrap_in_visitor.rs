@@ -0,0 +1,47 @@
+#![warn(clippy::serde_unwrap_in_visitor)]
+#![allow(dead_code)]
+
+extern crate serde;
+
+use serde::de::{self, MapAccess, Visitor};
+use std::fmt;
+
+struct Parsed(u32);
+
+struct ParsedVisitor;
+
+impl<'de> Visitor<'de> for ParsedVisitor {
+    type Value = Parsed;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number").unwrap();
+        Ok(())
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    //~^ ERROR: used `unwrap` or `expect` in `visit_str`
+    where
+        E: de::Error,
+    {
+        Ok(Parsed(v.parse().unwrap()))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    //~^ ERROR: used `unwrap` or `expect` in `visit_map`
+    where
+        A: MapAccess<'de>,
+    {
+        let _: String = map.next_key()?.expect("a key");
+        let value: Option<u32> = map.next_value()?;
+        Ok(Parsed(value.unwrap()))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        u32::try_from(v).map(Parsed).map_err(E::custom)
+    }
+}
+
+fn main() {}
@@ -28,6 +28,7 @@ fn consistent_clippy_crate_versions() {
         "declare_clippy_lint/Cargo.toml",
         "clippy_config/Cargo.toml",
         "clippy_lints/Cargo.toml",
+        "clippy_summary/Cargo.toml",
         "clippy_utils/Cargo.toml",
     ];
 